@@ -6,6 +6,7 @@
 #include "nstd/core.h"
 #include "nstd/env.h"
 #include "nstd/events.h"
+#include "nstd/fmt.h"
 #include "nstd/fs.h"
 #include "nstd/gl.h"
 #include "nstd/gui.h"
@@ -2,6 +2,10 @@
 #define NSTD_ALLOC_H_INCLUDED
 #include "alloc/alloc.h"
 #include "alloc/allocator.h"
+#include "alloc/arena.h"
+#include "alloc/debug.h"
+#include "alloc/fault.h"
 #include "alloc/heap.h"
+#include "alloc/pool.h"
 #include "nstd.h"
 #endif
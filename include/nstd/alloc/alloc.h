@@ -2,8 +2,53 @@
 #define NSTD_ALLOC_ALLOC_H_INCLUDED
 #include "../core/def.h"
 #include "../nstd.h"
+#include "allocator.h"
 NSTDCPPSTART
 
+/// Installs a process-wide allocator that every `nstd_alloc` function is routed through.
+///
+/// # Note
+///
+/// The allocator is passed to it's own functions as `this`, and must stay valid until it is
+/// uninstalled. Memory must be freed by the same allocator that allocated it, so the allocator
+/// should be installed before and uninstalled after any memory it is responsible for is used.
+/// Memory that the installed allocator allocates through `nstd_alloc` is always allocated with the
+/// system allocator.
+///
+/// Calls into the installed allocator are serialised with a process-wide lock, so allocators that
+/// are not thread safe, such as the fault and debug allocators, can be installed. Using the
+/// allocator directly while it is installed is not synchronised with this lock. Once this returns
+/// no other thread is inside of the previously installed allocator.
+///
+/// # Parameters
+///
+/// - `NSTDAllocator *const allocator` - The allocator to install, null to use the system allocator.
+NSTDAPI void nstd_alloc_set_global_allocator(NSTDAllocator *const allocator);
+
+/// Returns the installed process-wide allocator.
+///
+/// # Returns
+///
+/// `NSTDAllocator *allocator` - The process-wide allocator, null if the system allocator is used.
+NSTDAPI NSTDAllocator *nstd_alloc_global_allocator();
+
+/// Returns the process-wide allocation statistics.
+///
+/// # Note
+///
+/// Every successful call to an `nstd_alloc` function is recorded, except for calls made by the
+/// installed process-wide allocator itself. Sizes are taken from the caller, so deallocating with
+/// the wrong size will skew the byte counts.
+///
+/// # Returns
+///
+/// `NSTDAllocStats stats` - A snapshot of the process-wide allocation statistics.
+NSTDAPI NSTDAllocStats nstd_alloc_stats();
+
+/// Resets the peak number of live bytes in the process-wide allocation statistics to the current
+/// number of live bytes.
+NSTDAPI void nstd_alloc_stats_reset_peak();
+
 /// Allocates a new memory block.
 ///
 /// # Parameters
@@ -57,5 +102,74 @@ NSTDAPI NSTDErrorCode nstd_alloc_reallocate(
 /// `NSTDErrorCode errc` - Nonzero on error.
 NSTDAPI NSTDErrorCode nstd_alloc_deallocate(NSTDAny *const ptr, const NSTDUSize size);
 
+/// Allocates a new memory block with a specific alignment.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - Number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new memory block, null on error.
+NSTDAPI NSTDAny nstd_alloc_allocate_aligned(const NSTDUSize size, const NSTDUSize align);
+
+/// Allocates a new memory block with a specific alignment and all bytes set to 0.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - Number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new memory block, null on error.
+NSTDAPI NSTDAny nstd_alloc_allocate_zeroed_aligned(const NSTDUSize size, const NSTDUSize align);
+
+/// Reallocates a memory block that was allocated with a specific alignment.
+///
+/// # Note
+///
+/// `ptr` is left untouched on error.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the memory block.
+///
+/// - `const NSTDUSize size` - The current size of the memory block.
+///
+/// - `const NSTDUSize new_size` - The new size of the memory block.
+///
+/// - `const NSTDUSize align` - The alignment the memory block was allocated with.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_alloc_reallocate_aligned(
+    NSTDAny *const ptr,
+    const NSTDUSize size,
+    const NSTDUSize new_size,
+    const NSTDUSize align);
+
+/// Deallocates a memory block that was allocated with a specific alignment.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the memory block.
+///
+/// - `const NSTDUSize size` - Number of bytes to deallocate.
+///
+/// - `const NSTDUSize align` - The alignment the memory block was allocated with.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_alloc_deallocate_aligned(
+    NSTDAny *const ptr,
+    const NSTDUSize size,
+    const NSTDUSize align);
+
 NSTDCPPEND
 #endif
@@ -4,6 +4,23 @@
 #include "../nstd.h"
 NSTDCPPSTART
 
+/// A snapshot of an allocator's usage.
+typedef struct
+{
+    /// The total number of bytes that have been allocated, including growth from reallocations.
+    NSTDUSize total_bytes;
+    /// The number of bytes that are currently allocated.
+    NSTDUSize live_bytes;
+    /// The highest number of bytes that have been allocated at once.
+    NSTDUSize peak_bytes;
+    /// The number of allocations that have been made.
+    NSTDUSize allocations;
+    /// The number of deallocations that have been made.
+    NSTDUSize deallocations;
+    /// The number of reallocations that have been made.
+    NSTDUSize reallocations;
+} NSTDAllocStats;
+
 /// A heap memory allocator type.
 typedef struct
 {
@@ -55,6 +72,70 @@ typedef struct
     ///
     /// - `NSTDUSize size` - Number of bytes to deallocate.
     void (*deallocate)(NSTDAny, NSTDAny *, NSTDUSize);
+    /// Allocates a new block of memory with a specific alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDUSize size` - Number of bytes to allocate.
+    ///
+    /// - `NSTDUSize align` - The alignment of the block of memory, must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDAny ptr` - The new block of memory.
+    NSTDAny (*allocate_aligned)(NSTDAny, NSTDUSize, NSTDUSize);
+    /// Allocates a new block of memory with a specific alignment and all bytes set to 0.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDUSize size` - Number of bytes to allocate.
+    ///
+    /// - `NSTDUSize align` - The alignment of the block of memory, must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDAny ptr` - The new block of memory.
+    NSTDAny (*allocate_zeroed_aligned)(NSTDAny, NSTDUSize, NSTDUSize);
+    /// Reallocates a block of memory that was allocated with a specific alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDAny *ptr` - Pointer to the block of memory.
+    ///
+    /// - `NSTDUSize size` - The current size of the block of memory.
+    ///
+    /// - `NSTDUSize new_size` - The new size of the block of memory.
+    ///
+    /// - `NSTDUSize align` - The alignment the block of memory was allocated with.
+    void (*reallocate_aligned)(NSTDAny, NSTDAny *, NSTDUSize, NSTDUSize, NSTDUSize);
+    /// Deallocates a block of memory that was allocated with a specific alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDAny *ptr` - Pointer to the block of memory.
+    ///
+    /// - `NSTDUSize size` - Number of bytes to deallocate.
+    ///
+    /// - `NSTDUSize align` - The alignment the block of memory was allocated with.
+    void (*deallocate_aligned)(NSTDAny, NSTDAny *, NSTDUSize, NSTDUSize);
+    /// Returns the allocator's usage statistics.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst this` - A pointer to the owner of the allocator.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDAllocStats stats` - A snapshot of the allocator's usage.
+    NSTDAllocStats (*stats)(NSTDAnyConst);
 } NSTDAllocator;
 
 /// Returns the default memory allocator.
@@ -64,5 +145,17 @@ typedef struct
 /// `NSTDAllocator allocator` - The default memory allocator.
 NSTDAPI NSTDAllocator nstd_alloc_allocator_default();
 
+/// Returns an allocator's usage statistics.
+///
+/// # Parameters
+///
+/// - `const NSTDAllocator *allocator` - The allocator, must be the first field of its owner.
+///
+/// # Returns
+///
+/// `NSTDAllocStats stats` - A snapshot of the allocator's usage, all zero if the allocator doesn't
+/// keep statistics.
+NSTDAPI NSTDAllocStats nstd_alloc_allocator_stats(const NSTDAllocator *allocator);
+
 NSTDCPPEND
 #endif
@@ -0,0 +1,110 @@
+#ifndef NSTD_ALLOC_ARENA_H_INCLUDED
+#define NSTD_ALLOC_ARENA_H_INCLUDED
+#include "../core/def.h"
+#include "../nstd.h"
+#include "allocator.h"
+NSTDCPPSTART
+
+/// Memory usage statistics for an arena allocator.
+typedef struct
+{
+    /// The number of chunks the arena has allocated.
+    NSTDUSize chunk_count;
+    /// The total number of bytes available for allocations in all chunks.
+    NSTDUSize capacity;
+    /// The number of bytes that have been handed out, including alignment padding.
+    NSTDUSize used;
+    /// The number of allocations that have been made since the last reset.
+    NSTDUSize allocations;
+} NSTDArenaStats;
+
+/// An arena allocator.
+///
+/// # Note
+///
+/// The arena itself must be passed as `this` when using `allocator`.
+typedef struct
+{
+    /// The arena's allocator vtable.
+    NSTDAllocator allocator;
+    /// The minimum number of bytes to allocate for each chunk.
+    NSTDUSize chunk_size;
+    /// The most recently allocated chunk, null if the arena has no chunks.
+    NSTDAny chunk;
+    /// The number of bytes used in the most recently allocated chunk.
+    NSTDUSize offset;
+    /// The arena's usage statistics.
+    NSTDArenaStats stats;
+    /// The usage statistics reported through `allocator`.
+    NSTDAllocStats usage;
+} NSTDArena;
+
+/// Creates a new arena allocator.
+///
+/// # Note
+///
+/// No memory is allocated until the first allocation is made. Allocations that are larger than
+/// `chunk_size` are given their own chunk.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize chunk_size` - The minimum number of bytes to allocate for each chunk.
+///
+/// # Returns
+///
+/// `NSTDArena arena` - The new arena allocator.
+NSTDAPI NSTDArena nstd_alloc_arena_new(const NSTDUSize chunk_size);
+
+/// Allocates a new block of memory from an arena.
+///
+/// # Parameters
+///
+/// - `NSTDArena *const arena` - The arena.
+///
+/// - `const NSTDUSize size` - Number of bytes to allocate.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new block of memory, null on error.
+NSTDAPI NSTDAny nstd_alloc_arena_allocate(NSTDArena *const arena, const NSTDUSize size);
+
+/// Makes all of the memory in an arena available for allocations again.
+///
+/// # Note
+///
+/// Every chunk except for the most recently allocated one is freed. All memory previously handed
+/// out by the arena becomes invalid.
+///
+/// # Parameters
+///
+/// - `NSTDArena *const arena` - The arena.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_alloc_arena_reset(NSTDArena *const arena);
+
+/// Returns an arena's memory usage statistics.
+///
+/// # Parameters
+///
+/// - `const NSTDArena *const arena` - The arena.
+///
+/// # Returns
+///
+/// `NSTDArenaStats stats` - The arena's usage statistics.
+NSTDAPI NSTDArenaStats nstd_alloc_arena_stats(const NSTDArena *const arena);
+
+/// Frees an arena and all of the memory that was allocated from it.
+///
+/// # Parameters
+///
+/// - `NSTDArena *const arena` - The arena.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_alloc_arena_free(NSTDArena *const arena);
+
+NSTDCPPEND
+#endif
@@ -0,0 +1,128 @@
+#ifndef NSTD_ALLOC_DEBUG_H_INCLUDED
+#define NSTD_ALLOC_DEBUG_H_INCLUDED
+#include "../core/def.h"
+#include "../io/output_stream.h"
+#include "../nstd.h"
+#include "allocator.h"
+NSTDCPPSTART
+
+/// A raw handle to a debug allocator's bookkeeping.
+typedef NSTDAny NSTDDebugAllocatorHandle;
+
+/// Statistics and error counts for a debug allocator.
+typedef struct
+{
+    /// The number of allocations that have not been freed.
+    NSTDUSize live_count;
+    /// The number of bytes in allocations that have not been freed.
+    NSTDUSize live_bytes;
+    /// The total number of allocations that have been made.
+    NSTDUSize total_allocations;
+    /// The number of frees of memory that had already been freed.
+    NSTDUSize double_frees;
+    /// The number of frees of memory that was never allocated by the allocator.
+    NSTDUSize invalid_frees;
+    /// The number of frees or reallocations that were given the wrong size.
+    NSTDUSize size_mismatches;
+    /// The number of allocations found with corrupted guard bytes.
+    NSTDUSize overruns;
+} NSTDDebugAllocatorStats;
+
+/// A debug allocator.
+///
+/// # Note
+///
+/// The debug allocator itself must be passed as `this` when using `allocator`. Every error that is
+/// detected is counted in `stats` and sets `allocator.errc`.
+typedef struct
+{
+    /// The debug allocator's vtable.
+    NSTDAllocator allocator;
+    /// The caller tag recorded with each new allocation, may be null.
+    const NSTDChar *tag;
+    /// The debug allocator's statistics and error counts.
+    NSTDDebugAllocatorStats stats;
+    /// The raw handle to the debug allocator's bookkeeping.
+    NSTDDebugAllocatorHandle handle;
+    /// The usage statistics reported through `allocator`.
+    NSTDAllocStats usage;
+} NSTDDebugAllocator;
+
+/// Creates a new debug allocator.
+///
+/// # Returns
+///
+/// `NSTDDebugAllocator debug` - The new debug allocator.
+NSTDAPI NSTDDebugAllocator nstd_alloc_debug_new();
+
+/// Sets the caller tag that is recorded with each new allocation.
+///
+/// # Note
+///
+/// The tag is not copied, so it must outlive every allocation that is made while it is set.
+///
+/// # Parameters
+///
+/// - `NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// - `const NSTDChar *const tag` - The new caller tag, may be null.
+NSTDAPI void nstd_alloc_debug_set_tag(NSTDDebugAllocator *const debug, const NSTDChar *const tag);
+
+/// Checks the guard bytes of every live allocation.
+///
+/// # Parameters
+///
+/// - `NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// # Returns
+///
+/// `NSTDUSize overruns` - The number of live allocations with corrupted guard bytes.
+NSTDAPI NSTDUSize nstd_alloc_debug_check(NSTDDebugAllocator *const debug);
+
+/// Returns a debug allocator's statistics and error counts.
+///
+/// # Parameters
+///
+/// - `const NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// # Returns
+///
+/// `NSTDDebugAllocatorStats stats` - The debug allocator's statistics.
+NSTDAPI NSTDDebugAllocatorStats nstd_alloc_debug_stats(const NSTDDebugAllocator *const debug);
+
+/// Writes a report of every leaked allocation and detected error to an output stream.
+///
+/// # Note
+///
+/// Leaks are reported in the order that they were allocated.
+///
+/// # Parameters
+///
+/// - `const NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// - `NSTDOutputStream *const stream` - The stream to write the report to.
+///
+/// - `const NSTDAny owner` - The object that owns `stream`, such as an `NSTDFile`. This is passed
+///   to the stream's write function as `this`.
+///
+/// # Returns
+///
+/// `NSTDUSize leaks` - The number of allocations that have not been freed.
+NSTDAPI NSTDUSize nstd_alloc_debug_report(
+    const NSTDDebugAllocator *const debug,
+    NSTDOutputStream *const stream,
+    const NSTDAny owner);
+
+/// Frees a debug allocator, releasing any allocations that were leaked.
+///
+/// # Parameters
+///
+/// - `NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_alloc_debug_free(NSTDDebugAllocator *const debug);
+
+NSTDCPPEND
+#endif
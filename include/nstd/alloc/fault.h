@@ -0,0 +1,82 @@
+#ifndef NSTD_ALLOC_FAULT_H_INCLUDED
+#define NSTD_ALLOC_FAULT_H_INCLUDED
+#include "../core/def.h"
+#include "../nstd.h"
+#include "allocator.h"
+NSTDCPPSTART
+
+/// A fault-injecting allocator.
+///
+/// # Note
+///
+/// The fault allocator itself must be passed as `this` when using `allocator`. Memory is allocated
+/// with the `nstd_alloc` functions, so the fault allocator can be installed as the process-wide
+/// allocator with `nstd_alloc_set_global_allocator`. Reallocations that grow a block of memory are
+/// treated as allocations of the extra bytes, shrinking and deallocating never fail.
+typedef struct
+{
+    /// The fault allocator's vtable.
+    NSTDAllocator allocator;
+    /// Every allocation fails once `successes` reaches this value, -1/usize::MAX to never fail.
+    NSTDUSize fail_after;
+    /// The chance of each allocation failing, from 0.0 to 1.0.
+    NSTDFloat64 failure_rate;
+    /// The state of the random number generator used for `failure_rate`.
+    NSTDUInt64 seed;
+    /// The maximum number of bytes that may be allocated at once, -1/usize::MAX for no limit.
+    NSTDUSize byte_budget;
+    /// The number of bytes that are currently allocated.
+    NSTDUSize live_bytes;
+    /// The number of allocations that have succeeded.
+    NSTDUSize successes;
+    /// The number of allocations that have been made to fail.
+    NSTDUSize failures;
+    /// The usage statistics reported through `allocator`.
+    NSTDAllocStats usage;
+} NSTDFaultAllocator;
+
+/// Creates a new fault allocator that never fails.
+///
+/// # Returns
+///
+/// `NSTDFaultAllocator fault` - The new fault allocator.
+NSTDAPI NSTDFaultAllocator nstd_alloc_fault_new();
+
+/// Makes every allocation fail once `count` more allocations have succeeded.
+///
+/// # Parameters
+///
+/// - `NSTDFaultAllocator *const fault` - The fault allocator.
+///
+/// - `const NSTDUSize count` - The number of allocations to allow, -1/usize::MAX to never fail.
+NSTDAPI void nstd_alloc_fault_fail_after(NSTDFaultAllocator *const fault, const NSTDUSize count);
+
+/// Makes allocations fail randomly.
+///
+/// # Note
+///
+/// The same seed always produces the same sequence of failures.
+///
+/// # Parameters
+///
+/// - `NSTDFaultAllocator *const fault` - The fault allocator.
+///
+/// - `const NSTDFloat64 rate` - The chance of each allocation failing, from 0.0 to 1.0.
+///
+/// - `const NSTDUInt64 seed` - The seed for the random number generator.
+NSTDAPI void nstd_alloc_fault_fail_randomly(
+    NSTDFaultAllocator *const fault,
+    const NSTDFloat64 rate,
+    const NSTDUInt64 seed);
+
+/// Makes allocations fail when they would exceed a byte budget.
+///
+/// # Parameters
+///
+/// - `NSTDFaultAllocator *const fault` - The fault allocator.
+///
+/// - `const NSTDUSize bytes` - The maximum number of live bytes, -1/usize::MAX for no limit.
+NSTDAPI void nstd_alloc_fault_set_budget(NSTDFaultAllocator *const fault, const NSTDUSize bytes);
+
+NSTDCPPEND
+#endif
@@ -0,0 +1,100 @@
+#ifndef NSTD_ALLOC_POOL_H_INCLUDED
+#define NSTD_ALLOC_POOL_H_INCLUDED
+#include "../core/def.h"
+#include "../nstd.h"
+#include "allocator.h"
+NSTDCPPSTART
+
+/// A pool allocator for objects of a single size.
+///
+/// # Note
+///
+/// The pool itself must be passed as `this` when using `allocator`. Requests for more than
+/// `object_size` bytes or with an alignment greater than `alignment` fail.
+///
+/// A pool created with invalid parameters has `allocator.errc` set and fails every allocation.
+typedef struct
+{
+    /// The pool's allocator vtable.
+    NSTDAllocator allocator;
+    /// The size of each object in bytes.
+    NSTDUSize object_size;
+    /// The alignment of each object in bytes.
+    NSTDUSize alignment;
+    /// The number of bytes between each object.
+    NSTDUSize slot_size;
+    /// The number of objects each page holds.
+    NSTDUSize objects_per_page;
+    /// The most recently allocated page, null if the pool has no pages.
+    NSTDAny pages;
+    /// The first free object, null if there are none.
+    NSTDAny free_list;
+    /// The number of pages the pool has allocated.
+    NSTDUSize page_count;
+    /// The number of objects that are currently allocated.
+    NSTDUSize live_count;
+    /// The usage statistics reported through `allocator`.
+    NSTDAllocStats usage;
+} NSTDPool;
+
+/// Creates a new pool allocator.
+///
+/// # Note
+///
+/// No memory is allocated until the first allocation is made. `alignment` must be a power of two
+/// and `objects_per_page` must be greater than 0, otherwise the returned pool's `allocator.errc`
+/// is set and every allocation from it fails.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize object_size` - The size of each object in bytes.
+///
+/// - `const NSTDUSize alignment` - The alignment of each object.
+///
+/// - `const NSTDUSize objects_per_page` - The number of objects each page holds.
+///
+/// # Returns
+///
+/// `NSTDPool pool` - The new pool allocator.
+NSTDAPI NSTDPool nstd_alloc_pool_new(
+    const NSTDUSize object_size,
+    const NSTDUSize alignment,
+    const NSTDUSize objects_per_page);
+
+/// Allocates an object from a pool.
+///
+/// # Parameters
+///
+/// - `NSTDPool *const pool` - The pool.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new object, null on error.
+NSTDAPI NSTDAny nstd_alloc_pool_allocate(NSTDPool *const pool);
+
+/// Returns an object to a pool.
+///
+/// # Parameters
+///
+/// - `NSTDPool *const pool` - The pool.
+///
+/// - `NSTDAny *const ptr` - A pointer to the object, set to null once it has been returned.
+NSTDAPI void nstd_alloc_pool_deallocate(NSTDPool *const pool, NSTDAny *const ptr);
+
+/// Frees a pool and every page it has allocated.
+///
+/// # Note
+///
+/// All objects allocated from the pool become invalid.
+///
+/// # Parameters
+///
+/// - `NSTDPool *const pool` - The pool.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_alloc_pool_free(NSTDPool *const pool);
+
+NSTDCPPEND
+#endif
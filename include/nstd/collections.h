@@ -1,6 +1,11 @@
 #ifndef NSTD_COLLECTIONS_H_INCLUDED
 #define NSTD_COLLECTIONS_H_INCLUDED
+#include "collections/arc.h"
 #include "collections/bit_mask.h"
+#include "collections/deque.h"
+#include "collections/hash_map.h"
+#include "collections/ordered_map.h"
+#include "collections/priority_queue.h"
 #include "collections/rc.h"
 #include "collections/stack.h"
 #include "nstd.h"
@@ -0,0 +1,119 @@
+#ifndef NSTD_COLLECTIONS_ARC_H_INCLUDED
+#define NSTD_COLLECTIONS_ARC_H_INCLUDED
+#include "../alloc/heap.h"
+#include "../core/pointer.h"
+#include "../nstd.h"
+NSTDCPPSTART
+
+/// The state of an atomic reference counter.
+typedef struct
+{
+    /// The number of atomic reference counters that own `data`.
+    NSTDUSize count;
+    /// The number of weak references to this state, plus one while `count` is nonzero.
+    NSTDUSize weak_count;
+    /// The shared data.
+    NSTDHeap data;
+} NSTDARCState;
+
+/// An atomic reference counter. A heap allocated type that can be shared across multiple objects
+/// and threads, once the last object to own the data is freed the data is freed as well.
+typedef struct
+{
+    /// The atomic reference counter state. Only free once `state.weak_count` == 0.
+    NSTDHeap state;
+} NSTDARC;
+
+/// A weak reference to the data owned by an `NSTDARC`. Weak references do not keep the data alive.
+typedef struct
+{
+    /// The atomic reference counter state. Only free once `state.weak_count` == 0.
+    NSTDHeap state;
+} NSTDARCWeak;
+
+/// Creates a new atomic reference counter.
+///
+/// # Parameters
+///
+/// - `const NSTDPointer *const ptr` - A pointer to the object to be placed on the heap.
+///
+/// # Returns
+///
+/// `NSTDARC arc` - The new atomic reference counter.
+NSTDAPI NSTDARC nstd_collections_arc_new(const NSTDPointer *const ptr);
+
+/// Shares the atomic reference counter, creating a new instance of `NSTDARC` and increasing the
+/// reference count.
+///
+/// # Parameters
+///
+/// - `const NSTDARC *const arc` - A pointer to the atomic reference counter.
+///
+/// # Returns
+///
+/// `NSTDARC new_arc` - The new atomic reference counter that points to the new data.
+NSTDAPI NSTDARC nstd_collections_arc_share(const NSTDARC *const arc);
+
+/// Returns a pointer to the underlying data.
+///
+/// # Parameters
+///
+/// - `const NSTDARC *const arc` - A pointer to the atomic reference counter.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the underlying data.
+NSTDAPI NSTDAny nstd_collections_arc_get(const NSTDARC *const arc);
+
+/// Frees an atomic reference counter, only frees the underlying data once all other atomic
+/// reference counters have been freed as well.
+///
+/// # Parameters
+///
+/// - `NSTDARC *const arc` - The atomic reference counter to free.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_arc_free(NSTDARC *const arc);
+
+/// Creates a weak reference to an atomic reference counter's data.
+///
+/// # Parameters
+///
+/// - `const NSTDARC *const arc` - A pointer to the atomic reference counter.
+///
+/// # Returns
+///
+/// `NSTDARCWeak weak` - The new weak reference.
+NSTDAPI NSTDARCWeak nstd_collections_arc_downgrade(const NSTDARC *const arc);
+
+/// Attempts to create a new atomic reference counter from a weak reference.
+///
+/// # Parameters
+///
+/// - `const NSTDARCWeak *const weak` - A pointer to the weak reference.
+///
+/// - `NSTDARC *const arc` - Returns as the new atomic reference counter on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the data has already been freed.
+NSTDAPI NSTDErrorCode nstd_collections_arc_weak_upgrade(
+    const NSTDARCWeak *const weak,
+    NSTDARC *const arc);
+
+/// Frees a weak reference, the atomic reference counter state is freed once there are no
+/// references to it left.
+///
+/// # Parameters
+///
+/// - `NSTDARCWeak *const weak` - The weak reference to free.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_arc_weak_free(NSTDARCWeak *const weak);
+
+NSTDCPPEND
+#endif
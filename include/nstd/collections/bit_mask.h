@@ -5,11 +5,17 @@
 #include "../vec.h"
 NSTDCPPSTART
 
+/// Returned by `nstd_collections_bit_mask_first_set` and `nstd_collections_bit_mask_next_set` when
+/// no bit is found.
+#define NSTD_COLLECTIONS_BIT_MASK_NONE 0xFFFFFFFF
+
 /// A bit mask type with a small memory footprint.
 typedef struct
 {
-    /// Vector of bytes.
-    NSTDVec bytes;
+    /// The number of bits in the bit mask.
+    NSTDUInt32 size;
+    /// Vector of 64-bit words, bits past `size` in the last word are always off.
+    NSTDVec words;
 } NSTDBitMask;
 
 /// Creates a new bit mask with `size` number of bits.
@@ -29,7 +35,8 @@ NSTDAPI NSTDBitMask nstd_collections_bit_mask_new(const NSTDUInt32 size);
 ///
 /// - `NSTDBitMask *const mask` - The bit mask.
 ///
-/// - `const NSTDUInt32 pos` - The bit index to set.
+/// - `const NSTDUInt32 pos` - The bit index to set, nothing is done if it is not less than the
+///   bit mask's size.
 ///
 /// - `const NSTDBitValue mode` - The mode to set the bit.
 NSTDAPI void nstd_collections_bit_mask_set(
@@ -47,7 +54,8 @@ NSTDAPI void nstd_collections_bit_mask_set(
 ///
 /// # Returns
 ///
-/// `NSTDBitValue is_on` - `NSTD_BIT_VALUE_ON` if the bit is on (1).
+/// `NSTDBitValue is_on` - `NSTD_BIT_VALUE_ON` if the bit is on (1), `NSTD_BIT_VALUE_OFF` if `pos`
+/// is not less than the bit mask's size.
 NSTDAPI NSTDBitValue nstd_collections_bit_mask_get(
     const NSTDBitMask *const mask,
     const NSTDUInt32 pos);
@@ -61,6 +69,169 @@ NSTDAPI NSTDBitValue nstd_collections_bit_mask_get(
 /// - `const NSTDBitValue mode` - The mode to set all bits.
 NSTDAPI void nstd_collections_bit_mask_set_all(NSTDBitMask *const mask, const NSTDBitValue mode);
 
+/// Returns the number of bits in a bit mask.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDUInt32 size` - The number of bits in the bit mask.
+NSTDAPI NSTDUInt32 nstd_collections_bit_mask_len(const NSTDBitMask *const mask);
+
+/// Resizes a bit mask, any new bits are off.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask.
+///
+/// - `const NSTDUInt32 new_size` - The new number of bits in the bit mask.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_bit_mask_resize(
+    NSTDBitMask *const mask,
+    const NSTDUInt32 new_size);
+
+/// Turns off each bit in `mask` that is off in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `other` are treated as off.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask to modify.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+NSTDAPI void nstd_collections_bit_mask_and(NSTDBitMask *const mask, const NSTDBitMask *const other);
+
+/// Turns on each bit in `mask` that is on in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `mask` are ignored.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask to modify.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+NSTDAPI void nstd_collections_bit_mask_or(NSTDBitMask *const mask, const NSTDBitMask *const other);
+
+/// Toggles each bit in `mask` that is on in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `mask` are ignored.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask to modify.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+NSTDAPI void nstd_collections_bit_mask_xor(NSTDBitMask *const mask, const NSTDBitMask *const other);
+
+/// Toggles every bit in a bit mask.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask.
+NSTDAPI void nstd_collections_bit_mask_not(NSTDBitMask *const mask);
+
+/// Returns the number of bits that are on in a bit mask.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count` - The number of bits that are on.
+NSTDAPI NSTDUInt32 nstd_collections_bit_mask_count(const NSTDBitMask *const mask);
+
+/// Returns the index of the first bit that is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDUInt32 pos` - The index of the first bit that is on, `NSTD_COLLECTIONS_BIT_MASK_NONE` if
+/// all bits are off.
+NSTDAPI NSTDUInt32 nstd_collections_bit_mask_first_set(const NSTDBitMask *const mask);
+
+/// Returns the index of the first bit after `pos` that is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// - `const NSTDUInt32 pos` - The bit index to search after.
+///
+/// # Returns
+///
+/// `NSTDUInt32 next` - The index of the next bit that is on, `NSTD_COLLECTIONS_BIT_MASK_NONE` if
+/// there are none.
+NSTDAPI NSTDUInt32 nstd_collections_bit_mask_next_set(
+    const NSTDBitMask *const mask,
+    const NSTDUInt32 pos);
+
+/// Determines whether any bit in a bit mask is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool any` - `NSTD_BOOL_TRUE` if at least one bit is on.
+NSTDAPI NSTDBool nstd_collections_bit_mask_any(const NSTDBitMask *const mask);
+
+/// Determines whether every bit in a bit mask is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool all` - `NSTD_BOOL_TRUE` if every bit is on, or the bit mask is empty.
+NSTDAPI NSTDBool nstd_collections_bit_mask_all(const NSTDBitMask *const mask);
+
+/// Determines whether every bit in a bit mask is off.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool none` - `NSTD_BOOL_TRUE` if every bit is off.
+NSTDAPI NSTDBool nstd_collections_bit_mask_none(const NSTDBitMask *const mask);
+
+/// Determines whether every bit that is on in `mask` is also on in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `other` are treated as off.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool is_subset` - `NSTD_BOOL_TRUE` if `mask` is a subset of `other`.
+NSTDAPI NSTDBool nstd_collections_bit_mask_is_subset(
+    const NSTDBitMask *const mask,
+    const NSTDBitMask *const other);
+
 /// Frees an `NSTDBitMask`.
 ///
 /// # Parameters
@@ -0,0 +1,218 @@
+#ifndef NSTD_COLLECTIONS_DEQUE_H_INCLUDED
+#define NSTD_COLLECTIONS_DEQUE_H_INCLUDED
+#include "../core/def.h"
+#include "../core/slice.h"
+#include "../nstd.h"
+NSTDCPPSTART
+
+/// A double-ended queue backed by a growable ring buffer.
+typedef struct
+{
+    /// The number of active elements in the deque.
+    NSTDUSize size;
+    /// The buffer index of the first element.
+    NSTDUSize head;
+    /// Buffer of allocated memory where `buffer.size` is the capacity, `buffer.ptr.size` is the
+    /// size of each element, and `buffer.ptr.raw` is a raw pointer to the buffer.
+    NSTDSlice buffer;
+} NSTDDeque;
+
+/// Creates a new deque.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the deque.
+///
+/// # Returns
+///
+/// `NSTDDeque deque` - The new deque.
+NSTDAPI NSTDDeque nstd_collections_deque_new(const NSTDUSize element_size);
+
+/// Creates a new deque with the specified capacity.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the deque.
+///
+/// - `const NSTDUSize capacity` - The capacity to give the deque, must be greater than 0.
+///
+/// # Returns
+///
+/// `NSTDDeque deque` - The new deque.
+NSTDAPI NSTDDeque nstd_collections_deque_new_with_capacity(
+    const NSTDUSize element_size,
+    const NSTDUSize capacity);
+
+/// Gets a pointer to an element from a deque, where position 0 is the front of the deque.
+///
+/// # Note
+///
+/// The returned element pointer can quickly become a dangling pointer if the deque's memory
+/// gets reallocated or deallocated, so it is advised to create a copy of the element after
+/// getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDUSize pos` - The position of the element to get.
+///
+/// # Returns
+///
+/// `NSTDAny element` - Pointer to the element.
+NSTDAPI NSTDAny nstd_collections_deque_get(const NSTDDeque *const deque, const NSTDUSize pos);
+
+/// Gets the element at the front of the deque.
+///
+/// # Note
+///
+/// This function follows the same behaviour rules as `nstd_collections_deque_get`.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - Pointer to the front element.
+NSTDAPI NSTDAny nstd_collections_deque_front(const NSTDDeque *const deque);
+
+/// Gets the element at the back of the deque.
+///
+/// # Note
+///
+/// This function follows the same behaviour rules as `nstd_collections_deque_get`.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - Pointer to the back element.
+NSTDAPI NSTDAny nstd_collections_deque_back(const NSTDDeque *const deque);
+
+/// Pushes an element onto the back of a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDAnyConst element` - Pointer to the new element.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_deque_push_back(
+    NSTDDeque *const deque,
+    const NSTDAnyConst element);
+
+/// Pushes an element onto the front of a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDAnyConst element` - Pointer to the new element.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_deque_push_front(
+    NSTDDeque *const deque,
+    const NSTDAnyConst element);
+
+/// Pops a value off of the back of a deque and returns a pointer to it.
+///
+/// # Note
+///
+/// The returned element pointer is only valid until the next element is pushed onto the deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - The element that was removed, null if the deque is empty.
+NSTDAPI NSTDAny nstd_collections_deque_pop_back(NSTDDeque *const deque);
+
+/// Pops a value off of the front of a deque and returns a pointer to it.
+///
+/// # Note
+///
+/// The returned element pointer is only valid until the next element is pushed onto the deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - The element that was removed, null if the deque is empty.
+NSTDAPI NSTDAny nstd_collections_deque_pop_front(NSTDDeque *const deque);
+
+/// Returns the contents of a deque, front to back, as two slices.
+///
+/// # Note
+///
+/// `second` will be empty if the deque's elements are contiguous in memory.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// - `NSTDSlice *const first` - Returns as the front part of the deque.
+///
+/// - `NSTDSlice *const second` - Returns as the back part of the deque.
+NSTDAPI void nstd_collections_deque_as_slices(
+    const NSTDDeque *const deque,
+    NSTDSlice *const first,
+    NSTDSlice *const second);
+
+/// Returns the number of elements in a deque.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The length of the deque.
+NSTDAPI NSTDUSize nstd_collections_deque_len(const NSTDDeque *const deque);
+
+/// Clears the contents of a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+NSTDAPI void nstd_collections_deque_clear(NSTDDeque *const deque);
+
+/// Reserves memory for the deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDUSize new_cap` - The new, greater capacity for the deque.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_deque_reserve(
+    NSTDDeque *const deque,
+    const NSTDUSize new_cap);
+
+/// Frees a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_deque_free(NSTDDeque *const deque);
+
+NSTDCPPEND
+#endif
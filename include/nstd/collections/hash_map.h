@@ -0,0 +1,239 @@
+#ifndef NSTD_COLLECTIONS_HASH_MAP_H_INCLUDED
+#define NSTD_COLLECTIONS_HASH_MAP_H_INCLUDED
+#include "../core/def.h"
+#include "../core/str.h"
+#include "../nstd.h"
+#include "../vec.h"
+NSTDCPPSTART
+
+/// An unordered map of keys to values.
+typedef struct
+{
+    /// The number of entries in the map.
+    NSTDUSize size;
+    /// The number of slots that have been marked as removed.
+    NSTDUSize removed;
+    /// The state of each slot, one byte per slot.
+    NSTDVec states;
+    /// The key stored in each slot, `keys.buffer.ptr.size` is the size of each key.
+    NSTDVec keys;
+    /// The value stored in each slot, `values.buffer.ptr.size` is the size of each value.
+    NSTDVec values;
+    /// Hashes a key.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst key` - A pointer to the key.
+    ///
+    /// - `NSTDUSize key_size` - The size of the key in bytes.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDUInt64 hash` - The key's hash.
+    NSTDUInt64 (*hash)(NSTDAnyConst, NSTDUSize);
+    /// Checks if two keys are equal.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst key1` - A pointer to the first key.
+    ///
+    /// - `NSTDAnyConst key2` - A pointer to the second key.
+    ///
+    /// - `NSTDUSize key_size` - The size of each key in bytes.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDBool is_eq` - True if the two keys are equal.
+    NSTDBool (*compare)(NSTDAnyConst, NSTDAnyConst, NSTDUSize);
+} NSTDHashMap;
+
+/// Creates a new hash map.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize key_size` - The size of each key in the map.
+///
+/// - `const NSTDUSize value_size` - The size of each value in the map.
+///
+/// - `NSTDUInt64 (*hash)(NSTDAnyConst, NSTDUSize)` - The function used to hash keys.
+///
+/// - `NSTDBool (*compare)(NSTDAnyConst, NSTDAnyConst, NSTDUSize)` - Checks keys for equality.
+///
+/// # Returns
+///
+/// `NSTDHashMap map` - The new hash map.
+NSTDAPI NSTDHashMap nstd_collections_hash_map_new(
+    const NSTDUSize key_size,
+    const NSTDUSize value_size,
+    NSTDUInt64 (*hash)(NSTDAnyConst, NSTDUSize),
+    NSTDBool (*compare)(NSTDAnyConst, NSTDAnyConst, NSTDUSize));
+
+/// Inserts a key-value pair into a hash map, overwriting the value if the key already exists.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to copy into the map.
+///
+/// - `const NSTDAnyConst value` - A pointer to the value to copy into the map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_hash_map_insert(
+    NSTDHashMap *const map,
+    const NSTDAnyConst key,
+    const NSTDAnyConst value);
+
+/// Gets a pointer to the value associated with `key`.
+///
+/// # Note
+///
+/// The returned value pointer can quickly become a dangling pointer if the map's memory gets
+/// reallocated or deallocated, so it is advised to create a copy of the value after getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDHashMap *const map` - The hash map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key.
+///
+/// # Returns
+///
+/// `NSTDAny value` - Pointer to the value, null if `key` is not in the map.
+NSTDAPI NSTDAny nstd_collections_hash_map_get(
+    const NSTDHashMap *const map,
+    const NSTDAnyConst key);
+
+/// Removes a key-value pair from a hash map.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key of the entry to remove.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `key` is not in the map.
+NSTDAPI NSTDErrorCode nstd_collections_hash_map_remove(
+    NSTDHashMap *const map,
+    const NSTDAnyConst key);
+
+/// Returns the number of entries in a hash map.
+///
+/// # Parameters
+///
+/// - `const NSTDHashMap *const map` - The hash map.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of entries in the map.
+NSTDAPI NSTDUSize nstd_collections_hash_map_len(const NSTDHashMap *const map);
+
+/// Removes all entries from a hash map without freeing it's memory.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+NSTDAPI void nstd_collections_hash_map_clear(NSTDHashMap *const map);
+
+/// Calls `callback` with each key-value pair in a hash map, in no particular order.
+///
+/// # Parameters
+///
+/// - `const NSTDHashMap *const map` - The hash map.
+///
+/// - `void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny)` - Called with each key, value and `data`.
+///
+/// - `NSTDAny data` - User data to pass to `callback`.
+NSTDAPI void nstd_collections_hash_map_iterate(
+    const NSTDHashMap *const map,
+    void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny),
+    NSTDAny data);
+
+/// Frees a hash map.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_hash_map_free(NSTDHashMap *const map);
+
+/// Hashes a key byte-by-byte using FNV-1a.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key` - A pointer to the key.
+///
+/// - `const NSTDUSize key_size` - The size of the key in bytes.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The key's hash.
+NSTDAPI NSTDUInt64 nstd_collections_hash_map_hash_bytes(
+    const NSTDAnyConst key,
+    const NSTDUSize key_size);
+
+/// Checks if two keys contain the same bytes.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key1` - A pointer to the first key.
+///
+/// - `const NSTDAnyConst key2` - A pointer to the second key.
+///
+/// - `const NSTDUSize key_size` - The size of each key in bytes.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - True if the two keys are equal.
+NSTDAPI NSTDBool nstd_collections_hash_map_compare_bytes(
+    const NSTDAnyConst key1,
+    const NSTDAnyConst key2,
+    const NSTDUSize key_size);
+
+/// Hashes an `NSTDStr` key by the string data it views.
+///
+/// # Note
+///
+/// Only the `NSTDStr` view is copied into the map, the string data it points to must outlive the
+/// map's entry.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key` - A pointer to the `NSTDStr` key.
+///
+/// - `const NSTDUSize key_size` - The size of the key, `sizeof(NSTDStr)`.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The key's hash.
+NSTDAPI NSTDUInt64 nstd_collections_hash_map_hash_str(
+    const NSTDAnyConst key,
+    const NSTDUSize key_size);
+
+/// Checks if two `NSTDStr` keys view the same string data.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key1` - A pointer to the first `NSTDStr` key.
+///
+/// - `const NSTDAnyConst key2` - A pointer to the second `NSTDStr` key.
+///
+/// - `const NSTDUSize key_size` - The size of each key, `sizeof(NSTDStr)`.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - True if the two keys are equal.
+NSTDAPI NSTDBool nstd_collections_hash_map_compare_str(
+    const NSTDAnyConst key1,
+    const NSTDAnyConst key2,
+    const NSTDUSize key_size);
+
+NSTDCPPEND
+#endif
@@ -0,0 +1,234 @@
+#ifndef NSTD_COLLECTIONS_ORDERED_MAP_H_INCLUDED
+#define NSTD_COLLECTIONS_ORDERED_MAP_H_INCLUDED
+#include "../core/def.h"
+#include "../nstd.h"
+NSTDCPPSTART
+
+/// A key-value pair in an ordered map.
+typedef struct
+{
+    /// A pointer to the entry's key, null if there is no such entry.
+    NSTDAnyConst key;
+    /// A pointer to the entry's value, null if there is no such entry.
+    NSTDAny value;
+} NSTDOrderedMapEntry;
+
+/// A sorted map of keys to values backed by a B-tree.
+typedef struct
+{
+    /// The number of entries in the map.
+    NSTDUSize size;
+    /// The size of each key in the map.
+    NSTDUSize key_size;
+    /// The size of each value in the map.
+    NSTDUSize value_size;
+    /// A pointer to the root node of the B-tree, null if nothing has been inserted yet.
+    NSTDAny root;
+    /// Compares two keys.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst key1` - A pointer to the first key.
+    ///
+    /// - `NSTDAnyConst key2` - A pointer to the second key.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDInt32 ordering` - Negative if `key1` is less than `key2`, 0 if they are equal and
+    /// positive if `key1` is greater than `key2`.
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst);
+} NSTDOrderedMap;
+
+/// Creates a new ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize key_size` - The size of each key in the map.
+///
+/// - `const NSTDUSize value_size` - The size of each value in the map.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - The function used to order keys.
+///
+/// # Returns
+///
+/// `NSTDOrderedMap map` - The new ordered map.
+NSTDAPI NSTDOrderedMap nstd_collections_ordered_map_new(
+    const NSTDUSize key_size,
+    const NSTDUSize value_size,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Inserts a key-value pair into an ordered map, overwriting the value if the key already exists.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to copy into the map.
+///
+/// - `const NSTDAnyConst value` - A pointer to the value to copy into the map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_ordered_map_insert(
+    NSTDOrderedMap *const map,
+    const NSTDAnyConst key,
+    const NSTDAnyConst value);
+
+/// Gets a pointer to the value associated with `key`.
+///
+/// # Note
+///
+/// The returned value pointer can quickly become a dangling pointer if the map is modified, so it
+/// is advised to create a copy of the value after getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key.
+///
+/// # Returns
+///
+/// `NSTDAny value` - Pointer to the value, null if `key` is not in the map.
+NSTDAPI NSTDAny nstd_collections_ordered_map_get(
+    const NSTDOrderedMap *const map,
+    const NSTDAnyConst key);
+
+/// Removes a key-value pair from an ordered map.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key of the entry to remove.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `key` is not in the map or on error.
+NSTDAPI NSTDErrorCode nstd_collections_ordered_map_remove(
+    NSTDOrderedMap *const map,
+    const NSTDAnyConst key);
+
+/// Returns the number of entries in an ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of entries in the map.
+NSTDAPI NSTDUSize nstd_collections_ordered_map_len(const NSTDOrderedMap *const map);
+
+/// Returns the entry with the smallest key in an ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry first` - The first entry, null pointers if the map is empty.
+NSTDAPI NSTDOrderedMapEntry nstd_collections_ordered_map_first(const NSTDOrderedMap *const map);
+
+/// Returns the entry with the largest key in an ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry last` - The last entry, null pointers if the map is empty.
+NSTDAPI NSTDOrderedMapEntry nstd_collections_ordered_map_last(const NSTDOrderedMap *const map);
+
+/// Returns the first entry whose key is not less than `key`.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry entry` - The lower bound entry, null pointers if there is none.
+NSTDAPI NSTDOrderedMapEntry nstd_collections_ordered_map_lower_bound(
+    const NSTDOrderedMap *const map,
+    const NSTDAnyConst key);
+
+/// Returns the first entry whose key is greater than `key`.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry entry` - The upper bound entry, null pointers if there is none.
+NSTDAPI NSTDOrderedMapEntry nstd_collections_ordered_map_upper_bound(
+    const NSTDOrderedMap *const map,
+    const NSTDAnyConst key);
+
+/// Calls `callback` with each key-value pair in an ordered map, in ascending key order.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny)` - Called with each key, value and `data`.
+///
+/// - `NSTDAny data` - User data to pass to `callback`.
+NSTDAPI void nstd_collections_ordered_map_iterate(
+    const NSTDOrderedMap *const map,
+    void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny),
+    NSTDAny data);
+
+/// Calls `callback` with each key-value pair in an ordered map whose key is within the range
+/// `[start, end)`, in ascending key order.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst start` - The start of the key range (included), null for no lower bound.
+///
+/// - `const NSTDAnyConst end` - The end of the key range (excluded), null for no upper bound.
+///
+/// - `void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny)` - Called with each key, value and `data`.
+///
+/// - `NSTDAny data` - User data to pass to `callback`.
+NSTDAPI void nstd_collections_ordered_map_iterate_range(
+    const NSTDOrderedMap *const map,
+    const NSTDAnyConst start,
+    const NSTDAnyConst end,
+    void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny),
+    NSTDAny data);
+
+/// Removes all entries from an ordered map, freeing it's nodes.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_ordered_map_clear(NSTDOrderedMap *const map);
+
+/// Frees an ordered map.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_ordered_map_free(NSTDOrderedMap *const map);
+
+NSTDCPPEND
+#endif
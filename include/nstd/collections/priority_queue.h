@@ -0,0 +1,200 @@
+#ifndef NSTD_COLLECTIONS_PRIORITY_QUEUE_H_INCLUDED
+#define NSTD_COLLECTIONS_PRIORITY_QUEUE_H_INCLUDED
+#include "../core/def.h"
+#include "../core/slice.h"
+#include "../nstd.h"
+#include "../vec.h"
+NSTDCPPSTART
+
+/// A priority queue backed by a binary heap.
+///
+/// The element that compares the least is kept at the front of the queue.
+typedef struct
+{
+    /// The heap of elements.
+    NSTDVec buffer;
+    /// The handle of each element in `buffer`.
+    NSTDVec handles;
+    /// The position in `buffer` of each handle, -1/usize::MAX if the handle is not in use.
+    NSTDVec positions;
+    /// Handles that have been released and can be reused.
+    NSTDVec free_handles;
+    /// Compares two elements.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst element1` - A pointer to the first element.
+    ///
+    /// - `NSTDAnyConst element2` - A pointer to the second element.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDInt32 ordering` - Negative if `element1` is less than `element2`, 0 if they are equal
+    /// and positive if `element1` is greater than `element2`.
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst);
+} NSTDPriorityQueue;
+
+/// Creates a new priority queue.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the queue.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - The function used to order elements.
+///
+/// # Returns
+///
+/// `NSTDPriorityQueue queue` - The new priority queue.
+NSTDAPI NSTDPriorityQueue nstd_collections_priority_queue_new(
+    const NSTDUSize element_size,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Creates a new priority queue from the elements in a slice.
+///
+/// # Note
+///
+/// The handle of each element is it's index in `slice`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The elements to build the queue out of.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - The function used to order elements.
+///
+/// # Returns
+///
+/// `NSTDPriorityQueue queue` - The new priority queue.
+NSTDAPI NSTDPriorityQueue nstd_collections_priority_queue_from_slice(
+    const NSTDSlice *const slice,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Pushes an element onto a priority queue.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDAnyConst element` - A pointer to the element to push onto the queue.
+///
+/// - `NSTDUSize *const handle` - Returns as the element's handle.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_priority_queue_push(
+    NSTDPriorityQueue *const queue,
+    const NSTDAnyConst element,
+    NSTDUSize *const handle);
+
+/// Returns a pointer to the element at the front of a priority queue.
+///
+/// # Note
+///
+/// The returned element pointer can quickly become a dangling pointer if the queue is modified,
+/// so it is advised to create a copy of the element after getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDAny front` - The element at the front of the queue, null if the queue is empty.
+NSTDAPI NSTDAny nstd_collections_priority_queue_peek(const NSTDPriorityQueue *const queue);
+
+/// Removes the element at the front of a priority queue and returns a pointer to it.
+///
+/// # Note
+///
+/// The returned element pointer is only valid until the next element is pushed onto the queue.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDAny front` - The element that was removed, null if the queue is empty.
+NSTDAPI NSTDAny nstd_collections_priority_queue_pop(NSTDPriorityQueue *const queue);
+
+/// Returns a pointer to the element referred to by `handle`.
+///
+/// # Note
+///
+/// This function follows the same behaviour rules as `nstd_collections_priority_queue_peek`.
+///
+/// # Parameters
+///
+/// - `const NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDUSize handle` - The element's handle.
+///
+/// # Returns
+///
+/// `NSTDAny element` - The element, null if `handle` does not refer to an element in the queue.
+NSTDAPI NSTDAny nstd_collections_priority_queue_get(
+    const NSTDPriorityQueue *const queue,
+    const NSTDUSize handle);
+
+/// Replaces the element referred to by `handle`, moving it to it's new place in the queue.
+///
+/// # Note
+///
+/// This can be used to both decrease and increase an element's priority.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDUSize handle` - The element's handle.
+///
+/// - `const NSTDAnyConst element` - A pointer to the new element.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `handle` does not refer to an element in the queue.
+NSTDAPI NSTDErrorCode nstd_collections_priority_queue_update(
+    NSTDPriorityQueue *const queue,
+    const NSTDUSize handle,
+    const NSTDAnyConst element);
+
+/// Removes the element referred to by `handle` from a priority queue, releasing the handle.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDUSize handle` - The element's handle.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `handle` does not refer to an element in the queue or on
+/// error.
+NSTDAPI NSTDErrorCode nstd_collections_priority_queue_remove(
+    NSTDPriorityQueue *const queue,
+    const NSTDUSize handle);
+
+/// Returns the number of elements in a priority queue.
+///
+/// # Parameters
+///
+/// - `const NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of elements in the queue.
+NSTDAPI NSTDUSize nstd_collections_priority_queue_len(const NSTDPriorityQueue *const queue);
+
+/// Frees a priority queue.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_priority_queue_free(NSTDPriorityQueue *const queue);
+
+NSTDCPPEND
+#endif
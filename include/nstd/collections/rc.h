@@ -10,8 +10,12 @@ typedef struct
 {
     /// The number of reference counters that own `data`.
     NSTDUSize count;
+    /// The number of weak references to this state.
+    NSTDUSize weak_count;
     /// The shared data.
     NSTDHeap data;
+    /// An optional function that is called with a pointer to the shared data before it is freed.
+    void (*drop)(NSTDAny);
 } NSTDRCState;
 
 /// A reference counter. A heap allocated type that can be shared across multiple objects, once the
@@ -22,6 +26,13 @@ typedef struct
     NSTDHeap state;
 } NSTDRC;
 
+/// A weak reference to the data owned by an `NSTDRC`. Weak references do not keep the data alive.
+typedef struct
+{
+    /// The reference counter state. Only free once `state.count` and `state.weak_count` == 0.
+    NSTDHeap state;
+} NSTDRCWeak;
+
 /// Creates a new reference counter.
 ///
 /// # Parameters
@@ -56,6 +67,15 @@ NSTDAPI NSTDRC nstd_collections_rc_share(const NSTDRC *const rc);
 /// `NSTDAny ptr` - A pointer to the underlying data.
 NSTDAPI NSTDAny nstd_collections_rc_get(const NSTDRC *const rc);
 
+/// Sets the function that is called with a pointer to the shared data before it is freed.
+///
+/// # Parameters
+///
+/// - `const NSTDRC *const rc` - A pointer to the reference counter.
+///
+/// - `void (*drop)(NSTDAny)` - The drop callback, null to disable dropping the data.
+NSTDAPI void nstd_collections_rc_set_drop(const NSTDRC *const rc, void (*drop)(NSTDAny));
+
 /// Frees a reference counter, only frees the underlying data once all other reference counters have
 /// been freed as well.
 ///
@@ -68,5 +88,43 @@ NSTDAPI NSTDAny nstd_collections_rc_get(const NSTDRC *const rc);
 /// `NSTDErrorCode errc` - Nonzero on error.
 NSTDAPI NSTDErrorCode nstd_collections_rc_free(NSTDRC *const rc);
 
+/// Creates a weak reference to a reference counter's data.
+///
+/// # Parameters
+///
+/// - `const NSTDRC *const rc` - A pointer to the reference counter.
+///
+/// # Returns
+///
+/// `NSTDRCWeak weak` - The new weak reference.
+NSTDAPI NSTDRCWeak nstd_collections_rc_downgrade(const NSTDRC *const rc);
+
+/// Attempts to create a new reference counter from a weak reference.
+///
+/// # Parameters
+///
+/// - `const NSTDRCWeak *const weak` - A pointer to the weak reference.
+///
+/// - `NSTDRC *const rc` - Returns as the new reference counter on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the data has already been freed.
+NSTDAPI NSTDErrorCode nstd_collections_rc_weak_upgrade(
+    const NSTDRCWeak *const weak,
+    NSTDRC *const rc);
+
+/// Frees a weak reference, the reference counter state is freed once there are no references to it
+/// left.
+///
+/// # Parameters
+///
+/// - `NSTDRCWeak *const weak` - The weak reference to free.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_collections_rc_weak_free(NSTDRCWeak *const weak);
+
 NSTDCPPEND
 #endif
@@ -1,5 +1,6 @@
 #ifndef NSTD_COLLECTIONS_STACK_H_INCLUDED
 #define NSTD_COLLECTIONS_STACK_H_INCLUDED
+#include "../alloc/allocator.h"
 #include "../core/def.h"
 #include "../nstd.h"
 #include "../vec.h"
@@ -23,6 +24,36 @@ typedef struct
 /// `NSTDStack stack` - The new stack.
 NSTDAPI NSTDStack nstd_collections_stack_new(const NSTDUSize element_size);
 
+/// Creates a new stack that allocates it's memory with `allocator`.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element on the stack.
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDStack stack` - The new stack.
+NSTDAPI NSTDStack nstd_collections_stack_new_in(
+    const NSTDUSize element_size,
+    NSTDAllocator *const allocator);
+
+/// Sets the function that is called with a pointer to each element that is dropped by the stack.
+///
+/// # Note
+///
+/// Elements are dropped when the stack is freed or cleared, and by
+/// `nstd_collections_stack_pop_discard`. Elements returned by `nstd_collections_stack_pop` are not
+/// dropped.
+///
+/// # Parameters
+///
+/// - `NSTDStack *const stack` - Pointer to the stack.
+///
+/// - `void (*drop)(NSTDAny)` - The drop callback, null to disable dropping elements.
+NSTDAPI void nstd_collections_stack_set_drop(NSTDStack *const stack, void (*drop)(NSTDAny));
+
 /// Gets a pointer to the value at the top of the stack.
 ///
 /// # Note
@@ -68,6 +99,17 @@ NSTDAPI NSTDErrorCode nstd_collections_stack_push(
 /// `NSTDAny popped` - The value that was popped off the stack.
 NSTDAPI NSTDAny nstd_collections_stack_pop(NSTDStack *const stack);
 
+/// Pops a value off of the stack and drops it.
+///
+/// # Parameters
+///
+/// - `NSTDStack *const stack` - Pointer to the stack.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the stack is empty.
+NSTDAPI NSTDErrorCode nstd_collections_stack_pop_discard(NSTDStack *const stack);
+
 /// Pops all values off the stack.
 ///
 /// # Parameters
@@ -62,5 +62,39 @@ NSTDAPI NSTDUSize nstd_core_cstr_len_with_null(const NSTDChar *const cstr);
 /// `NSTDBool is_eq` - `NSTD_BOOL_TRUE` if the two strings are lexicographically equal.
 NSTDAPI NSTDBool nstd_core_cstr_compare(const NSTDChar *cstr1, const NSTDChar *cstr2);
 
+/// Orders two C strings lexicographically, comparing their characters as unsigned bytes.
+///
+/// # Parameters
+///
+/// - `const NSTDChar *cstr1` - The first C string.
+///
+/// - `const NSTDChar *cstr2` - The second C string.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `cstr1` is less than `cstr2`, 0 if they are equal and
+/// a positive value if `cstr1` is greater than `cstr2`.
+NSTDAPI NSTDInt32 nstd_core_cstr_order(const NSTDChar *cstr1, const NSTDChar *cstr2);
+
+/// Orders two C strings naturally, so that "file9" is ordered before "file10".
+///
+/// # Note
+///
+/// Runs of ASCII digits are compared by their numeric value, everything else is compared as
+/// unsigned bytes. Numbers that only differ by leading zeros are ordered by the number of leading
+/// zeros.
+///
+/// # Parameters
+///
+/// - `const NSTDChar *cstr1` - The first C string.
+///
+/// - `const NSTDChar *cstr2` - The second C string.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `cstr1` is less than `cstr2`, 0 if they are equal and
+/// a positive value if `cstr1` is greater than `cstr2`.
+NSTDAPI NSTDInt32 nstd_core_cstr_order_natural(const NSTDChar *cstr1, const NSTDChar *cstr2);
+
 NSTDCPPEND
 #endif
@@ -95,6 +95,49 @@ NSTDAPI NSTDAny nstd_core_slice_last(const NSTDSlice *const slice);
 /// `NSTDBool is_same` - True if the two slices carry the same data.
 NSTDAPI NSTDBool nstd_core_slice_compare(const NSTDSlice *const s1, const NSTDSlice *const s2);
 
+/// Orders two slices lexicographically by their bytes.
+///
+/// # Note
+///
+/// A slice that is a prefix of the other slice is ordered first.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const s1` - The first slice.
+///
+/// - `const NSTDSlice *const s2` - The second slice.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `s1` is less than `s2`, 0 if they are equal and a
+/// positive value if `s1` is greater than `s2`.
+NSTDAPI NSTDInt32 nstd_core_slice_order(const NSTDSlice *const s1, const NSTDSlice *const s2);
+
+/// Orders two slices lexicographically with a comparator function.
+///
+/// # Note
+///
+/// `compare` returns a negative value if the first element is less than the second, 0 if they are
+/// equal and a positive value if the first element is greater than the second. A slice that is a
+/// prefix of the other slice is ordered first.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const s1` - The first slice.
+///
+/// - `const NSTDSlice *const s2` - The second slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `s1` is less than `s2`, 0 if they are equal and a
+/// positive value if `s1` is greater than `s2`.
+NSTDAPI NSTDInt32 nstd_core_slice_order_by(
+    const NSTDSlice *const s1,
+    const NSTDSlice *const s2,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
 /// Checks if a slice contains `element`.
 ///
 /// # Parameters
@@ -244,6 +287,24 @@ NSTDAPI void nstd_core_slice_shift_right(NSTDSlice *const slice, const NSTDUSize
 /// - `const NSTDUSize x` - Number of times to shift the slice.
 NSTDAPI void nstd_core_slice_shift_left(NSTDSlice *const slice, const NSTDUSize x);
 
+/// Rotates a slice's elements `x` places to the right in place.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize x` - Number of places to rotate the slice.
+NSTDAPI void nstd_core_slice_rotate_right(NSTDSlice *const slice, const NSTDUSize x);
+
+/// Rotates a slice's elements `x` places to the left in place.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize x` - Number of places to rotate the slice.
+NSTDAPI void nstd_core_slice_rotate_left(NSTDSlice *const slice, const NSTDUSize x);
+
 /// Copies elements from `s2` to `s1`. The slices must be the same size in bytes.
 ///
 /// # Parameters
@@ -271,5 +332,284 @@ NSTDAPI void nstd_core_slice_swap_with_slice(NSTDSlice *const s1, NSTDSlice *con
 /// - `NSTDSlice *const s2` - The second slice.
 NSTDAPI void nstd_core_slice_move(NSTDSlice *const s1, NSTDSlice *const s2);
 
+/// Sorts a slice with a comparator function, keeping equal elements in their original order.
+///
+/// # Note
+///
+/// `compare` returns a negative value if the first element is less than the second, 0 if they are
+/// equal and a positive value if the first element is greater than the second.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+NSTDAPI void nstd_core_slice_sort(
+    NSTDSlice *const slice,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Sorts a slice with a comparator function, equal elements may be reordered.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+NSTDAPI void nstd_core_slice_sort_unstable(
+    NSTDSlice *const slice,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Sorts a slice by comparing the raw bytes of each element.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+NSTDAPI void nstd_core_slice_sort_bytes(NSTDSlice *const slice);
+
+/// Checks if a slice is sorted.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+///
+/// # Returns
+///
+/// `NSTDBool is_sorted` - True if no element is less than the element before it.
+NSTDAPI NSTDBool nstd_core_slice_is_sorted(
+    const NSTDSlice *const slice,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Searches a sorted slice for `element`.
+///
+/// # Note
+///
+/// `index` is set to the index of the first element that is not less than `element`, which is
+/// where `element` would be inserted to keep the slice sorted.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The sorted slice.
+///
+/// - `const NSTDAnyConst element` - The element to search for.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+///
+/// - `NSTDUSize *const index` - Returns as the found or insertion index.
+///
+/// # Returns
+///
+/// `NSTDBool found` - True if the element at `index` is equal to `element`.
+NSTDAPI NSTDBool nstd_core_slice_binary_search(
+    const NSTDSlice *const slice,
+    const NSTDAnyConst element,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst),
+    NSTDUSize *const index);
+
+/// Moves every element that matches `predicate` to the front of a slice.
+///
+/// # Note
+///
+/// The order of the elements is not preserved.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDBool (*predicate)(NSTDAnyConst, NSTDAny)` - Returns true for elements to move.
+///
+/// - `NSTDAny data` - Data to pass to `predicate`.
+///
+/// # Returns
+///
+/// `NSTDUSize count` - The number of elements that matched `predicate`.
+NSTDAPI NSTDUSize nstd_core_slice_partition(
+    NSTDSlice *const slice,
+    NSTDBool (*predicate)(NSTDAnyConst, NSTDAny),
+    NSTDAny data);
+
+/// Reorders a slice so that the element at `n` is the element that would be there if the slice
+/// was sorted, every element before it is not greater and every element after it is not less.
+///
+/// # Note
+///
+/// Nothing is done if `n` is out of bounds.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize n` - The index of the element to select.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+NSTDAPI void nstd_core_slice_select_nth(
+    NSTDSlice *const slice,
+    const NSTDUSize n,
+    NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst));
+
+/// Creates a view into the elements of a slice that are within `range`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDURange *const range` - The range of elements to view.
+///
+/// - `NSTDSlice *const subslice` - Returns as the view into `slice`.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `range` is not within the slice's bounds.
+NSTDAPI NSTDErrorCode nstd_core_slice_subslice(
+    const NSTDSlice *const slice,
+    const NSTDURange *const range,
+    NSTDSlice *const subslice);
+
+/// Splits a slice into two views at `index`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize index` - The index to split at, this element starts the second view.
+///
+/// - `NSTDSlice *const first` - Returns as a view into the elements before `index`.
+///
+/// - `NSTDSlice *const second` - Returns as a view into the elements from `index` onward.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `index` is greater than the slice's length.
+NSTDAPI NSTDErrorCode nstd_core_slice_split_at(
+    const NSTDSlice *const slice,
+    const NSTDUSize index,
+    NSTDSlice *const first,
+    NSTDSlice *const second);
+
+/// Splits a slice on the first occurrence of `delimiter`.
+///
+/// # Note
+///
+/// To split on every occurrence of `delimiter`, call this again with `rest` until it returns
+/// false, at which point `first` holds the last part of the slice.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDAnyConst delimiter` - The element to split on.
+///
+/// - `NSTDSlice *const first` - Returns as a view into the elements before `delimiter`.
+///
+/// - `NSTDSlice *const rest` - Returns as a view into the elements after `delimiter`.
+///
+/// # Returns
+///
+/// `NSTDBool found` - True if `delimiter` was found, otherwise `first` views the whole slice and
+/// `rest` is empty.
+NSTDAPI NSTDBool nstd_core_slice_split(
+    const NSTDSlice *const slice,
+    const NSTDAnyConst delimiter,
+    NSTDSlice *const first,
+    NSTDSlice *const rest);
+
+/// Returns the number of chunks a slice is divided into by `nstd_core_slice_chunk`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize chunk_size` - The number of elements in each chunk.
+///
+/// # Returns
+///
+/// `NSTDUSize count` - The number of chunks, 0 if `chunk_size` is 0.
+NSTDAPI NSTDUSize nstd_core_slice_chunk_count(
+    const NSTDSlice *const slice,
+    const NSTDUSize chunk_size);
+
+/// Creates a view into the chunk at `index` when a slice is divided into chunks of `chunk_size`
+/// elements.
+///
+/// # Note
+///
+/// The last chunk will be shorter than `chunk_size` if the slice's length is not a multiple of
+/// `chunk_size`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize chunk_size` - The number of elements in each chunk.
+///
+/// - `const NSTDUSize index` - The index of the chunk.
+///
+/// - `NSTDSlice *const chunk` - Returns as the view into the chunk.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if there is no chunk at `index`.
+NSTDAPI NSTDErrorCode nstd_core_slice_chunk(
+    const NSTDSlice *const slice,
+    const NSTDUSize chunk_size,
+    const NSTDUSize index,
+    NSTDSlice *const chunk);
+
+/// Returns the number of windows of `window_size` elements in a slice.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize window_size` - The number of elements in each window.
+///
+/// # Returns
+///
+/// `NSTDUSize count` - The number of windows, 0 if `window_size` is 0 or greater than the slice's
+/// length.
+NSTDAPI NSTDUSize nstd_core_slice_window_count(
+    const NSTDSlice *const slice,
+    const NSTDUSize window_size);
+
+/// Creates a view into the `window_size` elements starting at `index`, sliding one element at a
+/// time.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize window_size` - The number of elements in each window.
+///
+/// - `const NSTDUSize index` - The index of the window.
+///
+/// - `NSTDSlice *const window` - Returns as the view into the window.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if there is no window at `index`.
+NSTDAPI NSTDErrorCode nstd_core_slice_window(
+    const NSTDSlice *const slice,
+    const NSTDUSize window_size,
+    const NSTDUSize index,
+    NSTDSlice *const window);
+
+/// Removes consecutive repeated elements from a slice by moving the first element of each run to
+/// the front of the slice.
+///
+/// # Note
+///
+/// Elements are compared byte for byte. The elements after the returned length are left in an
+/// unspecified order.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of elements left at the front of the slice.
+NSTDAPI NSTDUSize nstd_core_slice_dedup(NSTDSlice *const slice);
+
 NSTDCPPEND
 #endif
@@ -13,6 +13,125 @@ typedef struct
     NSTDSlice bytes;
 } NSTDStr;
 
+/// An iterator over the characters of a string slice.
+///
+/// # Note
+///
+/// The iterator can be advanced from either end, iteration stops once the two ends meet.
+typedef struct
+{
+    /// The string slice being iterated over.
+    NSTDStr str;
+    /// The byte offset of the next character to yield from the front.
+    NSTDUSize front;
+    /// The byte offset just past the next character to yield from the back.
+    NSTDUSize back;
+} NSTDStrChars;
+
+/// Describes how an [NSTDStrSplit] iterator separates a string slice.
+typedef enum
+{
+    /// Splits on each occurrence of a pattern.
+    NSTD_STR_SPLIT_KIND_PATTERN,
+    /// Splits on runs of Unicode whitespace, never yielding empty substrings.
+    NSTD_STR_SPLIT_KIND_WHITESPACE,
+    /// Splits on "\n" or "\r\n" line endings, the final line ending is optional.
+    NSTD_STR_SPLIT_KIND_LINES,
+} NSTDStrSplitKind;
+
+/// A lazy iterator over the substrings of a string slice.
+typedef struct
+{
+    /// The string slice being split.
+    NSTDStr str;
+    /// The separator, only used by `NSTD_STR_SPLIT_KIND_PATTERN`.
+    NSTDStr pattern;
+    /// How the string slice is separated.
+    NSTDStrSplitKind kind;
+    /// The byte offset of the remaining part of the string slice.
+    NSTDUSize position;
+    /// Set once the iterator has been exhausted.
+    NSTDBool finished;
+} NSTDStrSplit;
+
+/// Describes the Unicode text segments that an [NSTDStrSegments] iterator yields.
+typedef enum
+{
+    /// Extended grapheme clusters, the characters that a user perceives.
+    NSTD_STR_SEGMENT_KIND_GRAPHEME,
+    /// The text between word boundaries, including runs of whitespace and punctuation.
+    NSTD_STR_SEGMENT_KIND_WORD,
+    /// The text between sentence boundaries.
+    NSTD_STR_SEGMENT_KIND_SENTENCE,
+} NSTDStrSegmentKind;
+
+/// An iterator over the Unicode text segments of a string slice, as defined by UAX #29.
+///
+/// # Note
+///
+/// The iterator can be advanced from either end, iteration stops once the two ends meet. Segment
+/// iterators are only available with the `nstd_core_segment` feature.
+typedef struct
+{
+    /// The string slice being segmented.
+    NSTDStr str;
+    /// The kind of segments to yield.
+    NSTDStrSegmentKind kind;
+    /// The byte offset of the next segment to yield from the front.
+    NSTDUSize front;
+    /// The byte offset just past the next segment to yield from the back.
+    NSTDUSize back;
+} NSTDStrSegments;
+
+/// Describes why parsing a number from a string slice failed.
+typedef enum
+{
+    /// No error occurred.
+    NSTD_STR_PARSE_ERROR_NONE,
+    /// There were no digits to parse.
+    NSTD_STR_PARSE_ERROR_EMPTY,
+    /// A character that isn't a digit in the radix, or a misplaced sign or separator, was found.
+    NSTD_STR_PARSE_ERROR_INVALID_DIGIT,
+    /// The number is too large for the type.
+    NSTD_STR_PARSE_ERROR_POS_OVERFLOW,
+    /// The number is too small for the type.
+    NSTD_STR_PARSE_ERROR_NEG_OVERFLOW,
+    /// The radix isn't supported.
+    NSTD_STR_PARSE_ERROR_INVALID_RADIX,
+    /// A float with separators is too long to be parsed.
+    NSTD_STR_PARSE_ERROR_TOO_LONG,
+} NSTDStrParseErrorKind;
+
+/// Describes why and where parsing a number from a string slice failed.
+typedef struct
+{
+    /// Why parsing failed.
+    NSTDStrParseErrorKind kind;
+    /// The byte offset into the string slice where parsing failed, -1/usize::MAX on success.
+    NSTDUSize offset;
+} NSTDStrParseError;
+
+/// Options for parsing numbers from string slices.
+typedef struct
+{
+    /// The radix of integers, 2-36. 0 detects the radix from a "0x", "0o" or "0b" prefix and
+    /// otherwise uses 10. Floats must use 0 or 10.
+    NSTDUInt32 radix;
+    /// Whether integers in radix 16, 8 or 2 may start with their "0x", "0o" or "0b" prefix.
+    NSTDBool prefix;
+    /// A character that may appear between two digits and is ignored, such as '_', 0 for none.
+    NSTDUnichar separator;
+} NSTDStrParseOptions;
+
+/// Selects the language specific rules used for case conversions and comparisons.
+typedef enum
+{
+    /// The language independent Unicode case mappings.
+    NSTD_STR_CASE_LOCALE_DEFAULT,
+    /// Turkish and Azerbaijani, where `I` and `ı` are paired as are `İ` and `i`.
+    NSTD_STR_CASE_LOCALE_TURKIC,
+} NSTDStrCaseLocale;
+
 /// Creates a new `NSTDStr` from a cstring.
 ///
 /// # Parameters
@@ -81,6 +200,132 @@ NSTDAPI NSTDUSize nstd_core_str_byte_len(const NSTDStr *const str);
 /// `NSTDStr subslice` - The string subslice.
 NSTDAPI NSTDStr nstd_core_str_get(const NSTDStr *const str, const NSTDURange *const range);
 
+/// Returns an iterator over the characters of a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrChars chars` - The character iterator.
+NSTDAPI NSTDStrChars nstd_core_str_chars(const NSTDStr *const str);
+
+/// Yields the next character from the front of a character iterator.
+///
+/// # Note
+///
+/// Each byte that is not part of a valid UTF-8 sequence is yielded as U+FFFD.
+///
+/// # Parameters
+///
+/// - `NSTDStrChars *const chars` - The character iterator.
+///
+/// - `NSTDUnichar *const chr` - Returns as the next character.
+///
+/// - `NSTDUSize *const offset` - Returns as the byte offset of the next character.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+NSTDAPI NSTDBool nstd_core_str_chars_next(
+    NSTDStrChars *const chars,
+    NSTDUnichar *const chr,
+    NSTDUSize *const offset);
+
+/// Yields the next character from the back of a character iterator.
+///
+/// # Note
+///
+/// Each byte that is not part of a valid UTF-8 sequence is yielded as U+FFFD.
+///
+/// # Parameters
+///
+/// - `NSTDStrChars *const chars` - The character iterator.
+///
+/// - `NSTDUnichar *const chr` - Returns as the next character.
+///
+/// - `NSTDUSize *const offset` - Returns as the byte offset of the next character.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+NSTDAPI NSTDBool nstd_core_str_chars_next_back(
+    NSTDStrChars *const chars,
+    NSTDUnichar *const chr,
+    NSTDUSize *const offset);
+
+/// Gets the character at a character index of a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize n` - The index of the character.
+///
+/// - `NSTDUnichar *const chr` - Returns as the character on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `n` is out of bounds or `str` isn't valid UTF-8.
+NSTDAPI NSTDErrorCode nstd_core_str_nth_char(
+    const NSTDStr *const str,
+    const NSTDUSize n,
+    NSTDUnichar *const chr);
+
+/// Converts a character index into a byte index.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize char_index` - The character index, may be the string slice's length.
+///
+/// # Returns
+///
+/// `NSTDUSize byte_index` - The byte offset of the character, -1/usize::MAX if `char_index` is out
+/// of bounds or `str` isn't valid UTF-8.
+NSTDAPI NSTDUSize nstd_core_str_char_to_byte_index(
+    const NSTDStr *const str,
+    const NSTDUSize char_index);
+
+/// Converts a byte index into a character index.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize byte_index` - The byte index, may be the string slice's byte length.
+///
+/// # Returns
+///
+/// `NSTDUSize char_index` - The index of the character starting at `byte_index`, -1/usize::MAX if
+/// `byte_index` is out of bounds, is not on a character boundary or `str` isn't valid UTF-8.
+NSTDAPI NSTDUSize nstd_core_str_byte_to_char_index(
+    const NSTDStr *const str,
+    const NSTDUSize byte_index);
+
+/// Decodes the UTF-8 encoded character that starts at a byte offset of a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize offset` - The byte offset of the character.
+///
+/// - `NSTDUnichar *const chr` - Returns as the decoded character on success.
+///
+/// - `NSTDUSize *const len` - Returns as the number of bytes the character uses on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if there isn't a valid UTF-8 sequence at `offset`.
+NSTDAPI NSTDErrorCode nstd_core_str_decode_char(
+    const NSTDStr *const str,
+    const NSTDUSize offset,
+    NSTDUnichar *const chr,
+    NSTDUSize *const len);
+
 /// Checks if a string slice is entirely ASCII.
 ///
 /// # Parameters
@@ -105,6 +350,87 @@ NSTDAPI NSTDBool nstd_core_str_is_ascii(const NSTDStr *const str);
 /// `NSTDBool is_eq` - True if the two slices are equal.
 NSTDAPI NSTDBool nstd_core_str_compare(const NSTDStr *const str1, const NSTDStr *const str2);
 
+/// Orders two string slices lexicographically by Unicode scalar value.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `str1` is less than `str2`, 0 if they are equal and a
+/// positive value if `str1` is greater than `str2`.
+NSTDAPI NSTDInt32 nstd_core_str_order(const NSTDStr *const str1, const NSTDStr *const str2);
+
+/// Orders two string slices naturally, so that "file9" is ordered before "file10".
+///
+/// # Note
+///
+/// Runs of ASCII digits are compared by their numeric value, everything else is compared by
+/// Unicode scalar value. Numbers that only differ by leading zeros are ordered by the number of
+/// leading zeros.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `str1` is less than `str2`, 0 if they are equal and a
+/// positive value if `str1` is greater than `str2`.
+NSTDAPI NSTDInt32 nstd_core_str_order_natural(const NSTDStr *const str1, const NSTDStr *const str2);
+
+/// Checks if two string slices are equal when case is ignored.
+///
+/// # Note
+///
+/// The string slices are compared using full Unicode case folding, so "STRASSE" is equal to
+/// "straße". Invalid UTF-8 is compared byte for byte.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - True if the two slices are equal when case is ignored.
+NSTDAPI NSTDBool nstd_core_str_eq_ignore_case(
+    const NSTDStr *const str1,
+    const NSTDStr *const str2,
+    const NSTDStrCaseLocale locale);
+
+/// Orders two string slices when case is ignored.
+///
+/// # Note
+///
+/// The case folded string slices are ordered by Unicode scalar value. Invalid UTF-8 is compared
+/// byte for byte.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `str1` is less than `str2`, 0 if they are equal and a
+/// positive value if `str1` is greater than `str2`.
+NSTDAPI NSTDInt32 nstd_core_str_compare_ignore_case(
+    const NSTDStr *const str1,
+    const NSTDStr *const str2,
+    const NSTDStrCaseLocale locale);
+
 /// Checks if `str` contains `pattern`.
 ///
 /// # Parameters
@@ -170,6 +496,223 @@ NSTDAPI NSTDUSize nstd_core_str_find(const NSTDStr *const str, const NSTDStr *co
 /// `NSTDUSize pos` - The position of `pattern` in str.
 NSTDAPI NSTDUSize nstd_core_str_find_last(const NSTDStr *const str, const NSTDStr *const pattern);
 
+/// Returns an iterator over the substrings of a string slice separated by a pattern.
+///
+/// # Note
+///
+/// Empty substrings are yielded between adjacent separators and at either end of `str`. An empty
+/// pattern never matches, so the whole string slice is yielded.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const pattern` - The separator.
+///
+/// # Returns
+///
+/// `NSTDStrSplit split` - The split iterator.
+NSTDAPI NSTDStrSplit nstd_core_str_split(const NSTDStr *const str, const NSTDStr *const pattern);
+
+/// Returns an iterator over the substrings of a string slice separated by Unicode whitespace.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSplit split` - The split iterator.
+NSTDAPI NSTDStrSplit nstd_core_str_split_whitespace(const NSTDStr *const str);
+
+/// Returns an iterator over the lines of a string slice, without their line endings.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSplit lines` - The line iterator.
+NSTDAPI NSTDStrSplit nstd_core_str_lines(const NSTDStr *const str);
+
+/// Yields the next substring from a split iterator.
+///
+/// # Parameters
+///
+/// - `NSTDStrSplit *const split` - The split iterator.
+///
+/// - `NSTDStr *const substr` - Returns as the next substring.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+NSTDAPI NSTDBool nstd_core_str_split_next(NSTDStrSplit *const split, NSTDStr *const substr);
+
+/// Returns an iterator over the extended grapheme clusters of a string slice.
+///
+/// # Note
+///
+/// Iteration stops at the first invalid UTF-8 sequence in `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSegments graphemes` - The grapheme cluster iterator.
+NSTDAPI NSTDStrSegments nstd_core_str_graphemes(const NSTDStr *const str);
+
+/// Returns an iterator over the segments of a string slice between word boundaries.
+///
+/// # Note
+///
+/// Iteration stops at the first invalid UTF-8 sequence in `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSegments words` - The word segment iterator.
+NSTDAPI NSTDStrSegments nstd_core_str_words(const NSTDStr *const str);
+
+/// Returns an iterator over the sentences of a string slice.
+///
+/// # Note
+///
+/// Iteration stops at the first invalid UTF-8 sequence in `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSegments sentences` - The sentence iterator.
+NSTDAPI NSTDStrSegments nstd_core_str_sentences(const NSTDStr *const str);
+
+/// Yields the next segment from the front of a segment iterator.
+///
+/// # Parameters
+///
+/// - `NSTDStrSegments *const segments` - The segment iterator.
+///
+/// - `NSTDStr *const segment` - Returns as the next segment.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+NSTDAPI NSTDBool nstd_core_str_segments_next(
+    NSTDStrSegments *const segments,
+    NSTDStr *const segment);
+
+/// Yields the next segment from the back of a segment iterator.
+///
+/// # Note
+///
+/// After a segment is yielded, `segments.back` is the byte offset of that segment. Iterating a
+/// string slice that ends at a text cursor from the back yields the grapheme cluster that a
+/// backspace should remove.
+///
+/// Sentence boundaries can only be found from the front, so each sentence yielded from the back
+/// takes time proportional to the length of it's paragraph, and iterating a whole paragraph from
+/// the back takes time proportional to the square of it's length.
+///
+/// # Parameters
+///
+/// - `NSTDStrSegments *const segments` - The segment iterator.
+///
+/// - `NSTDStr *const segment` - Returns as the next segment.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+NSTDAPI NSTDBool nstd_core_str_segments_next_back(
+    NSTDStrSegments *const segments,
+    NSTDStr *const segment);
+
+/// Returns a string slice with leading and trailing Unicode whitespace removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`.
+NSTDAPI NSTDStr nstd_core_str_trim(const NSTDStr *const str);
+
+/// Returns a string slice with leading Unicode whitespace removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`.
+NSTDAPI NSTDStr nstd_core_str_trim_start(const NSTDStr *const str);
+
+/// Returns a string slice with trailing Unicode whitespace removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`.
+NSTDAPI NSTDStr nstd_core_str_trim_end(const NSTDStr *const str);
+
+/// Returns a string slice with leading and trailing characters from a character set removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const chars` - The characters to remove.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`. This is all of `str` if
+/// `chars` isn't valid UTF-8.
+NSTDAPI NSTDStr nstd_core_str_trim_chars(const NSTDStr *const str, const NSTDStr *const chars);
+
+/// Returns a string slice with leading characters from a character set removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const chars` - The characters to remove.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`. This is all of `str` if
+/// `chars` isn't valid UTF-8.
+NSTDAPI NSTDStr nstd_core_str_trim_start_chars(
+    const NSTDStr *const str,
+    const NSTDStr *const chars);
+
+/// Returns a string slice with trailing characters from a character set removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const chars` - The characters to remove.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`. This is all of `str` if
+/// `chars` isn't valid UTF-8.
+NSTDAPI NSTDStr nstd_core_str_trim_end_chars(
+    const NSTDStr *const str,
+    const NSTDStr *const chars);
+
 /// Converts a string slice to ASCII uppercase in place.
 ///
 /// # Parameters
@@ -337,5 +880,238 @@ NSTDAPI NSTDISize nstd_core_str_to_isize(const NSTDStr *const str, NSTDErrorCode
 /// `NSTDUSize ul` - The `NSTDUSize` parsed from the string.
 NSTDAPI NSTDUSize nstd_core_str_to_usize(const NSTDStr *const str, NSTDErrorCode *const errc);
 
+/// Returns the default options for parsing numbers, decimal without prefixes or separators.
+///
+/// # Returns
+///
+/// `NSTDStrParseOptions options` - The default parsing options.
+NSTDAPI NSTDStrParseOptions nstd_core_str_parse_options_default();
+
+/// Parses an `NSTDInt8` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDInt8 num` - The parsed number, 0 on error.
+NSTDAPI NSTDInt8 nstd_core_str_parse_i8(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDUInt8` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDUInt8 num` - The parsed number, 0 on error.
+NSTDAPI NSTDUInt8 nstd_core_str_parse_u8(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDInt16` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDInt16 num` - The parsed number, 0 on error.
+NSTDAPI NSTDInt16 nstd_core_str_parse_i16(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDUInt16` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDUInt16 num` - The parsed number, 0 on error.
+NSTDAPI NSTDUInt16 nstd_core_str_parse_u16(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDInt32` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDInt32 num` - The parsed number, 0 on error.
+NSTDAPI NSTDInt32 nstd_core_str_parse_i32(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDUInt32` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDUInt32 num` - The parsed number, 0 on error.
+NSTDAPI NSTDUInt32 nstd_core_str_parse_u32(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDInt64` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDInt64 num` - The parsed number, 0 on error.
+NSTDAPI NSTDInt64 nstd_core_str_parse_i64(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDUInt64` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDUInt64 num` - The parsed number, 0 on error.
+NSTDAPI NSTDUInt64 nstd_core_str_parse_u64(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDISize` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDISize num` - The parsed number, 0 on error.
+NSTDAPI NSTDISize nstd_core_str_parse_isize(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDUSize` from a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDUSize num` - The parsed number, 0 on error.
+NSTDAPI NSTDUSize nstd_core_str_parse_usize(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDFloat32` from a string slice.
+///
+/// # Note
+///
+/// Floats are parsed in decimal, with an optional exponent, or as "inf", "infinity" or "nan" in any
+/// case. A finite number that is too large to represent is an overflow error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDFloat32 num` - The parsed number, 0 on error.
+NSTDAPI NSTDFloat32 nstd_core_str_parse_f32(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
+/// Parses an `NSTDFloat64` from a string slice.
+///
+/// # Note
+///
+/// Floats are parsed in decimal, with an optional exponent, or as "inf", "infinity" or "nan" in any
+/// case. A finite number that is too large to represent is an overflow error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice to parse.
+///
+/// - `const NSTDStrParseOptions *const options` - The radix, prefix and separator rules to use.
+///
+/// - `NSTDStrParseError *const error` - Returns as the reason and byte offset of a failure.
+///
+/// # Returns
+///
+/// `NSTDFloat64 num` - The parsed number, 0 on error.
+NSTDAPI NSTDFloat64 nstd_core_str_parse_f64(
+    const NSTDStr *const str,
+    const NSTDStrParseOptions *const options,
+    NSTDStrParseError *const error);
+
 NSTDCPPEND
 #endif
@@ -0,0 +1,261 @@
+#ifndef NSTD_FMT_H_INCLUDED
+#define NSTD_FMT_H_INCLUDED
+#include "core/def.h"
+#include "core/slice.h"
+#include "core/str.h"
+#include "io/output_stream.h"
+#include "nstd.h"
+#include "string.h"
+NSTDCPPSTART
+
+// printf-style string formatting.
+//
+// # Format specifications
+//
+// Each `%` in a format string starts a specification of the form
+// `%[flags][width][.precision][@radix]conversion`, "%%" writes a single `%`.
+//
+// ## Flags
+//
+// - `-` - Left align the output within the width.
+//
+// - `^` - Center the output within the width, extra fill goes to the right.
+//
+// - `+` - Always write a sign for signed numbers.
+//
+// - ` ` - Write a space in place of the sign for positive signed numbers.
+//
+// - `#` - Alternate form, prefixes hex with "0x", binary with "0b" and octal with "0o" and keeps
+//   the decimal point and trailing zeros of floats.
+//
+// - `0` - Pad numbers with zeros after the sign and prefix, ignored if the output is aligned or
+//   an integer has a precision.
+//
+// - `'c` - Pad with the character `c` instead of spaces.
+//
+// ## Width, precision and radix
+//
+// The width is the minimum number of characters to write. For integers the precision is the
+// minimum number of digits, for floats it is the number of digits after the decimal point
+// (significant digits for `g`) and for strings it is the maximum number of characters. The
+// width, precision and radix can be `*` to take them from the next argument, a negative width
+// left aligns and a negative precision is ignored. Widths and precisions greater than
+// `NSTD_FMT_MAX_COUNT` are errors.
+//
+// ## Conversions
+//
+// - `d`, `i` - Decimal integer.
+//
+// - `u` - Decimal integer without a sign, negative integers are reinterpreted as unsigned 64-bit
+//   integers, so -1 is written as 18446744073709551615.
+//
+// - `x`, `X` - Hexadecimal integer.
+//
+// - `o` - Octal integer.
+//
+// - `b` - Binary integer.
+//
+// - `r`, `R` - Integer in the radix given by `@radix`, which must be within 2-36.
+//
+// - `f`, `F` - Float in fixed notation.
+//
+// - `e`, `E` - Float in exponent notation.
+//
+// - `g`, `G` - Float in whichever of fixed or exponent notation is shorter.
+//
+// - `s` - String slice or C string.
+//
+// - `c` - Unicode character.
+//
+// - `p` - Pointer, in hexadecimal.
+//
+// Other conversions write negative integers as a sign followed by their magnitude. Upper case
+// conversions use upper case digits, exponents, prefixes, "INF" and "NAN".
+
+/// The largest width or precision that a format specification may have.
+#define NSTD_FMT_MAX_COUNT 32767
+
+/// Describes the type of a formatting argument.
+typedef enum
+{
+    /// A signed integer.
+    NSTD_FORMAT_ARG_KIND_INT,
+    /// An unsigned integer.
+    NSTD_FORMAT_ARG_KIND_UINT,
+    /// A float.
+    NSTD_FORMAT_ARG_KIND_FLOAT,
+    /// A string slice.
+    NSTD_FORMAT_ARG_KIND_STR,
+    /// A null terminated C string.
+    NSTD_FORMAT_ARG_KIND_CSTR,
+    /// A Unicode character.
+    NSTD_FORMAT_ARG_KIND_CHAR,
+    /// A pointer.
+    NSTD_FORMAT_ARG_KIND_PTR,
+} NSTDFormatArgKind;
+
+/// The value of a formatting argument, the active field is given by the argument's kind.
+typedef union
+{
+    /// A signed integer.
+    NSTDInt64 int64;
+    /// An unsigned integer.
+    NSTDUInt64 uint64;
+    /// A float.
+    NSTDFloat64 float64;
+    /// A string slice.
+    NSTDStr str;
+    /// A null terminated C string.
+    const NSTDChar *cstr;
+    /// A Unicode character.
+    NSTDUnichar chr;
+    /// A pointer.
+    NSTDAnyConst ptr;
+} NSTDFormatArgValue;
+
+/// A tagged formatting argument.
+typedef struct
+{
+    /// The type of the argument.
+    NSTDFormatArgKind kind;
+    /// The argument's value.
+    NSTDFormatArgValue value;
+} NSTDFormatArg;
+
+/// Creates a signed integer formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDInt64 value` - The argument's value.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_int(const NSTDInt64 value);
+
+/// Creates an unsigned integer formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDUInt64 value` - The argument's value.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_uint(const NSTDUInt64 value);
+
+/// Creates a float formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDFloat64 value` - The argument's value.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_float(const NSTDFloat64 value);
+
+/// Creates a C string formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDChar *const value` - The null terminated C string, it must outlive the argument.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_cstr(const NSTDChar *const value);
+
+/// Creates a Unicode character formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDUnichar value` - The argument's value.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_char(const NSTDUnichar value);
+
+/// Creates a pointer formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst value` - The argument's value.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_ptr(const NSTDAnyConst value);
+
+/// Creates a string slice formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice, it must outlive the argument.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+NSTDAPI NSTDFormatArg nstd_fmt_arg_str(const NSTDStr *const str);
+
+/// Formats arguments onto the end of a string.
+///
+/// # Note
+///
+/// See the module documentation for the format specification syntax. `string` is left unchanged
+/// on error.
+///
+/// # Parameters
+///
+/// - `NSTDString *const string` - The string to append the formatted text to.
+///
+/// - `const NSTDStr *const format` - The format string.
+///
+/// - `const NSTDSlice *const args` - A slice of `NSTDFormatArg`.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the invalid UTF-8 sequence or
+///   format specification in `format` on error, -1/usize::MAX on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_fmt_format(
+    NSTDString *const string,
+    const NSTDStr *const format,
+    const NSTDSlice *const args,
+    NSTDUSize *const error_offset);
+
+/// Formats arguments and writes them to an output stream.
+///
+/// # Note
+///
+/// See the module documentation for the format specification syntax. The formatted text is
+/// written with a single call to the stream's `write` function, nothing is written on error.
+///
+/// # Parameters
+///
+/// - `NSTDOutputStream *const stream` - The stream to write the formatted text to.
+///
+/// - `const NSTDAny owner` - The object that owns `stream`, such as an `NSTDFile`. This is passed
+///   to the stream's write function as `this`.
+///
+/// - `const NSTDStr *const format` - The format string.
+///
+/// - `const NSTDSlice *const args` - A slice of `NSTDFormatArg`.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the invalid UTF-8 sequence or
+///   format specification in `format` on error, -1/usize::MAX on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error, including errors that the stream sets while writing.
+NSTDAPI NSTDErrorCode nstd_fmt_write(
+    NSTDOutputStream *const stream,
+    const NSTDAny owner,
+    const NSTDStr *const format,
+    const NSTDSlice *const args,
+    NSTDUSize *const error_offset);
+
+NSTDCPPEND
+#endif
@@ -2,4 +2,5 @@
 #define NSTD_OS_LINUX_H_INCLUDED
 #include "../nstd.h"
 #include "linux/alloc.h"
+#include "linux/mem.h"
 #endif
@@ -48,5 +48,65 @@ NSTDAPI NSTDErrorCode nstd_os_linux_alloc_reallocate(NSTDAny *const ptr, const N
 /// - `NSTDAny *const ptr` - Pointer to the block of memory.
 NSTDAPI void nstd_os_linux_alloc_deallocate(NSTDAny *const ptr);
 
+/// Allocates a block of memory on the heap with a specific alignment.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the heap block of memory, null on error.
+NSTDAPI NSTDAny nstd_os_linux_alloc_allocate_aligned(const NSTDUSize size, const NSTDUSize align);
+
+/// Allocates a zero-initialized block of memory on the heap with a specific alignment.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the heap block of memory, null on error.
+NSTDAPI NSTDAny nstd_os_linux_alloc_allocate_zeroed_aligned(
+    const NSTDUSize size,
+    const NSTDUSize align);
+
+/// Reallocates a memory block with a new size, keeping it's alignment.
+///
+/// # Note
+///
+/// The memory block is always moved to a new allocation, `ptr` is left untouched on error.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the pointer to the memory block to reallocate.
+///
+/// - `const NSTDUSize size` - The current number of bytes in the memory block.
+///
+/// - `const NSTDUSize new_size` - The number of bytes the new memory block will have.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_alloc_reallocate_aligned(
+    NSTDAny *const ptr,
+    const NSTDUSize size,
+    const NSTDUSize new_size,
+    const NSTDUSize align);
+
+/// Deallocates a block of memory that was allocated with a specific alignment.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the block of memory.
+NSTDAPI void nstd_os_linux_alloc_deallocate_aligned(NSTDAny *const ptr);
+
 NSTDCPPEND
 #endif
@@ -0,0 +1,164 @@
+#ifndef NSTD_OS_LINUX_MEM_H_INCLUDED
+#define NSTD_OS_LINUX_MEM_H_INCLUDED
+#include "../../core/def.h"
+#include "../../nstd.h"
+NSTDCPPSTART
+
+/// A set of memory protection flags.
+typedef NSTDUInt32 NSTDOSLinuxMemProtection;
+
+/// The memory can't be accessed.
+#define NSTD_OS_LINUX_MEM_PROTECTION_NONE 0
+/// The memory can be read.
+#define NSTD_OS_LINUX_MEM_PROTECTION_READ 1
+/// The memory can be written to.
+#define NSTD_OS_LINUX_MEM_PROTECTION_WRITE (1 << 1)
+/// The memory can be executed.
+#define NSTD_OS_LINUX_MEM_PROTECTION_EXECUTE (1 << 2)
+
+/// Describes how a range of memory is expected to be used.
+typedef enum
+{
+    /// No special treatment.
+    NSTD_OS_LINUX_MEM_ADVICE_NORMAL,
+    /// The memory will be accessed in a random order.
+    NSTD_OS_LINUX_MEM_ADVICE_RANDOM,
+    /// The memory will be accessed in sequential order.
+    NSTD_OS_LINUX_MEM_ADVICE_SEQUENTIAL,
+    /// The memory will be accessed soon.
+    NSTD_OS_LINUX_MEM_ADVICE_WILL_NEED,
+    /// The memory won't be accessed soon, it's contents are discarded.
+    NSTD_OS_LINUX_MEM_ADVICE_DONT_NEED,
+    /// The memory's contents are no longer needed and may be discarded lazily.
+    NSTD_OS_LINUX_MEM_ADVICE_FREE
+} NSTDOSLinuxMemAdvice;
+
+/// Returns the size of a page of memory in bytes.
+///
+/// # Returns
+///
+/// `NSTDUSize page_size` - The size of a page of memory.
+NSTDAPI NSTDUSize nstd_os_linux_mem_page_size();
+
+/// Reserves a range of virtual memory without making it accessible.
+///
+/// # Note
+///
+/// The memory must be committed before it is accessed.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to reserve, rounded up to the page size.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A page aligned pointer to the reserved memory, null on error.
+NSTDAPI NSTDAny nstd_os_linux_mem_reserve(const NSTDUSize size);
+
+/// Makes a range of reserved memory readable and writable.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer into reserved memory.
+///
+/// - `const NSTDUSize size` - The number of bytes to commit.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_mem_commit(const NSTDAny ptr, const NSTDUSize size);
+
+/// Returns a range of committed memory to the reserved state, discarding it's contents.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer into committed memory.
+///
+/// - `const NSTDUSize size` - The number of bytes to decommit.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_mem_decommit(const NSTDAny ptr, const NSTDUSize size);
+
+/// Changes the protection of a range of memory.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer to the memory.
+///
+/// - `const NSTDUSize size` - The number of bytes to protect.
+///
+/// - `const NSTDOSLinuxMemProtection protection` - The new protection flags.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_mem_protect(
+    const NSTDAny ptr,
+    const NSTDUSize size,
+    const NSTDOSLinuxMemProtection protection);
+
+/// Tells the kernel how a range of memory is expected to be used.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer to the memory.
+///
+/// - `const NSTDUSize size` - The number of bytes the advice applies to.
+///
+/// - `const NSTDOSLinuxMemAdvice advice` - The expected usage of the memory.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_mem_advise(
+    const NSTDAny ptr,
+    const NSTDUSize size,
+    const NSTDOSLinuxMemAdvice advice);
+
+/// Releases a range of reserved or committed memory back to the operating system.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - A pointer to the memory, set to null on success.
+///
+/// - `const NSTDUSize size` - The number of bytes to release.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_mem_release(NSTDAny *const ptr, const NSTDUSize size);
+
+/// Allocates readable and writable memory surrounded by inaccessible guard pages.
+///
+/// # Note
+///
+/// Accessing a guard page raises `SIGSEGV`. The end of the memory meets the back guard page so
+/// overruns are caught immediately, which means the memory is only aligned to the largest power of
+/// two that divides `size`, up to the page size.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to allocate.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the memory, null on error.
+NSTDAPI NSTDAny nstd_os_linux_mem_allocate_guarded(const NSTDUSize size);
+
+/// Frees memory allocated with `nstd_os_linux_mem_allocate_guarded`.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - A pointer to the memory, set to null on success.
+///
+/// - `const NSTDUSize size` - The number of bytes that were allocated.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_os_linux_mem_free_guarded(NSTDAny *const ptr, const NSTDUSize size);
+
+NSTDCPPEND
+#endif
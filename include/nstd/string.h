@@ -1,6 +1,8 @@
 #ifndef NSTD_STRING_H_INCLUDED
 #define NSTD_STRING_H_INCLUDED
+#include "alloc/allocator.h"
 #include "core/def.h"
+#include "core/platform.h"
 #include "core/slice.h"
 #include "core/str.h"
 #include "nstd.h"
@@ -14,6 +16,34 @@ typedef struct
     NSTDVec bytes;
 } NSTDString;
 
+/// The largest precision that a float can be converted to a string with.
+#define NSTD_STRING_FLOAT_MAX_PRECISION 32767
+
+/// Describes how a float is converted to a string.
+typedef enum
+{
+    /// Fixed notation with a set number of digits after the decimal point, such as "1234.50".
+    NSTD_STRING_FLOAT_FORMAT_FIXED,
+    /// Scientific notation with a set number of digits after the decimal point, such as "1.23e3".
+    NSTD_STRING_FLOAT_FORMAT_SCIENTIFIC,
+    /// The fewest digits that parse back to the same float, in scientific notation for very large
+    /// or small magnitudes.
+    NSTD_STRING_FLOAT_FORMAT_SHORTEST,
+} NSTDStringFloatFormat;
+
+/// A Unicode normalization form.
+typedef enum
+{
+    /// Canonical decomposition followed by canonical composition.
+    NSTD_STRING_NORMAL_FORM_NFC,
+    /// Canonical decomposition.
+    NSTD_STRING_NORMAL_FORM_NFD,
+    /// Compatibility decomposition followed by canonical composition.
+    NSTD_STRING_NORMAL_FORM_NFKC,
+    /// Compatibility decomposition.
+    NSTD_STRING_NORMAL_FORM_NFKD,
+} NSTDStringNormalForm;
+
 /// Creates a new `NSTDString` instance.
 ///
 /// # Returns
@@ -21,6 +51,17 @@ typedef struct
 /// `NSTDString string` - The new string.
 NSTDAPI NSTDString nstd_string_new();
 
+/// Creates a new `NSTDString` instance that allocates it's memory with `allocator`.
+///
+/// # Parameters
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string.
+NSTDAPI NSTDString nstd_string_new_in(NSTDAllocator *const allocator);
+
 /// Creates an `NSTDString` from existing data.
 ///
 /// # Parameters
@@ -120,6 +161,434 @@ NSTDAPI NSTDUnichar nstd_string_pop(NSTDString *const string);
 /// - `const NSTDSlice *const chars` - `NSTDSlice` of `NSTDUnichar`s.
 NSTDAPI void nstd_string_extend(NSTDString *const string, const NSTDSlice *const chars);
 
+/// Creates a new string with the Unicode uppercase form of a string slice.
+///
+/// # Note
+///
+/// The new string may be longer than `str`, for example "ß" becomes "SS".
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The uppercase string, empty on error.
+NSTDAPI NSTDString nstd_string_to_uppercase(
+    const NSTDStr *const str,
+    const NSTDStrCaseLocale locale,
+    NSTDErrorCode *const is_err);
+
+/// Creates a new string with the Unicode lowercase form of a string slice.
+///
+/// # Note
+///
+/// A Greek capital sigma at the end of a word becomes a final sigma.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The lowercase string, empty on error.
+NSTDAPI NSTDString nstd_string_to_lowercase(
+    const NSTDStr *const str,
+    const NSTDStrCaseLocale locale,
+    NSTDErrorCode *const is_err);
+
+/// Creates a new string with the Unicode titlecase form of a string slice.
+///
+/// # Note
+///
+/// The first letter of each word is titlecased and the rest of the word is lowercased. Words are
+/// runs of letters, digits and combining marks, an apostrophe between two letters doesn't end a
+/// word.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The titlecase string, empty on error.
+NSTDAPI NSTDString nstd_string_to_titlecase(
+    const NSTDStr *const str,
+    const NSTDStrCaseLocale locale,
+    NSTDErrorCode *const is_err);
+
+/// Creates a new string with a string slice converted to a Unicode normalization form.
+///
+/// # Note
+///
+/// Strings that are in the same normalization form can be compared bytewise. The compatibility
+/// forms also fold formatting distinctions, so "ﬁ" becomes "fi".
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStringNormalForm form` - The normalization form.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The normalized string, empty on error.
+NSTDAPI NSTDString nstd_string_normalize(
+    const NSTDStr *const str,
+    const NSTDStringNormalForm form,
+    NSTDErrorCode *const is_err);
+
+/// Checks if a string slice is in a Unicode normalization form.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStringNormalForm form` - The normalization form.
+///
+/// # Returns
+///
+/// `NSTDBool is_normalized` - True if `str` is valid UTF-8 and in the normalization form.
+NSTDAPI NSTDBool nstd_string_is_normalized(
+    const NSTDStr *const str,
+    const NSTDStringNormalForm form);
+
+/// Collects the remaining substrings of a split iterator onto the end of a vector.
+///
+/// # Note
+///
+/// The substrings are views into the string slice being split. `parts` is left unchanged on
+/// error.
+///
+/// # Parameters
+///
+/// - `NSTDStrSplit *const split` - The split iterator.
+///
+/// - `NSTDVec *const parts` - A vector of `NSTDStr` to append the substrings to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_split_collect(NSTDStrSplit *const split, NSTDVec *const parts);
+
+/// Creates a new string with every occurrence of a pattern in a string slice replaced.
+///
+/// # Note
+///
+/// An empty pattern matches at the start of `str`, between each character and at the end of
+/// `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const from` - The pattern to replace.
+///
+/// - `const NSTDStr *const to` - The replacement.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if any of the string slices aren't valid
+///   UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+NSTDAPI NSTDString nstd_string_replace(
+    const NSTDStr *const str,
+    const NSTDStr *const from,
+    const NSTDStr *const to,
+    NSTDErrorCode *const is_err);
+
+/// Creates a new string with the first `count` occurrences of a pattern in a string slice
+/// replaced.
+///
+/// # Note
+///
+/// An empty pattern matches at the start of `str`, between each character and at the end of
+/// `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const from` - The pattern to replace.
+///
+/// - `const NSTDStr *const to` - The replacement.
+///
+/// - `const NSTDUSize count` - The maximum number of occurrences to replace.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if any of the string slices aren't valid
+///   UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+NSTDAPI NSTDString nstd_string_replacen(
+    const NSTDStr *const str,
+    const NSTDStr *const from,
+    const NSTDStr *const to,
+    const NSTDUSize count,
+    NSTDErrorCode *const is_err);
+
+/// Creates a new string made of a string slice repeated `n` times.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize n` - The number of times to repeat `str`.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if the new string's length would overflow or
+///   allocating it fails.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+NSTDAPI NSTDString nstd_string_repeat(
+    const NSTDStr *const str,
+    const NSTDUSize n,
+    NSTDErrorCode *const is_err);
+
+/// Creates a new string by joining a slice of string slices with a separator.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const strs` - A slice of `NSTDStr`.
+///
+/// - `const NSTDStr *const separator` - The string slice to put between each element of `strs`.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `strs` doesn't have an element size of
+///   `sizeof(NSTDStr)`.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+NSTDAPI NSTDString nstd_string_join(
+    const NSTDSlice *const strs,
+    const NSTDStr *const separator,
+    NSTDErrorCode *const is_err);
+
+/// Encodes a string slice as UTF-16 onto the end of a vector.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf16` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf16` - A vector of `NSTDChar16` to append the code units to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the first invalid UTF-8
+///   sequence in `str`, -1/usize::MAX if `str` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_encode_utf16(
+    const NSTDStr *const str,
+    const NSTDCPUEndian endian,
+    const NSTDBool bom,
+    NSTDVec *const utf16,
+    NSTDUSize *const error_offset);
+
+/// Encodes a string slice as UTF-16 onto the end of a vector, replacing invalid UTF-8 with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf16` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf16` - A vector of `NSTDChar16` to append the code units to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_encode_utf16_lossy(
+    const NSTDStr *const str,
+    const NSTDCPUEndian endian,
+    const NSTDBool bom,
+    NSTDVec *const utf16);
+
+/// Encodes a string slice as UTF-32 onto the end of a vector.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf32` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf32` - A vector of `NSTDChar32` to append the code units to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the first invalid UTF-8
+///   sequence in `str`, -1/usize::MAX if `str` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_encode_utf32(
+    const NSTDStr *const str,
+    const NSTDCPUEndian endian,
+    const NSTDBool bom,
+    NSTDVec *const utf32,
+    NSTDUSize *const error_offset);
+
+/// Encodes a string slice as UTF-32 onto the end of a vector, replacing invalid UTF-8 with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf32` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf32` - A vector of `NSTDChar32` to append the code units to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_encode_utf32_lossy(
+    const NSTDStr *const str,
+    const NSTDCPUEndian endian,
+    const NSTDBool bom,
+    NSTDVec *const utf32);
+
+/// Decodes UTF-16 code units onto the end of a string.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf16` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf16` - A slice of `NSTDChar16` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the index of the first invalid code unit in
+///   `utf16`, -1/usize::MAX if `utf16` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_decode_utf16(
+    const NSTDSlice *const utf16,
+    const NSTDCPUEndian endian,
+    NSTDString *const string,
+    NSTDUSize *const error_offset);
+
+/// Decodes UTF-16 code units onto the end of a string, replacing unpaired surrogates with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf16` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf16` - A slice of `NSTDChar16` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_decode_utf16_lossy(
+    const NSTDSlice *const utf16,
+    const NSTDCPUEndian endian,
+    NSTDString *const string);
+
+/// Decodes UTF-32 code units onto the end of a string.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf32` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf32` - A slice of `NSTDChar32` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the index of the first invalid code unit in
+///   `utf32`, -1/usize::MAX if `utf32` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_decode_utf32(
+    const NSTDSlice *const utf32,
+    const NSTDCPUEndian endian,
+    NSTDString *const string,
+    NSTDUSize *const error_offset);
+
+/// Decodes UTF-32 code units onto the end of a string, replacing invalid code units with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf32` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf32` - A slice of `NSTDChar32` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+NSTDAPI NSTDErrorCode nstd_string_decode_utf32_lossy(
+    const NSTDSlice *const utf32,
+    const NSTDCPUEndian endian,
+    NSTDString *const string);
+
 /// Converts an `NSTDFloat32` to an `NSTDString`.
 ///
 /// # Parameters
@@ -246,6 +715,232 @@ NSTDAPI NSTDString nstd_string_from_isize(const NSTDISize num);
 /// `NSTDString str` - The number represented as a string.
 NSTDAPI NSTDString nstd_string_from_usize(const NSTDUSize num);
 
+/// Converts an `NSTDFloat32` to an `NSTDString` in a specific format.
+///
+/// # Parameters
+///
+/// - `const NSTDFloat32 num` - The number to be converted.
+///
+/// - `const NSTDStringFloatFormat format` - The notation to use.
+///
+/// - `const NSTDUSize precision` - The number of digits after the decimal point, ignored by
+///   `NSTD_STRING_FLOAT_FORMAT_SHORTEST`.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `precision` is greater than
+///   `NSTD_STRING_FLOAT_MAX_PRECISION`.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_f32_formatted(
+    const NSTDFloat32 num,
+    const NSTDStringFloatFormat format,
+    const NSTDUSize precision,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDFloat64` to an `NSTDString` in a specific format.
+///
+/// # Parameters
+///
+/// - `const NSTDFloat64 num` - The number to be converted.
+///
+/// - `const NSTDStringFloatFormat format` - The notation to use.
+///
+/// - `const NSTDUSize precision` - The number of digits after the decimal point, ignored by
+///   `NSTD_STRING_FLOAT_FORMAT_SHORTEST`.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `precision` is greater than
+///   `NSTD_STRING_FLOAT_MAX_PRECISION`.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_f64_formatted(
+    const NSTDFloat64 num,
+    const NSTDStringFloatFormat format,
+    const NSTDUSize precision,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDInt8` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDInt8 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_i8_radix(
+    const NSTDInt8 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDUInt8` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDUInt8 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_u8_radix(
+    const NSTDUInt8 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDInt16` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDInt16 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_i16_radix(
+    const NSTDInt16 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDUInt16` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDUInt16 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_u16_radix(
+    const NSTDUInt16 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDInt32` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDInt32 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_i32_radix(
+    const NSTDInt32 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDUInt32` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDUInt32 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_u32_radix(
+    const NSTDUInt32 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDInt64` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDInt64 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_i64_radix(
+    const NSTDInt64 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDUInt64` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDUInt64 num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_u64_radix(
+    const NSTDUInt64 num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDISize` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDISize num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_isize_radix(
+    const NSTDISize num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
+/// Converts an `NSTDUSize` to an `NSTDString` in a radix, using lowercase digits.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize num` - The number to be converted.
+///
+/// - `const NSTDUInt32 radix` - The radix, 2-36.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `radix` is out of range.
+///
+/// # Returns
+///
+/// `NSTDString str` - The number represented as a string, empty on error.
+NSTDAPI NSTDString nstd_string_from_usize_radix(
+    const NSTDUSize num,
+    const NSTDUInt32 radix,
+    NSTDErrorCode *const is_err);
+
 /// Frees an `NSTDString` instance.
 ///
 /// # Parameters
@@ -1,5 +1,6 @@
 #ifndef NSTD_VEC_H_INCLUDED
 #define NSTD_VEC_H_INCLUDED
+#include "alloc/allocator.h"
 #include "core/def.h"
 #include "core/slice.h"
 #include "nstd.h"
@@ -13,6 +14,13 @@ typedef struct
     /// Buffer of allocated memory where `buffer.size` is the capacity, `buffer.ptr.size` is the
     /// size of each element, and `buffer.ptr.raw` is a raw pointer to the buffer.
     NSTDSlice buffer;
+    /// An optional function that is called with a pointer to each element that is dropped by the
+    /// vector.
+    void (*drop)(NSTDAny);
+    /// The allocator used for the vector's buffer, null to use the default heap allocator.
+    ///
+    /// The allocator is passed to it's own functions as `this`.
+    NSTDAllocator *allocator;
 } NSTDVec;
 
 /// Creates a new vector.
@@ -39,6 +47,43 @@ NSTDAPI NSTDVec nstd_vec_new(const NSTDUSize element_size);
 /// `NSTDVec vec` - The new vector.
 NSTDAPI NSTDVec nstd_vec_new_with_capacity(const NSTDUSize element_size, const NSTDUSize capacity);
 
+/// Creates a new vector that allocates it's buffer with `allocator`.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the vector.
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDVec vec` - The new vector.
+NSTDAPI NSTDVec nstd_vec_new_in(const NSTDUSize element_size, NSTDAllocator *const allocator);
+
+/// Creates a new vector with the specified capacity that allocates it's buffer with `allocator`.
+///
+/// # Note
+///
+/// The allocator must outlive the vector. Custom allocators that store their state alongside the
+/// vtable (such as `NSTDArena` and `NSTDPool`) must be passed as a pointer to the whole allocator
+/// object.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the vector.
+///
+/// - `const NSTDUSize capacity` - The capacity to give the vector, must be greater than 0.
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDVec vec` - The new vector.
+NSTDAPI NSTDVec nstd_vec_new_with_capacity_in(
+    const NSTDUSize element_size,
+    const NSTDUSize capacity,
+    NSTDAllocator *const allocator);
+
 /// Creates an `NSTDVec` object from existing data.
 ///
 /// # Parameters
@@ -52,6 +97,21 @@ NSTDAPI NSTDVec nstd_vec_new_with_capacity(const NSTDUSize element_size, const N
 /// `NSTDVec vec` - The new `NSTDVec` object.
 NSTDAPI NSTDVec nstd_vec_from_existing(const NSTDUSize size, const NSTDSlice *const buffer);
 
+/// Sets the function that is called with a pointer to each element that is dropped by a vector.
+///
+/// # Note
+///
+/// Elements are dropped when the vector is freed or cleared, and by `nstd_vec_pop_discard`,
+/// `nstd_vec_truncate`, `nstd_vec_resize` and `nstd_vec_remove`. Elements returned by
+/// `nstd_vec_pop` are not dropped.
+///
+/// # Parameters
+///
+/// - `NSTDVec *const vec` - The vector.
+///
+/// - `void (*drop)(NSTDAny)` - The drop callback, null to disable dropping elements.
+NSTDAPI void nstd_vec_set_drop(NSTDVec *const vec, void (*drop)(NSTDAny));
+
 /// Creates an `NSTDSlice` from an `NSTDVec`.
 ///
 /// # Parameters
@@ -140,6 +200,17 @@ NSTDAPI NSTDErrorCode nstd_vec_push(NSTDVec *const vec, const NSTDAnyConst eleme
 /// `NSTDAny element` - The element that was removed.
 NSTDAPI NSTDAny nstd_vec_pop(NSTDVec *const vec);
 
+/// Pops a value off of the back of a vector and drops it.
+///
+/// # Parameters
+///
+/// - `NSTDVec *const vec` - The vector.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the vector is empty.
+NSTDAPI NSTDErrorCode nstd_vec_pop_discard(NSTDVec *const vec);
+
 /// Extends a vector from a slice. `vec` and `slice` must have the same element size.
 ///
 /// # Parameters
@@ -191,6 +262,19 @@ NSTDAPI NSTDErrorCode nstd_vec_remove(NSTDVec *const vec, const NSTDUSize index)
 /// - `NSTDVec *const vec` - The vector.
 NSTDAPI void nstd_vec_clear(NSTDVec *const vec);
 
+/// Shortens a vector to `len` elements, dropping the rest.
+///
+/// # Parameters
+///
+/// - `NSTDVec *const vec` - The vector.
+///
+/// - `const NSTDUSize len` - The new length of the vector.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `len` is greater than the vector's length.
+NSTDAPI NSTDErrorCode nstd_vec_truncate(NSTDVec *const vec, const NSTDUSize len);
+
 /// Resizes a vector.
 ///
 /// # Parameters
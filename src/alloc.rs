@@ -1,7 +1,221 @@
 //! Low level heap memory allocation APIs.
 pub mod allocator;
+pub mod arena;
+pub mod debug;
+pub mod fault;
 pub mod heap;
-use crate::core::def::{NSTDAny, NSTDErrorCode};
+pub mod pool;
+use crate::{
+    alloc::allocator::{with_errc, NSTDAllocStats, NSTDAllocator},
+    core::{
+        def::{NSTDAny, NSTDErrorCode},
+        NSTD_CORE_NULL,
+    },
+};
+use std::{
+    cell::Cell,
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+/// Process-wide allocation counters.
+struct AllocCounters {
+    /// The total number of bytes that have been allocated.
+    total_bytes: AtomicUsize,
+    /// The number of bytes that are currently allocated.
+    live_bytes: AtomicUsize,
+    /// The highest number of bytes that have been allocated at once.
+    peak_bytes: AtomicUsize,
+    /// The number of allocations that have been made.
+    allocations: AtomicUsize,
+    /// The number of deallocations that have been made.
+    deallocations: AtomicUsize,
+    /// The number of reallocations that have been made.
+    reallocations: AtomicUsize,
+}
+impl AllocCounters {
+    /// Records an allocation of `size` bytes.
+    fn allocated(&self, size: usize) {
+        if !in_global_allocator() {
+            self.allocations.fetch_add(1, Ordering::Relaxed);
+            self.grow(size);
+        }
+    }
+
+    /// Records a reallocation from `size` to `new_size` bytes.
+    fn reallocated(&self, size: usize, new_size: usize) {
+        if !in_global_allocator() {
+            self.reallocations.fetch_add(1, Ordering::Relaxed);
+            match new_size > size {
+                true => self.grow(new_size - size),
+                false => self.shrink(size - new_size),
+            }
+        }
+    }
+
+    /// Records a deallocation of `size` bytes.
+    fn deallocated(&self, size: usize) {
+        if !in_global_allocator() {
+            self.deallocations.fetch_add(1, Ordering::Relaxed);
+            self.shrink(size);
+        }
+    }
+
+    /// Adds `size` bytes to the live byte count.
+    fn grow(&self, size: usize) {
+        self.total_bytes.fetch_add(size, Ordering::Relaxed);
+        let live_bytes = self.live_bytes.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_bytes.fetch_max(live_bytes, Ordering::Relaxed);
+    }
+
+    /// Removes `size` bytes from the live byte count.
+    fn shrink(&self, size: usize) {
+        let _ = self
+            .live_bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live_bytes| {
+                Some(live_bytes.saturating_sub(size))
+            });
+    }
+
+    /// Returns a snapshot of the counters.
+    fn snapshot(&self) -> NSTDAllocStats {
+        NSTDAllocStats {
+            total_bytes: self.total_bytes.load(Ordering::Relaxed),
+            live_bytes: self.live_bytes.load(Ordering::Relaxed),
+            peak_bytes: self.peak_bytes.load(Ordering::Relaxed),
+            allocations: self.allocations.load(Ordering::Relaxed),
+            deallocations: self.deallocations.load(Ordering::Relaxed),
+            reallocations: self.reallocations.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The process-wide allocation counters.
+static STATS: AllocCounters = AllocCounters {
+    total_bytes: AtomicUsize::new(0),
+    live_bytes: AtomicUsize::new(0),
+    peak_bytes: AtomicUsize::new(0),
+    allocations: AtomicUsize::new(0),
+    deallocations: AtomicUsize::new(0),
+    reallocations: AtomicUsize::new(0),
+};
+
+/// The process-wide allocator, null to use the system allocator.
+static GLOBAL_ALLOCATOR: AtomicPtr<NSTDAllocator> = AtomicPtr::new(std::ptr::null_mut());
+
+/// Held while any thread is inside of the process-wide allocator, so it is never entered
+/// concurrently.
+static GLOBAL_ALLOCATOR_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    /// Set while the current thread is inside of the process-wide allocator.
+    static IN_GLOBAL_ALLOCATOR: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns true if the current thread is inside of the process-wide allocator.
+#[inline]
+fn in_global_allocator() -> bool {
+    IN_GLOBAL_ALLOCATOR.try_with(Cell::get).unwrap_or(true)
+}
+
+/// Calls `f` with the process-wide allocator if one is installed.
+///
+/// Returns `None` if there is no process-wide allocator or if the current thread is already inside
+/// of it, so allocators that allocate their own memory through `nstd_alloc` can be installed.
+/// Calls from different threads are serialised.
+unsafe fn with_global_allocator<T, F: FnOnce(*mut NSTDAllocator) -> T>(f: F) -> Option<T> {
+    if GLOBAL_ALLOCATOR.load(Ordering::Acquire).is_null() {
+        return None;
+    }
+    let entered = IN_GLOBAL_ALLOCATOR.try_with(|inside| !inside.replace(true));
+    if entered != Ok(true) {
+        return None;
+    }
+    let guard = GLOBAL_ALLOCATOR_LOCK
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    // The allocator may have been uninstalled while waiting for the lock.
+    let allocator = GLOBAL_ALLOCATOR.load(Ordering::Acquire);
+    let result = match allocator.is_null() {
+        true => None,
+        false => Some(f(allocator)),
+    };
+    drop(guard);
+    let _ = IN_GLOBAL_ALLOCATOR.try_with(|inside| inside.set(false));
+    result
+}
+
+/// Installs a process-wide allocator that every `nstd_alloc` function is routed through.
+///
+/// # Note
+///
+/// The allocator is passed to it's own functions as `this`, and must stay valid until it is
+/// uninstalled. Memory must be freed by the same allocator that allocated it, so the allocator
+/// should be installed before and uninstalled after any memory it is responsible for is used.
+/// Memory that the installed allocator allocates through `nstd_alloc` is always allocated with the
+/// system allocator.
+///
+/// Calls into the installed allocator are serialised with a process-wide lock, so allocators that
+/// are not thread safe, such as the fault and debug allocators, can be installed. Using the
+/// allocator directly while it is installed is not synchronised with this lock. Once this returns
+/// no other thread is inside of the previously installed allocator.
+///
+/// # Parameters
+///
+/// - `NSTDAllocator *const allocator` - The allocator to install, null to use the system allocator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_set_global_allocator(allocator: *mut NSTDAllocator) {
+    // Wait for other threads to leave the old allocator so it can be freed once this returns.
+    let _guard = match in_global_allocator() {
+        true => None,
+        false => Some(
+            GLOBAL_ALLOCATOR_LOCK
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        ),
+    };
+    GLOBAL_ALLOCATOR.store(allocator, Ordering::Release);
+}
+
+/// Returns the installed process-wide allocator.
+///
+/// # Returns
+///
+/// `NSTDAllocator *allocator` - The process-wide allocator, null if the system allocator is used.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_global_allocator() -> *mut NSTDAllocator {
+    GLOBAL_ALLOCATOR.load(Ordering::Acquire)
+}
+
+/// Returns the process-wide allocation statistics.
+///
+/// # Note
+///
+/// Every successful call to an `nstd_alloc` function is recorded, except for calls made by the
+/// installed process-wide allocator itself. Sizes are taken from the caller, so deallocating with
+/// the wrong size will skew the byte counts.
+///
+/// # Returns
+///
+/// `NSTDAllocStats stats` - A snapshot of the process-wide allocation statistics.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_stats() -> NSTDAllocStats {
+    STATS.snapshot()
+}
+
+/// Resets the peak number of live bytes in the process-wide allocation statistics to the current
+/// number of live bytes.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_stats_reset_peak() {
+    let live_bytes = STATS.live_bytes.load(Ordering::Relaxed);
+    STATS.peak_bytes.store(live_bytes, Ordering::Relaxed);
+}
 
 /// Allocates a new memory block.
 ///
@@ -15,20 +229,15 @@ use crate::core::def::{NSTDAny, NSTDErrorCode};
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_alloc_allocate(size: usize) -> NSTDAny {
-    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
-    {
-        use std::alloc::Layout;
-        let layout = Layout::from_size_align_unchecked(size, 1);
-        std::alloc::alloc(layout).cast()
-    }
-    #[cfg(target_os = "linux")]
-    {
-        crate::os::linux::alloc::nstd_os_linux_alloc_allocate(size)
-    }
-    #[cfg(target_os = "windows")]
-    {
-        crate::os::windows::alloc::nstd_os_windows_alloc_allocate(size)
+    let global = with_global_allocator(|allocator| match (*allocator).allocate {
+        Some(allocate) => allocate(allocator.cast(), size),
+        None => NSTD_CORE_NULL,
+    });
+    let ptr = global.unwrap_or_else(|| sys_allocate(size));
+    if !ptr.is_null() {
+        STATS.allocated(size);
     }
+    ptr
 }
 
 /// Allocates a new memory block with all bytes set to 0.
@@ -43,20 +252,15 @@ pub unsafe extern "C" fn nstd_alloc_allocate(size: usize) -> NSTDAny {
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_alloc_allocate_zeroed(size: usize) -> NSTDAny {
-    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
-    {
-        use std::alloc::Layout;
-        let layout = Layout::from_size_align_unchecked(size, 1);
-        std::alloc::alloc_zeroed(layout).cast()
-    }
-    #[cfg(target_os = "linux")]
-    {
-        crate::os::linux::alloc::nstd_os_linux_alloc_allocate_zeroed(size, 1)
-    }
-    #[cfg(target_os = "windows")]
-    {
-        crate::os::windows::alloc::nstd_os_windows_alloc_allocate_zeroed(size)
+    let global = with_global_allocator(|allocator| match (*allocator).allocate_zeroed {
+        Some(allocate_zeroed) => allocate_zeroed(allocator.cast(), size),
+        None => NSTD_CORE_NULL,
+    });
+    let ptr = global.unwrap_or_else(|| sys_allocate_zeroed(size));
+    if !ptr.is_null() {
+        STATS.allocated(size);
     }
+    ptr
 }
 
 /// Reallocates a memory block.
@@ -74,15 +278,217 @@ pub unsafe extern "C" fn nstd_alloc_allocate_zeroed(size: usize) -> NSTDAny {
 /// `NSTDErrorCode errc` - Nonzero on error.
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
-#[cfg_attr(
-    any(target_os = "linux", target_os = "windows"),
-    allow(unused_variables)
-)]
 pub unsafe extern "C" fn nstd_alloc_reallocate(
     ptr: &mut NSTDAny,
     size: usize,
     new_size: usize,
 ) -> NSTDErrorCode {
+    let global = with_global_allocator(|allocator| match (*allocator).reallocate {
+        Some(reallocate) => with_errc(allocator, || {
+            reallocate(allocator.cast(), ptr, size, new_size);
+        }),
+        None => 1,
+    });
+    let errc = global.unwrap_or_else(|| sys_reallocate(ptr, size, new_size));
+    if errc == 0 {
+        STATS.reallocated(size, new_size);
+    }
+    errc
+}
+
+/// Deallocates a memory block.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the memory block.
+///
+/// - `const NSTDUSize size` - Number of bytes to deallocate.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_deallocate(ptr: &mut NSTDAny, size: usize) -> NSTDErrorCode {
+    let global = with_global_allocator(|allocator| match (*allocator).deallocate {
+        Some(deallocate) => with_errc(allocator, || deallocate(allocator.cast(), ptr, size)),
+        None => 1,
+    });
+    let errc = global.unwrap_or_else(|| sys_deallocate(ptr, size));
+    if errc == 0 {
+        STATS.deallocated(size);
+    }
+    errc
+}
+
+/// Allocates a new memory block with a specific alignment.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - Number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new memory block, null on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_allocate_aligned(size: usize, align: usize) -> NSTDAny {
+    let global = with_global_allocator(|allocator| match (*allocator).allocate_aligned {
+        Some(allocate_aligned) => allocate_aligned(allocator.cast(), size, align),
+        None => NSTD_CORE_NULL,
+    });
+    let ptr = global.unwrap_or_else(|| sys_allocate_aligned(size, align));
+    if !ptr.is_null() {
+        STATS.allocated(size);
+    }
+    ptr
+}
+
+/// Allocates a new memory block with a specific alignment and all bytes set to 0.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - Number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new memory block, null on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_allocate_zeroed_aligned(size: usize, align: usize) -> NSTDAny {
+    let global = with_global_allocator(|allocator| match (*allocator).allocate_zeroed_aligned {
+        Some(allocate_zeroed_aligned) => allocate_zeroed_aligned(allocator.cast(), size, align),
+        None => NSTD_CORE_NULL,
+    });
+    let ptr = global.unwrap_or_else(|| sys_allocate_zeroed_aligned(size, align));
+    if !ptr.is_null() {
+        STATS.allocated(size);
+    }
+    ptr
+}
+
+/// Reallocates a memory block that was allocated with a specific alignment.
+///
+/// # Note
+///
+/// `ptr` is left untouched on error.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the memory block.
+///
+/// - `const NSTDUSize size` - The current size of the memory block.
+///
+/// - `const NSTDUSize new_size` - The new size of the memory block.
+///
+/// - `const NSTDUSize align` - The alignment the memory block was allocated with.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_reallocate_aligned(
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) -> NSTDErrorCode {
+    let global = with_global_allocator(|allocator| match (*allocator).reallocate_aligned {
+        Some(reallocate_aligned) => with_errc(allocator, || {
+            reallocate_aligned(allocator.cast(), ptr, size, new_size, align);
+        }),
+        None => 1,
+    });
+    let errc = global.unwrap_or_else(|| sys_reallocate_aligned(ptr, size, new_size, align));
+    if errc == 0 {
+        STATS.reallocated(size, new_size);
+    }
+    errc
+}
+
+/// Deallocates a memory block that was allocated with a specific alignment.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the memory block.
+///
+/// - `const NSTDUSize size` - Number of bytes to deallocate.
+///
+/// - `const NSTDUSize align` - The alignment the memory block was allocated with.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_deallocate_aligned(
+    ptr: &mut NSTDAny,
+    size: usize,
+    align: usize,
+) -> NSTDErrorCode {
+    let global = with_global_allocator(|allocator| match (*allocator).deallocate_aligned {
+        Some(deallocate_aligned) => with_errc(allocator, || {
+            deallocate_aligned(allocator.cast(), ptr, size, align);
+        }),
+        None => 1,
+    });
+    let errc = global.unwrap_or_else(|| sys_deallocate_aligned(ptr, size, align));
+    if errc == 0 {
+        STATS.deallocated(size);
+    }
+    errc
+}
+
+/// Allocates a new memory block with the system allocator.
+#[inline]
+unsafe fn sys_allocate(size: usize) -> NSTDAny {
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        use std::alloc::Layout;
+        let layout = Layout::from_size_align_unchecked(size, 1);
+        std::alloc::alloc(layout).cast()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::alloc::nstd_os_linux_alloc_allocate(size)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        crate::os::windows::alloc::nstd_os_windows_alloc_allocate(size)
+    }
+}
+
+/// Allocates a new memory block with all bytes set to 0 with the system allocator.
+#[inline]
+unsafe fn sys_allocate_zeroed(size: usize) -> NSTDAny {
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        use std::alloc::Layout;
+        let layout = Layout::from_size_align_unchecked(size, 1);
+        std::alloc::alloc_zeroed(layout).cast()
+    }
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::alloc::nstd_os_linux_alloc_allocate_zeroed(size, 1)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        crate::os::windows::alloc::nstd_os_windows_alloc_allocate_zeroed(size)
+    }
+}
+
+/// Reallocates a memory block with the system allocator.
+#[inline]
+#[cfg_attr(
+    any(target_os = "linux", target_os = "windows"),
+    allow(unused_variables)
+)]
+unsafe fn sys_reallocate(ptr: &mut NSTDAny, size: usize, new_size: usize) -> NSTDErrorCode {
     #[cfg(not(any(target_os = "linux", target_os = "windows")))]
     {
         use std::alloc::Layout;
@@ -104,27 +510,15 @@ pub unsafe extern "C" fn nstd_alloc_reallocate(
     }
 }
 
-/// Deallocates a memory block.
-///
-/// # Parameters
-///
-/// - `NSTDAny *const ptr` - Pointer to the memory block.
-///
-/// - `const NSTDUSize size` - Number of bytes to deallocate.
-///
-/// # Returns
-///
-/// `NSTDErrorCode errc` - Nonzero on error.
+/// Deallocates a memory block with the system allocator.
 #[inline]
-#[cfg_attr(feature = "clib", no_mangle)]
 #[cfg_attr(
     any(target_os = "linux", target_os = "windows"),
     allow(unused_variables)
 )]
-pub unsafe extern "C" fn nstd_alloc_deallocate(ptr: &mut NSTDAny, size: usize) -> NSTDErrorCode {
+unsafe fn sys_deallocate(ptr: &mut NSTDAny, size: usize) -> NSTDErrorCode {
     #[cfg(not(any(target_os = "linux", target_os = "windows")))]
     {
-        use crate::core::NSTD_CORE_NULL;
         use std::alloc::Layout;
         let layout = Layout::from_size_align_unchecked(size, 1);
         std::alloc::dealloc((*ptr).cast(), layout);
@@ -141,3 +535,92 @@ pub unsafe extern "C" fn nstd_alloc_deallocate(ptr: &mut NSTDAny, size: usize) -
         crate::os::windows::alloc::nstd_os_windows_alloc_deallocate(ptr)
     }
 }
+
+/// Allocates a new memory block with a specific alignment with the system allocator.
+#[inline]
+unsafe fn sys_allocate_aligned(size: usize, align: usize) -> NSTDAny {
+    #[cfg(not(target_os = "linux"))]
+    {
+        match std::alloc::Layout::from_size_align(size, align) {
+            Ok(layout) => std::alloc::alloc(layout).cast(),
+            _ => NSTD_CORE_NULL,
+        }
+    }
+    #[cfg(target_os = "linux")]
+    {
+        match align.is_power_of_two() {
+            true => crate::os::linux::alloc::nstd_os_linux_alloc_allocate_aligned(size, align),
+            false => NSTD_CORE_NULL,
+        }
+    }
+}
+
+/// Allocates a new memory block with a specific alignment and all bytes set to 0 with the system
+/// allocator.
+#[inline]
+unsafe fn sys_allocate_zeroed_aligned(size: usize, align: usize) -> NSTDAny {
+    #[cfg(not(target_os = "linux"))]
+    {
+        match std::alloc::Layout::from_size_align(size, align) {
+            Ok(layout) => std::alloc::alloc_zeroed(layout).cast(),
+            _ => NSTD_CORE_NULL,
+        }
+    }
+    #[cfg(target_os = "linux")]
+    {
+        match align.is_power_of_two() {
+            true => {
+                crate::os::linux::alloc::nstd_os_linux_alloc_allocate_zeroed_aligned(size, align)
+            }
+            false => NSTD_CORE_NULL,
+        }
+    }
+}
+
+/// Reallocates a memory block that was allocated with a specific alignment with the system
+/// allocator.
+#[inline]
+unsafe fn sys_reallocate_aligned(
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) -> NSTDErrorCode {
+    #[cfg(not(target_os = "linux"))]
+    {
+        use std::alloc::Layout;
+        if Layout::from_size_align(new_size, align).is_err() {
+            return 1;
+        }
+        let layout = Layout::from_size_align_unchecked(size, align);
+        let new_mem = std::alloc::realloc((*ptr).cast(), layout, new_size);
+        if !new_mem.is_null() {
+            *ptr = new_mem.cast();
+            return 0;
+        }
+        1
+    }
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::alloc::nstd_os_linux_alloc_reallocate_aligned(ptr, size, new_size, align)
+    }
+}
+
+/// Deallocates a memory block that was allocated with a specific alignment with the system
+/// allocator.
+#[inline]
+#[cfg_attr(target_os = "linux", allow(unused_variables))]
+unsafe fn sys_deallocate_aligned(ptr: &mut NSTDAny, size: usize, align: usize) -> NSTDErrorCode {
+    #[cfg(not(target_os = "linux"))]
+    {
+        let layout = std::alloc::Layout::from_size_align_unchecked(size, align);
+        std::alloc::dealloc((*ptr).cast(), layout);
+        *ptr = NSTD_CORE_NULL;
+        0
+    }
+    #[cfg(target_os = "linux")]
+    {
+        crate::os::linux::alloc::nstd_os_linux_alloc_deallocate_aligned(ptr);
+        0
+    }
+}
@@ -1,5 +1,52 @@
 //! A VMT struct for memory allocation.
-use crate::core::def::{NSTDAny, NSTDErrorCode};
+use crate::core::def::{NSTDAny, NSTDAnyConst, NSTDErrorCode};
+
+/// A snapshot of an allocator's usage.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct NSTDAllocStats {
+    /// The total number of bytes that have been allocated, including growth from reallocations.
+    pub total_bytes: usize,
+    /// The number of bytes that are currently allocated.
+    pub live_bytes: usize,
+    /// The highest number of bytes that have been allocated at once.
+    pub peak_bytes: usize,
+    /// The number of allocations that have been made.
+    pub allocations: usize,
+    /// The number of deallocations that have been made.
+    pub deallocations: usize,
+    /// The number of reallocations that have been made.
+    pub reallocations: usize,
+}
+impl NSTDAllocStats {
+    /// Records an allocation of `size` bytes.
+    pub(crate) fn allocated(&mut self, size: usize) {
+        self.allocations += 1;
+        self.grow(size);
+    }
+
+    /// Records a reallocation from `size` to `new_size` bytes.
+    pub(crate) fn reallocated(&mut self, size: usize, new_size: usize) {
+        self.reallocations += 1;
+        match new_size > size {
+            true => self.grow(new_size - size),
+            false => self.live_bytes = self.live_bytes.saturating_sub(size - new_size),
+        }
+    }
+
+    /// Records a deallocation of `size` bytes.
+    pub(crate) fn deallocated(&mut self, size: usize) {
+        self.deallocations += 1;
+        self.live_bytes = self.live_bytes.saturating_sub(size);
+    }
+
+    /// Adds `size` bytes to the live byte count.
+    fn grow(&mut self, size: usize) {
+        self.total_bytes = self.total_bytes.saturating_add(size);
+        self.live_bytes = self.live_bytes.saturating_add(size);
+        self.peak_bytes = self.peak_bytes.max(self.live_bytes);
+    }
+}
 
 /// A heap memory allocator type.
 #[repr(C)]
@@ -53,6 +100,71 @@ pub struct NSTDAllocator {
     ///
     /// - `NSTDUSize size` - Number of bytes to deallocate.
     pub deallocate: Option<unsafe extern "C" fn(NSTDAny, &mut NSTDAny, usize)>,
+    /// Allocates a new block of memory with a specific alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDUSize size` - Number of bytes to allocate.
+    ///
+    /// - `NSTDUSize align` - The alignment of the block of memory, must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDAny ptr` - The new block of memory.
+    pub allocate_aligned: Option<unsafe extern "C" fn(NSTDAny, usize, usize) -> NSTDAny>,
+    /// Allocates a new block of memory with a specific alignment and all bytes set to 0.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDUSize size` - Number of bytes to allocate.
+    ///
+    /// - `NSTDUSize align` - The alignment of the block of memory, must be a power of two.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDAny ptr` - The new block of memory.
+    pub allocate_zeroed_aligned: Option<unsafe extern "C" fn(NSTDAny, usize, usize) -> NSTDAny>,
+    /// Reallocates a block of memory that was allocated with a specific alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDAny *ptr` - Pointer to the block of memory.
+    ///
+    /// - `NSTDUSize size` - The current size of the block of memory.
+    ///
+    /// - `NSTDUSize new_size` - The new size of the block of memory.
+    ///
+    /// - `NSTDUSize align` - The alignment the block of memory was allocated with.
+    pub reallocate_aligned:
+        Option<unsafe extern "C" fn(NSTDAny, &mut NSTDAny, usize, usize, usize)>,
+    /// Deallocates a block of memory that was allocated with a specific alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAny this` - A pointer to the owner of the allocator.
+    ///
+    /// - `NSTDAny *ptr` - Pointer to the block of memory.
+    ///
+    /// - `NSTDUSize size` - Number of bytes to deallocate.
+    ///
+    /// - `NSTDUSize align` - The alignment the block of memory was allocated with.
+    pub deallocate_aligned: Option<unsafe extern "C" fn(NSTDAny, &mut NSTDAny, usize, usize)>,
+    /// Returns the allocator's usage statistics.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst this` - A pointer to the owner of the allocator.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDAllocStats stats` - A snapshot of the allocator's usage.
+    pub stats: Option<unsafe extern "C" fn(NSTDAnyConst) -> NSTDAllocStats>,
 }
 
 /// Returns the default memory allocator.
@@ -69,9 +181,44 @@ pub unsafe extern "C" fn nstd_alloc_allocator_default() -> NSTDAllocator {
         allocate_zeroed: Some(allocate_zeroed),
         reallocate: Some(reallocate),
         deallocate: Some(deallocate),
+        allocate_aligned: Some(allocate_aligned),
+        allocate_zeroed_aligned: Some(allocate_zeroed_aligned),
+        reallocate_aligned: Some(reallocate_aligned),
+        deallocate_aligned: Some(deallocate_aligned),
+        stats: Some(stats),
+    }
+}
+
+/// Returns an allocator's usage statistics.
+///
+/// # Parameters
+///
+/// - `const NSTDAllocator *allocator` - The allocator, must be the first field of its owner.
+///
+/// # Returns
+///
+/// `NSTDAllocStats stats` - A snapshot of the allocator's usage, all zero if the allocator doesn't
+/// keep statistics.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_allocator_stats(allocator: &NSTDAllocator) -> NSTDAllocStats {
+    match allocator.stats {
+        Some(stats) => stats(allocator as *const NSTDAllocator as NSTDAnyConst),
+        None => NSTDAllocStats::default(),
     }
 }
 
+/// Calls `f` and returns the error code that it set on `allocator`, restoring any error code the
+/// allocator already had.
+#[inline]
+pub(crate) unsafe fn with_errc<F: FnOnce()>(allocator: *mut NSTDAllocator, f: F) -> NSTDErrorCode {
+    let prev_errc = std::mem::replace(&mut (*allocator).errc, 0);
+    f();
+    let errc = (*allocator).errc;
+    (*allocator).errc |= prev_errc;
+    errc
+}
+
 /// Default allocate function.
 #[inline]
 unsafe extern "C" fn allocate(_: NSTDAny, size: usize) -> NSTDAny {
@@ -97,3 +244,46 @@ unsafe extern "C" fn deallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize) {
     let this = this as *mut NSTDAllocator;
     (*this).errc |= crate::alloc::nstd_alloc_deallocate(ptr, size);
 }
+
+/// Default allocate_aligned function.
+#[inline]
+unsafe extern "C" fn allocate_aligned(_: NSTDAny, size: usize, align: usize) -> NSTDAny {
+    crate::alloc::nstd_alloc_allocate_aligned(size, align)
+}
+
+/// Default allocate_zeroed_aligned function.
+#[inline]
+unsafe extern "C" fn allocate_zeroed_aligned(_: NSTDAny, size: usize, align: usize) -> NSTDAny {
+    crate::alloc::nstd_alloc_allocate_zeroed_aligned(size, align)
+}
+
+/// Default reallocate_aligned function.
+#[inline]
+unsafe extern "C" fn reallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) {
+    let this = this as *mut NSTDAllocator;
+    (*this).errc |= crate::alloc::nstd_alloc_reallocate_aligned(ptr, size, new_size, align);
+}
+
+/// Default deallocate_aligned function.
+#[inline]
+unsafe extern "C" fn deallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    align: usize,
+) {
+    let this = this as *mut NSTDAllocator;
+    (*this).errc |= crate::alloc::nstd_alloc_deallocate_aligned(ptr, size, align);
+}
+
+/// Default stats function.
+#[inline]
+unsafe extern "C" fn stats(_: NSTDAnyConst) -> NSTDAllocStats {
+    crate::alloc::nstd_alloc_stats()
+}
@@ -0,0 +1,414 @@
+//! An arena allocator that hands out memory from large chunks and frees it all at once.
+use crate::{
+    alloc::allocator::{NSTDAllocStats, NSTDAllocator},
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+        NSTD_CORE_NULL,
+    },
+};
+
+/// The alignment of every block of memory handed out by an arena.
+const ALIGNMENT: usize = 16;
+
+/// The header at the start of each of an arena's chunks.
+#[repr(C, align(16))]
+struct ArenaChunk {
+    /// The previously allocated chunk.
+    prev: *mut ArenaChunk,
+    /// The number of bytes available for allocations in this chunk.
+    size: usize,
+}
+impl ArenaChunk {
+    /// Returns a pointer to the first byte available for allocations.
+    #[inline]
+    unsafe fn data(chunk: *mut ArenaChunk) -> *mut u8 {
+        chunk.add(1).cast()
+    }
+
+    /// Returns the total number of bytes occupied by a chunk with `size` available bytes.
+    #[inline]
+    fn byte_count(size: usize) -> usize {
+        std::mem::size_of::<ArenaChunk>() + size
+    }
+}
+
+/// Memory usage statistics for an arena allocator.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct NSTDArenaStats {
+    /// The number of chunks the arena has allocated.
+    pub chunk_count: usize,
+    /// The total number of bytes available for allocations in all chunks.
+    pub capacity: usize,
+    /// The number of bytes that have been handed out, including alignment padding.
+    pub used: usize,
+    /// The number of allocations that have been made since the last reset.
+    pub allocations: usize,
+}
+
+/// An arena allocator.
+///
+/// # Note
+///
+/// The arena itself must be passed as `this` when using `allocator`.
+#[repr(C)]
+pub struct NSTDArena {
+    /// The arena's allocator vtable.
+    pub allocator: NSTDAllocator,
+    /// The minimum number of bytes to allocate for each chunk.
+    pub chunk_size: usize,
+    /// The most recently allocated chunk, null if the arena has no chunks.
+    pub chunk: NSTDAny,
+    /// The number of bytes used in the most recently allocated chunk.
+    pub offset: usize,
+    /// The arena's usage statistics.
+    pub stats: NSTDArenaStats,
+    /// The usage statistics reported through `allocator`.
+    pub usage: NSTDAllocStats,
+}
+impl NSTDArena {
+    /// Returns the most recently allocated chunk.
+    #[inline]
+    fn current(&self) -> *mut ArenaChunk {
+        self.chunk.cast()
+    }
+
+    /// Returns a pointer to the next free byte in the current chunk.
+    #[inline]
+    unsafe fn top(&self) -> *mut u8 {
+        ArenaChunk::data(self.current()).add(self.offset)
+    }
+
+    /// Returns the number of free bytes in the current chunk.
+    #[inline]
+    unsafe fn remaining(&self) -> usize {
+        match self.chunk.is_null() {
+            true => 0,
+            false => (*self.current()).size - self.offset,
+        }
+    }
+
+    /// Allocates a new chunk with room for at least `size` bytes.
+    unsafe fn grow(&mut self, size: usize) -> NSTDErrorCode {
+        let chunk_size = align(self.chunk_size.max(size));
+        let chunk = crate::alloc::nstd_alloc_allocate(ArenaChunk::byte_count(chunk_size));
+        if chunk.is_null() {
+            return 1;
+        }
+        let chunk = chunk as *mut ArenaChunk;
+        (*chunk).prev = self.current();
+        (*chunk).size = chunk_size;
+        self.chunk = chunk.cast();
+        self.offset = 0;
+        self.stats.chunk_count += 1;
+        self.stats.capacity += chunk_size;
+        0
+    }
+}
+
+/// Rounds `size` up to the arena's alignment.
+#[inline]
+fn align(size: usize) -> usize {
+    (size + ALIGNMENT - 1) & !(ALIGNMENT - 1)
+}
+
+/// Creates a new arena allocator.
+///
+/// # Note
+///
+/// No memory is allocated until the first allocation is made. Allocations that are larger than
+/// `chunk_size` are given their own chunk.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize chunk_size` - The minimum number of bytes to allocate for each chunk.
+///
+/// # Returns
+///
+/// `NSTDArena arena` - The new arena allocator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_arena_new(chunk_size: usize) -> NSTDArena {
+    NSTDArena {
+        allocator: NSTDAllocator {
+            errc: 0,
+            allocate: Some(arena_allocate),
+            allocate_zeroed: Some(arena_allocate_zeroed),
+            reallocate: Some(arena_reallocate),
+            deallocate: Some(arena_deallocate),
+            allocate_aligned: Some(arena_allocate_aligned),
+            allocate_zeroed_aligned: Some(arena_allocate_zeroed_aligned),
+            reallocate_aligned: Some(arena_reallocate_aligned),
+            deallocate_aligned: Some(arena_deallocate_aligned),
+            stats: Some(arena_stats),
+        },
+        chunk_size,
+        chunk: NSTD_CORE_NULL,
+        offset: 0,
+        stats: NSTDArenaStats::default(),
+        usage: NSTDAllocStats::default(),
+    }
+}
+
+/// Allocates a new block of memory from an arena.
+///
+/// # Parameters
+///
+/// - `NSTDArena *const arena` - The arena.
+///
+/// - `const NSTDUSize size` - Number of bytes to allocate.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new block of memory, null on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_arena_allocate(arena: &mut NSTDArena, size: usize) -> NSTDAny {
+    let size = align(size);
+    if arena.remaining() < size && arena.grow(size) != 0 {
+        arena.allocator.errc |= 1;
+        return NSTD_CORE_NULL;
+    }
+    let ptr = arena.top();
+    arena.offset += size;
+    arena.stats.used += size;
+    arena.stats.allocations += 1;
+    ptr.cast()
+}
+
+/// Makes all of the memory in an arena available for allocations again.
+///
+/// # Note
+///
+/// Every chunk except for the most recently allocated one is freed. All memory previously handed
+/// out by the arena becomes invalid.
+///
+/// # Parameters
+///
+/// - `NSTDArena *const arena` - The arena.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_arena_reset(arena: &mut NSTDArena) -> NSTDErrorCode {
+    let mut errc = 0;
+    let current = arena.current();
+    if !current.is_null() {
+        errc = free_chunks((*current).prev);
+        (*current).prev = std::ptr::null_mut();
+        arena.stats.chunk_count = 1;
+        arena.stats.capacity = (*current).size;
+    }
+    arena.offset = 0;
+    arena.stats.used = 0;
+    arena.stats.allocations = 0;
+    arena.usage.live_bytes = 0;
+    errc
+}
+
+/// Returns an arena's memory usage statistics.
+///
+/// # Parameters
+///
+/// - `const NSTDArena *const arena` - The arena.
+///
+/// # Returns
+///
+/// `NSTDArenaStats stats` - The arena's usage statistics.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_arena_stats(arena: &NSTDArena) -> NSTDArenaStats {
+    arena.stats
+}
+
+/// Frees an arena and all of the memory that was allocated from it.
+///
+/// # Parameters
+///
+/// - `NSTDArena *const arena` - The arena.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_arena_free(arena: &mut NSTDArena) -> NSTDErrorCode {
+    let errc = free_chunks(arena.current());
+    arena.chunk = NSTD_CORE_NULL;
+    arena.offset = 0;
+    arena.stats = NSTDArenaStats::default();
+    arena.usage.live_bytes = 0;
+    errc
+}
+
+/// Allocates `size` bytes from an arena with an alignment of at least `alignment`.
+unsafe fn allocate_aligned(arena: &mut NSTDArena, size: usize, alignment: usize) -> NSTDAny {
+    if alignment <= ALIGNMENT {
+        return nstd_alloc_arena_allocate(arena, size);
+    }
+    if !alignment.is_power_of_two() {
+        arena.allocator.errc |= 1;
+        return NSTD_CORE_NULL;
+    }
+    // Chunks are only aligned to `ALIGNMENT`, so padding may be needed before the allocation.
+    let size = align(size);
+    let mut padding = match arena.chunk.is_null() {
+        true => usize::MAX,
+        false => arena.top().align_offset(alignment),
+    };
+    if arena.remaining() < padding.saturating_add(size) {
+        if arena.grow(size + alignment - ALIGNMENT) != 0 {
+            arena.allocator.errc |= 1;
+            return NSTD_CORE_NULL;
+        }
+        padding = arena.top().align_offset(alignment);
+    }
+    arena.offset += padding;
+    arena.stats.used += padding;
+    nstd_alloc_arena_allocate(arena, size)
+}
+
+/// Resizes a block of memory allocated from an arena, moving it if it can't be resized in place.
+///
+/// A null `ptr` is given a new block of memory.
+unsafe fn reallocate(
+    arena: &mut NSTDArena,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    alignment: usize,
+) {
+    if ptr.is_null() {
+        let new_ptr = allocate_aligned(arena, new_size, alignment);
+        *ptr = allocated(arena, new_ptr, new_size);
+        return;
+    }
+    let (old_size, old_new_size) = (size, new_size);
+    let (size, new_size) = (align(size), align(new_size));
+    // The most recent allocation can be resized in place.
+    let is_last = !arena.chunk.is_null() && arena.top() == (*ptr).cast::<u8>().wrapping_add(size);
+    if is_last && (new_size <= size || arena.remaining() >= new_size - size) {
+        arena.offset = arena.offset - size + new_size;
+        arena.stats.used = arena.stats.used - size + new_size;
+        arena.usage.reallocated(old_size, old_new_size);
+        return;
+    }
+    let new_ptr = allocate_aligned(arena, new_size, alignment);
+    if !new_ptr.is_null() {
+        std::ptr::copy_nonoverlapping(*ptr as *const u8, new_ptr.cast(), size.min(new_size));
+        *ptr = new_ptr;
+        arena.usage.reallocated(old_size, old_new_size);
+    }
+}
+
+/// Records an allocation of `size` bytes made through an arena's vtable.
+#[inline]
+fn allocated(arena: &mut NSTDArena, ptr: NSTDAny, size: usize) -> NSTDAny {
+    if !ptr.is_null() {
+        arena.usage.allocated(size);
+    }
+    ptr
+}
+
+/// Frees `chunk` and every chunk allocated before it.
+unsafe fn free_chunks(mut chunk: *mut ArenaChunk) -> NSTDErrorCode {
+    let mut errc = 0;
+    while !chunk.is_null() {
+        let prev = (*chunk).prev;
+        let bytes = ArenaChunk::byte_count((*chunk).size);
+        let mut ptr: NSTDAny = chunk.cast();
+        errc |= crate::alloc::nstd_alloc_deallocate(&mut ptr, bytes);
+        chunk = prev;
+    }
+    errc
+}
+
+/// Arena allocate function.
+#[inline]
+unsafe extern "C" fn arena_allocate(this: NSTDAny, size: usize) -> NSTDAny {
+    let arena = &mut *(this as *mut NSTDArena);
+    let ptr = nstd_alloc_arena_allocate(arena, size);
+    allocated(arena, ptr, size)
+}
+
+/// Arena allocate_zeroed function.
+unsafe extern "C" fn arena_allocate_zeroed(this: NSTDAny, size: usize) -> NSTDAny {
+    let ptr = arena_allocate(this, size);
+    if !ptr.is_null() {
+        std::ptr::write_bytes(ptr.cast::<u8>(), 0, size);
+    }
+    ptr
+}
+
+/// Arena reallocate function.
+#[inline]
+unsafe extern "C" fn arena_reallocate(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+) {
+    reallocate(
+        &mut *(this as *mut NSTDArena),
+        ptr,
+        size,
+        new_size,
+        ALIGNMENT,
+    );
+}
+
+/// Arena deallocate function, memory is only released when the arena is reset or freed.
+#[inline]
+unsafe extern "C" fn arena_deallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize) {
+    (*(this as *mut NSTDArena)).usage.deallocated(size);
+    *ptr = NSTD_CORE_NULL;
+}
+
+/// Arena allocate_aligned function.
+#[inline]
+unsafe extern "C" fn arena_allocate_aligned(this: NSTDAny, size: usize, align: usize) -> NSTDAny {
+    let arena = &mut *(this as *mut NSTDArena);
+    let ptr = allocate_aligned(arena, size, align);
+    allocated(arena, ptr, size)
+}
+
+/// Arena allocate_zeroed_aligned function.
+unsafe extern "C" fn arena_allocate_zeroed_aligned(
+    this: NSTDAny,
+    size: usize,
+    align: usize,
+) -> NSTDAny {
+    let ptr = arena_allocate_aligned(this, size, align);
+    if !ptr.is_null() {
+        std::ptr::write_bytes(ptr.cast::<u8>(), 0, size);
+    }
+    ptr
+}
+
+/// Arena reallocate_aligned function.
+#[inline]
+unsafe extern "C" fn arena_reallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) {
+    reallocate(&mut *(this as *mut NSTDArena), ptr, size, new_size, align);
+}
+
+/// Arena deallocate_aligned function, memory is only released when the arena is reset or freed.
+#[inline]
+unsafe extern "C" fn arena_deallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    _: usize,
+) {
+    arena_deallocate(this, ptr, size);
+}
+
+/// Arena stats function.
+#[inline]
+unsafe extern "C" fn arena_stats(this: NSTDAnyConst) -> NSTDAllocStats {
+    (*(this as *const NSTDArena)).usage
+}
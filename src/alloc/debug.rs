@@ -0,0 +1,495 @@
+//! A debug allocator that tracks live allocations and detects common memory errors.
+use crate::{
+    alloc::allocator::{NSTDAllocStats, NSTDAllocator},
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDChar, NSTDErrorCode},
+        NSTD_CORE_NULL,
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+/// The minimum number of guard bytes placed on each side of an allocation.
+const GUARD_SIZE: usize = 16;
+
+/// The value that every guard byte is set to.
+const GUARD_BYTE: u8 = 0xFD;
+
+/// A raw handle to a debug allocator's bookkeeping.
+pub type NSTDDebugAllocatorHandle = NSTDAny;
+
+/// A live allocation tracked by a debug allocator.
+struct Allocation {
+    /// The number of bytes requested by the caller.
+    size: usize,
+    /// The alignment requested by the caller.
+    align: usize,
+    /// The caller tag that was set when the allocation was made.
+    tag: *const NSTDChar,
+    /// The order in which the allocation was made.
+    id: usize,
+}
+impl Allocation {
+    /// Returns the number of guard bytes on each side of the allocation.
+    #[inline]
+    fn guard_size(&self) -> usize {
+        GUARD_SIZE.max(self.align)
+    }
+
+    /// Returns the total number of bytes allocated for the allocation and it's guards.
+    #[inline]
+    fn byte_count(&self) -> usize {
+        self.size + 2 * self.guard_size()
+    }
+
+    /// Returns true if both of the allocation's guards are intact.
+    unsafe fn guards_intact(&self, ptr: usize) -> bool {
+        let guard_size = self.guard_size();
+        let front = std::slice::from_raw_parts((ptr - guard_size) as *const u8, guard_size);
+        let back = std::slice::from_raw_parts((ptr + self.size) as *const u8, guard_size);
+        front.iter().chain(back).all(|&byte| byte == GUARD_BYTE)
+    }
+}
+
+/// A debug allocator's bookkeeping.
+#[derive(Default)]
+struct DebugState {
+    /// Every live allocation keyed by the address handed out to the caller.
+    live: HashMap<usize, Allocation>,
+    /// Addresses that have been freed and not handed out again.
+    freed: HashSet<usize>,
+    /// The ID to give the next allocation.
+    next_id: usize,
+}
+
+/// Statistics and error counts for a debug allocator.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct NSTDDebugAllocatorStats {
+    /// The number of allocations that have not been freed.
+    pub live_count: usize,
+    /// The number of bytes in allocations that have not been freed.
+    pub live_bytes: usize,
+    /// The total number of allocations that have been made.
+    pub total_allocations: usize,
+    /// The number of frees of memory that had already been freed.
+    pub double_frees: usize,
+    /// The number of frees of memory that was never allocated by the allocator.
+    pub invalid_frees: usize,
+    /// The number of frees or reallocations that were given the wrong size.
+    pub size_mismatches: usize,
+    /// The number of allocations found with corrupted guard bytes.
+    pub overruns: usize,
+}
+
+/// A debug allocator.
+///
+/// # Note
+///
+/// The debug allocator itself must be passed as `this` when using `allocator`. Every error that is
+/// detected is counted in `stats` and sets `allocator.errc`.
+#[repr(C)]
+pub struct NSTDDebugAllocator {
+    /// The debug allocator's vtable.
+    pub allocator: NSTDAllocator,
+    /// The caller tag recorded with each new allocation, may be null.
+    pub tag: *const NSTDChar,
+    /// The debug allocator's statistics and error counts.
+    pub stats: NSTDDebugAllocatorStats,
+    /// The raw handle to the debug allocator's bookkeeping.
+    pub handle: NSTDDebugAllocatorHandle,
+    /// The usage statistics reported through `allocator`.
+    pub usage: NSTDAllocStats,
+}
+impl NSTDDebugAllocator {
+    /// Returns the debug allocator's bookkeeping.
+    #[inline]
+    unsafe fn state(&mut self) -> &mut DebugState {
+        &mut *(self.handle as *mut DebugState)
+    }
+
+    /// Records an error.
+    #[inline]
+    fn error(&mut self) {
+        self.allocator.errc |= 1;
+    }
+
+    /// Allocates a new guarded block of memory.
+    unsafe fn allocate(&mut self, size: usize, align: usize, zero: bool) -> NSTDAny {
+        if !align.is_power_of_two() {
+            self.error();
+            return NSTD_CORE_NULL;
+        }
+        let allocation = Allocation {
+            size,
+            align,
+            tag: self.tag,
+            id: self.state().next_id,
+        };
+        let guard_size = allocation.guard_size();
+        let base = crate::alloc::nstd_alloc_allocate_aligned(allocation.byte_count(), guard_size);
+        if base.is_null() {
+            self.error();
+            return NSTD_CORE_NULL;
+        }
+        let base = base.cast::<u8>();
+        std::ptr::write_bytes(base, GUARD_BYTE, guard_size);
+        std::ptr::write_bytes(base.add(guard_size + size), GUARD_BYTE, guard_size);
+        if zero {
+            std::ptr::write_bytes(base.add(guard_size), 0, size);
+        }
+        let ptr = base.add(guard_size);
+        let state = self.state();
+        state.next_id += 1;
+        state.freed.remove(&(ptr as usize));
+        state.live.insert(ptr as usize, allocation);
+        self.stats.live_count += 1;
+        self.stats.live_bytes += size;
+        self.stats.total_allocations += 1;
+        ptr.cast()
+    }
+
+    /// Stops tracking a live allocation, checking it's size and guards.
+    ///
+    /// Returns the allocation, or `None` if `ptr` is not a live allocation.
+    unsafe fn release(&mut self, ptr: NSTDAny, size: usize) -> Option<Allocation> {
+        let address = ptr as usize;
+        let allocation = match self.state().live.remove(&address) {
+            Some(allocation) => allocation,
+            None => {
+                match self.state().freed.contains(&address) {
+                    true => self.stats.double_frees += 1,
+                    false => self.stats.invalid_frees += 1,
+                }
+                self.error();
+                return None;
+            }
+        };
+        if allocation.size != size {
+            self.stats.size_mismatches += 1;
+            self.error();
+        }
+        if !allocation.guards_intact(address) {
+            self.stats.overruns += 1;
+            self.error();
+        }
+        self.state().freed.insert(address);
+        self.stats.live_count -= 1;
+        self.stats.live_bytes -= allocation.size;
+        Some(allocation)
+    }
+
+    /// Frees a guarded block of memory.
+    unsafe fn deallocate(&mut self, ptr: &mut NSTDAny, size: usize) {
+        if let Some(allocation) = self.release(*ptr, size) {
+            self.allocator.errc |= free_allocation(*ptr, &allocation);
+            *ptr = NSTD_CORE_NULL;
+        }
+    }
+
+    /// Moves a guarded block of memory into a new block with `new_size` bytes.
+    unsafe fn reallocate(&mut self, ptr: &mut NSTDAny, size: usize, new_size: usize, align: usize) {
+        if ptr.is_null() {
+            *ptr = self.allocate(new_size, align, false);
+            if !ptr.is_null() {
+                self.usage.allocated(new_size);
+            }
+            return;
+        }
+        let old_size = match self.state().live.get(&(*ptr as usize)) {
+            Some(allocation) => allocation.size,
+            None => {
+                self.release(*ptr, size);
+                return;
+            }
+        };
+        let new_ptr = self.allocate(new_size, align, false);
+        if !new_ptr.is_null() {
+            std::ptr::copy_nonoverlapping(
+                *ptr as *const u8,
+                new_ptr.cast(),
+                old_size.min(new_size),
+            );
+            self.deallocate(ptr, size);
+            *ptr = new_ptr;
+            self.usage.reallocated(size, new_size);
+        }
+    }
+
+    /// Allocates a new guarded block of memory through the vtable, recording it in `usage`.
+    unsafe fn allocate_tracked(&mut self, size: usize, align: usize, zero: bool) -> NSTDAny {
+        let ptr = self.allocate(size, align, zero);
+        if !ptr.is_null() {
+            self.usage.allocated(size);
+        }
+        ptr
+    }
+
+    /// Frees a guarded block of memory through the vtable, recording it in `usage`.
+    unsafe fn deallocate_tracked(&mut self, ptr: &mut NSTDAny, size: usize) {
+        self.deallocate(ptr, size);
+        if ptr.is_null() {
+            self.usage.deallocated(size);
+        }
+    }
+}
+
+/// Releases the memory backing an allocation.
+unsafe fn free_allocation(ptr: NSTDAny, allocation: &Allocation) -> NSTDErrorCode {
+    let guard_size = allocation.guard_size();
+    let mut base = ptr.cast::<u8>().sub(guard_size).cast();
+    crate::alloc::nstd_alloc_deallocate_aligned(&mut base, allocation.byte_count(), guard_size)
+}
+
+/// Creates a new debug allocator.
+///
+/// # Returns
+///
+/// `NSTDDebugAllocator debug` - The new debug allocator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_debug_new() -> NSTDDebugAllocator {
+    NSTDDebugAllocator {
+        allocator: NSTDAllocator {
+            errc: 0,
+            allocate: Some(debug_allocate),
+            allocate_zeroed: Some(debug_allocate_zeroed),
+            reallocate: Some(debug_reallocate),
+            deallocate: Some(debug_deallocate),
+            allocate_aligned: Some(debug_allocate_aligned),
+            allocate_zeroed_aligned: Some(debug_allocate_zeroed_aligned),
+            reallocate_aligned: Some(debug_reallocate_aligned),
+            deallocate_aligned: Some(debug_deallocate_aligned),
+            stats: Some(debug_stats),
+        },
+        tag: std::ptr::null(),
+        stats: NSTDDebugAllocatorStats::default(),
+        handle: Box::into_raw(Box::<DebugState>::default()).cast(),
+        usage: NSTDAllocStats::default(),
+    }
+}
+
+/// Sets the caller tag that is recorded with each new allocation.
+///
+/// # Note
+///
+/// The tag is not copied, so it must outlive every allocation that is made while it is set.
+///
+/// # Parameters
+///
+/// - `NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// - `const NSTDChar *const tag` - The new caller tag, may be null.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_debug_set_tag(
+    debug: &mut NSTDDebugAllocator,
+    tag: *const NSTDChar,
+) {
+    debug.tag = tag;
+}
+
+/// Checks the guard bytes of every live allocation.
+///
+/// # Parameters
+///
+/// - `NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// # Returns
+///
+/// `NSTDUSize overruns` - The number of live allocations with corrupted guard bytes.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_debug_check(debug: &mut NSTDDebugAllocator) -> usize {
+    let overruns = debug
+        .state()
+        .live
+        .iter()
+        .filter(|(&ptr, allocation)| !allocation.guards_intact(ptr))
+        .count();
+    if overruns > 0 {
+        debug.stats.overruns += overruns;
+        debug.error();
+    }
+    overruns
+}
+
+/// Returns a debug allocator's statistics and error counts.
+///
+/// # Parameters
+///
+/// - `const NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// # Returns
+///
+/// `NSTDDebugAllocatorStats stats` - The debug allocator's statistics.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_debug_stats(
+    debug: &NSTDDebugAllocator,
+) -> NSTDDebugAllocatorStats {
+    debug.stats
+}
+
+/// Writes a report of every leaked allocation and detected error to an output stream.
+///
+/// # Note
+///
+/// Leaks are reported in the order that they were allocated.
+///
+/// # Parameters
+///
+/// - `const NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// - `NSTDOutputStream *const stream` - The stream to write the report to.
+///
+/// - `const NSTDAny owner` - The object that owns `stream`, such as an `NSTDFile`. This is passed
+///   to the stream's write function as `this`.
+///
+/// # Returns
+///
+/// `NSTDUSize leaks` - The number of allocations that have not been freed.
+#[cfg(feature = "nstd_io")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_io")))]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_debug_report(
+    debug: &NSTDDebugAllocator,
+    stream: &mut crate::io::output_stream::NSTDOutputStream,
+    owner: NSTDAny,
+) -> usize {
+    use std::{ffi::CStr, fmt::Write};
+    let mut leaks = (*(debug.handle as *const DebugState))
+        .live
+        .iter()
+        .collect::<Vec<_>>();
+    leaks.sort_unstable_by_key(|(_, allocation)| allocation.id);
+    let mut report = String::new();
+    for (&ptr, allocation) in &leaks {
+        let tag = match allocation.tag.is_null() {
+            true => "untagged".into(),
+            false => CStr::from_ptr(allocation.tag).to_string_lossy(),
+        };
+        let _ = writeln!(
+            report,
+            "leak: {} bytes at {:#x} ({})",
+            allocation.size, ptr, tag
+        );
+    }
+    let stats = debug.stats;
+    let _ = writeln!(
+        report,
+        "{} leaks ({} bytes), {} double frees, {} invalid frees, {} size mismatches, {} overruns",
+        stats.live_count,
+        stats.live_bytes,
+        stats.double_frees,
+        stats.invalid_frees,
+        stats.size_mismatches,
+        stats.overruns
+    );
+    if let Some(write) = stream.write {
+        let bytes = crate::core::slice::nstd_core_slice_new(report.len(), 1, report.as_ptr() as _);
+        write(owner, &bytes);
+    }
+    stats.live_count
+}
+
+/// Frees a debug allocator, releasing any allocations that were leaked.
+///
+/// # Parameters
+///
+/// - `NSTDDebugAllocator *const debug` - The debug allocator.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_debug_free(debug: &mut NSTDDebugAllocator) -> NSTDErrorCode {
+    let mut errc = 0;
+    if !debug.handle.is_null() {
+        let state = Box::from_raw(debug.handle as *mut DebugState);
+        for (&ptr, allocation) in &state.live {
+            errc |= free_allocation(ptr as NSTDAny, allocation);
+        }
+        debug.handle = NSTD_CORE_NULL;
+    }
+    debug.stats.live_count = 0;
+    debug.stats.live_bytes = 0;
+    debug.usage.live_bytes = 0;
+    errc
+}
+
+/// Returns the debug allocator that owns a vtable.
+#[inline]
+unsafe fn debug<'a>(this: NSTDAny) -> &'a mut NSTDDebugAllocator {
+    &mut *(this as *mut NSTDDebugAllocator)
+}
+
+/// The alignment used for allocations that don't request one.
+const DEFAULT_ALIGN: usize = 1;
+
+/// Debug allocate function.
+#[inline]
+unsafe extern "C" fn debug_allocate(this: NSTDAny, size: usize) -> NSTDAny {
+    debug(this).allocate_tracked(size, DEFAULT_ALIGN, false)
+}
+
+/// Debug allocate_zeroed function.
+#[inline]
+unsafe extern "C" fn debug_allocate_zeroed(this: NSTDAny, size: usize) -> NSTDAny {
+    debug(this).allocate_tracked(size, DEFAULT_ALIGN, true)
+}
+
+/// Debug reallocate function.
+#[inline]
+unsafe extern "C" fn debug_reallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize, new: usize) {
+    debug(this).reallocate(ptr, size, new, DEFAULT_ALIGN);
+}
+
+/// Debug deallocate function.
+#[inline]
+unsafe extern "C" fn debug_deallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize) {
+    debug(this).deallocate_tracked(ptr, size);
+}
+
+/// Debug allocate_aligned function.
+#[inline]
+unsafe extern "C" fn debug_allocate_aligned(this: NSTDAny, size: usize, align: usize) -> NSTDAny {
+    debug(this).allocate_tracked(size, align, false)
+}
+
+/// Debug allocate_zeroed_aligned function.
+#[inline]
+unsafe extern "C" fn debug_allocate_zeroed_aligned(
+    this: NSTDAny,
+    size: usize,
+    align: usize,
+) -> NSTDAny {
+    debug(this).allocate_tracked(size, align, true)
+}
+
+/// Debug reallocate_aligned function.
+#[inline]
+unsafe extern "C" fn debug_reallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) {
+    debug(this).reallocate(ptr, size, new_size, align);
+}
+
+/// Debug deallocate_aligned function.
+#[inline]
+unsafe extern "C" fn debug_deallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    _: usize,
+) {
+    debug(this).deallocate_tracked(ptr, size);
+}
+
+/// Debug stats function.
+#[inline]
+unsafe extern "C" fn debug_stats(this: NSTDAnyConst) -> NSTDAllocStats {
+    (*(this as *const NSTDDebugAllocator)).usage
+}
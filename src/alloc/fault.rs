@@ -0,0 +1,279 @@
+//! An allocator that injects allocation failures for testing out-of-memory handling.
+use crate::{
+    alloc::allocator::{NSTDAllocStats, NSTDAllocator},
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+        NSTD_CORE_NULL,
+    },
+};
+
+/// A fault-injecting allocator.
+///
+/// # Note
+///
+/// The fault allocator itself must be passed as `this` when using `allocator`. Memory is allocated
+/// with the `nstd_alloc` functions, so the fault allocator can be installed as the process-wide
+/// allocator with `nstd_alloc_set_global_allocator`. Reallocations that grow a block of memory are
+/// treated as allocations of the extra bytes, shrinking and deallocating never fail.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NSTDFaultAllocator {
+    /// The fault allocator's vtable.
+    pub allocator: NSTDAllocator,
+    /// Every allocation fails once `successes` reaches this value, -1/usize::MAX to never fail.
+    pub fail_after: usize,
+    /// The chance of each allocation failing, from 0.0 to 1.0.
+    pub failure_rate: f64,
+    /// The state of the random number generator used for `failure_rate`.
+    pub seed: u64,
+    /// The maximum number of bytes that may be allocated at once, -1/usize::MAX for no limit.
+    pub byte_budget: usize,
+    /// The number of bytes that are currently allocated.
+    pub live_bytes: usize,
+    /// The number of allocations that have succeeded.
+    pub successes: usize,
+    /// The number of allocations that have been made to fail.
+    pub failures: usize,
+    /// The usage statistics reported through `allocator`.
+    pub usage: NSTDAllocStats,
+}
+impl NSTDFaultAllocator {
+    /// Returns the next random number in the range [0.0, 1.0).
+    fn next_random(&mut self) -> f64 {
+        // xorshift64*.
+        self.seed ^= self.seed >> 12;
+        self.seed ^= self.seed << 25;
+        self.seed ^= self.seed >> 27;
+        let random = self.seed.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        (random >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Decides whether an allocation of `size` bytes should fail, recording the outcome.
+    fn should_fail(&mut self, size: usize) -> bool {
+        let over_budget = self.live_bytes.saturating_add(size) > self.byte_budget;
+        let fail = self.successes >= self.fail_after
+            || over_budget
+            || (self.failure_rate > 0.0 && self.next_random() < self.failure_rate);
+        match fail {
+            true => {
+                self.failures += 1;
+                self.allocator.errc |= 1;
+            }
+            false => self.successes += 1,
+        }
+        fail
+    }
+
+    /// Records the result of an allocation of `size` bytes.
+    fn allocated(&mut self, ptr: NSTDAny, size: usize) -> NSTDAny {
+        match ptr.is_null() {
+            true => self.allocator.errc |= 1,
+            false => {
+                self.live_bytes += size;
+                self.usage.allocated(size);
+            }
+        }
+        ptr
+    }
+
+    /// Records the result of a reallocation from `size` to `new_size` bytes.
+    fn reallocated(&mut self, errc: NSTDErrorCode, size: usize, new_size: usize) {
+        match errc {
+            0 => {
+                self.live_bytes = self.live_bytes.saturating_sub(size) + new_size;
+                self.usage.reallocated(size, new_size);
+            }
+            _ => self.allocator.errc |= errc,
+        }
+    }
+
+    /// Records a deallocation of `size` bytes.
+    fn deallocated(&mut self, errc: NSTDErrorCode, size: usize) {
+        self.allocator.errc |= errc;
+        self.live_bytes = self.live_bytes.saturating_sub(size);
+        if errc == 0 {
+            self.usage.deallocated(size);
+        }
+    }
+}
+
+/// Creates a new fault allocator that never fails.
+///
+/// # Returns
+///
+/// `NSTDFaultAllocator fault` - The new fault allocator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_fault_new() -> NSTDFaultAllocator {
+    NSTDFaultAllocator {
+        allocator: NSTDAllocator {
+            errc: 0,
+            allocate: Some(fault_allocate),
+            allocate_zeroed: Some(fault_allocate_zeroed),
+            reallocate: Some(fault_reallocate),
+            deallocate: Some(fault_deallocate),
+            allocate_aligned: Some(fault_allocate_aligned),
+            allocate_zeroed_aligned: Some(fault_allocate_zeroed_aligned),
+            reallocate_aligned: Some(fault_reallocate_aligned),
+            deallocate_aligned: Some(fault_deallocate_aligned),
+            stats: Some(fault_stats),
+        },
+        fail_after: usize::MAX,
+        failure_rate: 0.0,
+        seed: 0,
+        byte_budget: usize::MAX,
+        live_bytes: 0,
+        successes: 0,
+        failures: 0,
+        usage: NSTDAllocStats::default(),
+    }
+}
+
+/// Makes every allocation fail once `count` more allocations have succeeded.
+///
+/// # Parameters
+///
+/// - `NSTDFaultAllocator *const fault` - The fault allocator.
+///
+/// - `const NSTDUSize count` - The number of allocations to allow, -1/usize::MAX to never fail.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_fault_fail_after(fault: &mut NSTDFaultAllocator, count: usize) {
+    fault.fail_after = fault.successes.saturating_add(count);
+}
+
+/// Makes allocations fail randomly.
+///
+/// # Note
+///
+/// The same seed always produces the same sequence of failures.
+///
+/// # Parameters
+///
+/// - `NSTDFaultAllocator *const fault` - The fault allocator.
+///
+/// - `const NSTDFloat64 rate` - The chance of each allocation failing, from 0.0 to 1.0.
+///
+/// - `const NSTDUInt64 seed` - The seed for the random number generator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_fault_fail_randomly(
+    fault: &mut NSTDFaultAllocator,
+    rate: f64,
+    seed: u64,
+) {
+    fault.failure_rate = rate;
+    // A state of 0 would only ever produce 0.
+    fault.seed = seed | 1;
+}
+
+/// Makes allocations fail when they would exceed a byte budget.
+///
+/// # Parameters
+///
+/// - `NSTDFaultAllocator *const fault` - The fault allocator.
+///
+/// - `const NSTDUSize bytes` - The maximum number of live bytes, -1/usize::MAX for no limit.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_fault_set_budget(fault: &mut NSTDFaultAllocator, bytes: usize) {
+    fault.byte_budget = bytes;
+}
+
+/// Returns the fault allocator that owns a vtable.
+#[inline]
+unsafe fn fault<'a>(this: NSTDAny) -> &'a mut NSTDFaultAllocator {
+    &mut *(this as *mut NSTDFaultAllocator)
+}
+
+/// Fault allocate function.
+unsafe extern "C" fn fault_allocate(this: NSTDAny, size: usize) -> NSTDAny {
+    let fault = fault(this);
+    match fault.should_fail(size) {
+        true => NSTD_CORE_NULL,
+        false => fault.allocated(crate::alloc::nstd_alloc_allocate(size), size),
+    }
+}
+
+/// Fault allocate_zeroed function.
+unsafe extern "C" fn fault_allocate_zeroed(this: NSTDAny, size: usize) -> NSTDAny {
+    let fault = fault(this);
+    match fault.should_fail(size) {
+        true => NSTD_CORE_NULL,
+        false => fault.allocated(crate::alloc::nstd_alloc_allocate_zeroed(size), size),
+    }
+}
+
+/// Fault reallocate function.
+unsafe extern "C" fn fault_reallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize, new: usize) {
+    let fault = fault(this);
+    if new > size && fault.should_fail(new - size) {
+        return;
+    }
+    let errc = crate::alloc::nstd_alloc_reallocate(ptr, size, new);
+    fault.reallocated(errc, size, new);
+}
+
+/// Fault deallocate function.
+unsafe extern "C" fn fault_deallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize) {
+    let errc = crate::alloc::nstd_alloc_deallocate(ptr, size);
+    fault(this).deallocated(errc, size);
+}
+
+/// Fault allocate_aligned function.
+unsafe extern "C" fn fault_allocate_aligned(this: NSTDAny, size: usize, align: usize) -> NSTDAny {
+    let fault = fault(this);
+    match fault.should_fail(size) {
+        true => NSTD_CORE_NULL,
+        false => fault.allocated(crate::alloc::nstd_alloc_allocate_aligned(size, align), size),
+    }
+}
+
+/// Fault allocate_zeroed_aligned function.
+unsafe extern "C" fn fault_allocate_zeroed_aligned(
+    this: NSTDAny,
+    size: usize,
+    align: usize,
+) -> NSTDAny {
+    let fault = fault(this);
+    match fault.should_fail(size) {
+        true => NSTD_CORE_NULL,
+        false => {
+            let ptr = crate::alloc::nstd_alloc_allocate_zeroed_aligned(size, align);
+            fault.allocated(ptr, size)
+        }
+    }
+}
+
+/// Fault reallocate_aligned function.
+unsafe extern "C" fn fault_reallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) {
+    let fault = fault(this);
+    if new_size > size && fault.should_fail(new_size - size) {
+        return;
+    }
+    let errc = crate::alloc::nstd_alloc_reallocate_aligned(ptr, size, new_size, align);
+    fault.reallocated(errc, size, new_size);
+}
+
+/// Fault deallocate_aligned function.
+unsafe extern "C" fn fault_deallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    align: usize,
+) {
+    let errc = crate::alloc::nstd_alloc_deallocate_aligned(ptr, size, align);
+    fault(this).deallocated(errc, size);
+}
+
+/// Fault stats function.
+#[inline]
+unsafe extern "C" fn fault_stats(this: NSTDAnyConst) -> NSTDAllocStats {
+    (*(this as *const NSTDFaultAllocator)).usage
+}
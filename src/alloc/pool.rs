@@ -0,0 +1,325 @@
+//! A pool allocator that hands out fixed-size blocks of memory from pages.
+use crate::{
+    alloc::allocator::{NSTDAllocStats, NSTDAllocator},
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+        NSTD_CORE_NULL,
+    },
+};
+
+/// The header at the start of each of a pool's pages.
+#[repr(C)]
+struct PoolPage {
+    /// The previously allocated page.
+    prev: *mut PoolPage,
+    /// The total number of bytes allocated for this page.
+    byte_count: usize,
+}
+
+/// A pool allocator for objects of a single size.
+///
+/// # Note
+///
+/// The pool itself must be passed as `this` when using `allocator`. Requests for more than
+/// `object_size` bytes or with an alignment greater than `alignment` fail.
+///
+/// A pool created with invalid parameters has `allocator.errc` set and fails every allocation.
+#[repr(C)]
+pub struct NSTDPool {
+    /// The pool's allocator vtable.
+    pub allocator: NSTDAllocator,
+    /// The size of each object in bytes.
+    pub object_size: usize,
+    /// The alignment of each object in bytes.
+    pub alignment: usize,
+    /// The number of bytes between each object.
+    pub slot_size: usize,
+    /// The number of objects each page holds.
+    pub objects_per_page: usize,
+    /// The most recently allocated page, null if the pool has no pages.
+    pub pages: NSTDAny,
+    /// The first free object, null if there are none.
+    pub free_list: NSTDAny,
+    /// The number of pages the pool has allocated.
+    pub page_count: usize,
+    /// The number of objects that are currently allocated.
+    pub live_count: usize,
+    /// The usage statistics reported through `allocator`.
+    pub usage: NSTDAllocStats,
+}
+impl NSTDPool {
+    /// Allocates a new page and adds each of it's objects to the free list.
+    unsafe fn grow(&mut self) -> NSTDErrorCode {
+        // A slot size of 0 marks a pool that was created with invalid parameters.
+        if self.slot_size == 0 || self.objects_per_page == 0 {
+            return 1;
+        }
+        // Over allocate so the first object can be aligned.
+        let header_size = std::mem::size_of::<PoolPage>();
+        let byte_count = match self
+            .slot_size
+            .checked_mul(self.objects_per_page)
+            .and_then(|objects| objects.checked_add(header_size + self.alignment - 1))
+        {
+            Some(byte_count) => byte_count,
+            _ => return 1,
+        };
+        let page = crate::alloc::nstd_alloc_allocate(byte_count) as *mut PoolPage;
+        if page.is_null() {
+            return 1;
+        }
+        (*page).prev = self.pages.cast();
+        (*page).byte_count = byte_count;
+        self.pages = page.cast();
+        self.page_count += 1;
+        // Push the page's objects onto the free list in reverse so they are handed out in order.
+        let data = page.cast::<u8>().add(header_size);
+        let first = data.add(data.align_offset(self.alignment));
+        for i in (0..self.objects_per_page).rev() {
+            let object = first.add(i * self.slot_size);
+            *object.cast::<NSTDAny>() = self.free_list;
+            self.free_list = object.cast();
+        }
+        0
+    }
+}
+
+/// Creates a new pool allocator.
+///
+/// # Note
+///
+/// No memory is allocated until the first allocation is made. `alignment` must be a power of two
+/// and `objects_per_page` must be greater than 0, otherwise the returned pool's `allocator.errc`
+/// is set and every allocation from it fails.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize object_size` - The size of each object in bytes.
+///
+/// - `const NSTDUSize alignment` - The alignment of each object.
+///
+/// - `const NSTDUSize objects_per_page` - The number of objects each page holds.
+///
+/// # Returns
+///
+/// `NSTDPool pool` - The new pool allocator.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_pool_new(
+    object_size: usize,
+    alignment: usize,
+    objects_per_page: usize,
+) -> NSTDPool {
+    let valid = alignment.is_power_of_two() && objects_per_page != 0;
+    // Each free object stores a pointer to the next free object.
+    let alignment = alignment.max(std::mem::align_of::<NSTDAny>());
+    let slot_size = match valid {
+        true => object_size
+            .max(std::mem::size_of::<NSTDAny>())
+            .checked_add(alignment - 1)
+            .map(|size| size & !(alignment - 1))
+            .unwrap_or(0),
+        false => 0,
+    };
+    NSTDPool {
+        allocator: NSTDAllocator {
+            errc: (slot_size == 0) as NSTDErrorCode,
+            allocate: Some(pool_allocate),
+            allocate_zeroed: Some(pool_allocate_zeroed),
+            reallocate: Some(pool_reallocate),
+            deallocate: Some(pool_deallocate),
+            allocate_aligned: Some(pool_allocate_aligned),
+            allocate_zeroed_aligned: Some(pool_allocate_zeroed_aligned),
+            reallocate_aligned: Some(pool_reallocate_aligned),
+            deallocate_aligned: Some(pool_deallocate_aligned),
+            stats: Some(pool_stats),
+        },
+        object_size,
+        alignment,
+        slot_size,
+        objects_per_page,
+        pages: NSTD_CORE_NULL,
+        free_list: NSTD_CORE_NULL,
+        page_count: 0,
+        live_count: 0,
+        usage: NSTDAllocStats::default(),
+    }
+}
+
+/// Allocates an object from a pool.
+///
+/// # Parameters
+///
+/// - `NSTDPool *const pool` - The pool.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - The new object, null on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_pool_allocate(pool: &mut NSTDPool) -> NSTDAny {
+    if pool.free_list.is_null() && pool.grow() != 0 {
+        pool.allocator.errc |= 1;
+        return NSTD_CORE_NULL;
+    }
+    let object = pool.free_list;
+    pool.free_list = *object.cast::<NSTDAny>();
+    pool.live_count += 1;
+    object
+}
+
+/// Returns an object to a pool.
+///
+/// # Parameters
+///
+/// - `NSTDPool *const pool` - The pool.
+///
+/// - `NSTDAny *const ptr` - A pointer to the object, set to null once it has been returned.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_pool_deallocate(pool: &mut NSTDPool, ptr: &mut NSTDAny) {
+    if !ptr.is_null() {
+        *(*ptr).cast::<NSTDAny>() = pool.free_list;
+        pool.free_list = *ptr;
+        pool.live_count -= 1;
+        *ptr = NSTD_CORE_NULL;
+    }
+}
+
+/// Frees a pool and every page it has allocated.
+///
+/// # Note
+///
+/// All objects allocated from the pool become invalid.
+///
+/// # Parameters
+///
+/// - `NSTDPool *const pool` - The pool.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_alloc_pool_free(pool: &mut NSTDPool) -> NSTDErrorCode {
+    let mut errc = 0;
+    let mut page = pool.pages as *mut PoolPage;
+    while !page.is_null() {
+        let prev = (*page).prev;
+        let byte_count = (*page).byte_count;
+        let mut ptr: NSTDAny = page.cast();
+        errc |= crate::alloc::nstd_alloc_deallocate(&mut ptr, byte_count);
+        page = prev;
+    }
+    pool.pages = NSTD_CORE_NULL;
+    pool.free_list = NSTD_CORE_NULL;
+    pool.page_count = 0;
+    pool.live_count = 0;
+    pool.usage.live_bytes = 0;
+    errc
+}
+
+/// Pool allocate function.
+unsafe extern "C" fn pool_allocate(this: NSTDAny, size: usize) -> NSTDAny {
+    let pool = &mut *(this as *mut NSTDPool);
+    if size > pool.object_size {
+        pool.allocator.errc |= 1;
+        return NSTD_CORE_NULL;
+    }
+    let ptr = nstd_alloc_pool_allocate(pool);
+    if !ptr.is_null() {
+        pool.usage.allocated(size);
+    }
+    ptr
+}
+
+/// Pool allocate_zeroed function.
+unsafe extern "C" fn pool_allocate_zeroed(this: NSTDAny, size: usize) -> NSTDAny {
+    let ptr = pool_allocate(this, size);
+    if !ptr.is_null() {
+        std::ptr::write_bytes(ptr.cast::<u8>(), 0, size);
+    }
+    ptr
+}
+
+/// Pool reallocate function, objects can only be resized up to the pool's object size.
+///
+/// A null `ptr` is given a new object.
+unsafe extern "C" fn pool_reallocate(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+) {
+    if ptr.is_null() {
+        *ptr = pool_allocate(this, new_size);
+        return;
+    }
+    let pool = &mut *(this as *mut NSTDPool);
+    match new_size > pool.object_size {
+        true => pool.allocator.errc |= 1,
+        false => pool.usage.reallocated(size, new_size),
+    }
+}
+
+/// Pool deallocate function.
+#[inline]
+unsafe extern "C" fn pool_deallocate(this: NSTDAny, ptr: &mut NSTDAny, size: usize) {
+    let pool = &mut *(this as *mut NSTDPool);
+    if !ptr.is_null() {
+        pool.usage.deallocated(size);
+    }
+    nstd_alloc_pool_deallocate(pool, ptr);
+}
+
+/// Pool allocate_aligned function.
+unsafe extern "C" fn pool_allocate_aligned(this: NSTDAny, size: usize, align: usize) -> NSTDAny {
+    let pool = &mut *(this as *mut NSTDPool);
+    // Every slot is aligned to the pool's alignment, so smaller alignments are also satisfied.
+    if align > pool.alignment || !align.is_power_of_two() {
+        pool.allocator.errc |= 1;
+        return NSTD_CORE_NULL;
+    }
+    pool_allocate(this, size)
+}
+
+/// Pool allocate_zeroed_aligned function.
+unsafe extern "C" fn pool_allocate_zeroed_aligned(
+    this: NSTDAny,
+    size: usize,
+    align: usize,
+) -> NSTDAny {
+    let ptr = pool_allocate_aligned(this, size, align);
+    if !ptr.is_null() {
+        std::ptr::write_bytes(ptr.cast::<u8>(), 0, size);
+    }
+    ptr
+}
+
+/// Pool reallocate_aligned function, objects can only be resized up to the pool's object size.
+#[inline]
+unsafe extern "C" fn pool_reallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) {
+    match ptr.is_null() {
+        true => *ptr = pool_allocate_aligned(this, new_size, align),
+        false => pool_reallocate(this, ptr, size, new_size),
+    }
+}
+
+/// Pool deallocate_aligned function.
+#[inline]
+unsafe extern "C" fn pool_deallocate_aligned(
+    this: NSTDAny,
+    ptr: &mut NSTDAny,
+    size: usize,
+    _: usize,
+) {
+    pool_deallocate(this, ptr, size);
+}
+
+/// Pool stats function.
+#[inline]
+unsafe extern "C" fn pool_stats(this: NSTDAnyConst) -> NSTDAllocStats {
+    (*(this as *const NSTDPool)).usage
+}
@@ -1,4 +1,9 @@
 //! Contains types for storing sorted or unsorted collections of data.
+pub mod arc;
 pub mod bit_mask;
+pub mod deque;
+pub mod hash_map;
+pub mod ordered_map;
+pub mod priority_queue;
 pub mod rc;
 pub mod stack;
@@ -0,0 +1,216 @@
+//! An atomic reference counter. A heap allocated type that can be shared across multiple objects
+//! and threads, once the last object to own the data is freed the data is freed as well.
+use crate::{
+    alloc::heap::NSTDHeap,
+    core::{
+        def::{NSTDAny, NSTDErrorCode},
+        pointer::NSTDPointer,
+    },
+};
+use std::{
+    ptr::addr_of_mut,
+    sync::atomic::{fence, AtomicUsize, Ordering},
+};
+
+/// The state of an atomic reference counter.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NSTDARCState {
+    /// The number of atomic reference counters that own `data`.
+    pub count: AtomicUsize,
+    /// The number of weak references to this state, plus one while `count` is nonzero.
+    pub weak_count: AtomicUsize,
+    /// The shared data.
+    pub data: NSTDHeap,
+}
+
+/// An atomic reference counter. A heap allocated type that can be shared across multiple objects
+/// and threads, once the last object to own the data is freed the data is freed as well.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NSTDARC {
+    /// The atomic reference counter state. Only free once `state.weak_count` == 0.
+    pub state: NSTDHeap,
+}
+
+/// A weak reference to the data owned by an `NSTDARC`. Weak references do not keep the data alive.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NSTDARCWeak {
+    /// The atomic reference counter state. Only free once `state.weak_count` == 0.
+    pub state: NSTDHeap,
+}
+
+/// Returns the state of an atomic reference counter.
+#[inline]
+unsafe fn state<'a>(state: &NSTDHeap) -> &'a NSTDARCState {
+    &*(state.ptr.raw as *const NSTDARCState)
+}
+
+/// Releases one weak reference to an atomic reference counter state, freeing the state if it was
+/// the last one.
+unsafe fn release_weak(heap: &mut NSTDHeap) -> NSTDErrorCode {
+    if state(heap).weak_count.fetch_sub(1, Ordering::Release) == 1 {
+        fence(Ordering::Acquire);
+        return crate::alloc::heap::nstd_alloc_heap_free(heap);
+    }
+    0
+}
+
+/// Creates a new atomic reference counter.
+///
+/// # Parameters
+///
+/// - `const NSTDPointer *const ptr` - A pointer to the object to be placed on the heap.
+///
+/// # Returns
+///
+/// `NSTDARC arc` - The new atomic reference counter.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_new(ptr: &NSTDPointer) -> NSTDARC {
+    // Create a new ARC state.
+    let mut state = NSTDARCState {
+        count: AtomicUsize::new(1),
+        weak_count: AtomicUsize::new(1),
+        data: crate::alloc::heap::nstd_alloc_heap_new(ptr),
+    };
+    // Construct an atomic reference counter with a new state.
+    NSTDARC {
+        state: crate::alloc::heap::nstd_alloc_heap_from_raw(
+            addr_of_mut!(state).cast(),
+            std::mem::size_of::<NSTDARCState>(),
+        ),
+    }
+}
+
+/// Shares the atomic reference counter, creating a new instance of `NSTDARC` and increasing the
+/// reference count.
+///
+/// # Parameters
+///
+/// - `const NSTDARC *const arc` - A pointer to the atomic reference counter.
+///
+/// # Returns
+///
+/// `NSTDARC new_arc` - The new atomic reference counter that points to the new data.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_share(arc: &NSTDARC) -> NSTDARC {
+    // Increase the state's reference count by one.
+    state(&arc.state).count.fetch_add(1, Ordering::Relaxed);
+    // Create a new atomic reference counter that points to the state.
+    NSTDARC {
+        state: crate::alloc::heap::nstd_alloc_heap_from_existing(&arc.state.ptr),
+    }
+}
+
+/// Returns a pointer to the underlying data.
+///
+/// # Parameters
+///
+/// - `const NSTDARC *const arc` - A pointer to the atomic reference counter.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the underlying data.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_get(arc: &NSTDARC) -> NSTDAny {
+    state(&arc.state).data.ptr.raw
+}
+
+/// Frees an atomic reference counter, only frees the underlying data once all other atomic
+/// reference counters have been freed as well.
+///
+/// # Parameters
+///
+/// - `NSTDARC *const arc` - The atomic reference counter to free.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_free(arc: &mut NSTDARC) -> NSTDErrorCode {
+    let mut errc = 0;
+    let state = &mut *(arc.state.ptr.raw as *mut NSTDARCState);
+    // Decrease the reference count, freeing the referenced memory if it reaches 0.
+    if state.count.fetch_sub(1, Ordering::Release) == 1 {
+        fence(Ordering::Acquire);
+        errc |= crate::alloc::heap::nstd_alloc_heap_free(&mut state.data);
+        // Release the weak reference held by the strong references.
+        errc |= release_weak(&mut arc.state);
+    }
+    errc
+}
+
+/// Creates a weak reference to an atomic reference counter's data.
+///
+/// # Parameters
+///
+/// - `const NSTDARC *const arc` - A pointer to the atomic reference counter.
+///
+/// # Returns
+///
+/// `NSTDARCWeak weak` - The new weak reference.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_downgrade(arc: &NSTDARC) -> NSTDARCWeak {
+    state(&arc.state).weak_count.fetch_add(1, Ordering::Relaxed);
+    NSTDARCWeak {
+        state: crate::alloc::heap::nstd_alloc_heap_from_existing(&arc.state.ptr),
+    }
+}
+
+/// Attempts to create a new atomic reference counter from a weak reference.
+///
+/// # Parameters
+///
+/// - `const NSTDARCWeak *const weak` - A pointer to the weak reference.
+///
+/// - `NSTDARC *const arc` - Returns as the new atomic reference counter on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the data has already been freed.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_weak_upgrade(
+    weak: &NSTDARCWeak,
+    arc: &mut NSTDARC,
+) -> NSTDErrorCode {
+    // Only increase the reference count if the data is still alive.
+    let count = &state(&weak.state).count;
+    let mut current = count.load(Ordering::Relaxed);
+    while current != 0 {
+        match count.compare_exchange_weak(
+            current,
+            current + 1,
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => {
+                *arc = NSTDARC {
+                    state: crate::alloc::heap::nstd_alloc_heap_from_existing(&weak.state.ptr),
+                };
+                return 0;
+            }
+            Err(actual) => current = actual,
+        }
+    }
+    1
+}
+
+/// Frees a weak reference, the atomic reference counter state is freed once there are no
+/// references to it left.
+///
+/// # Parameters
+///
+/// - `NSTDARCWeak *const weak` - The weak reference to free.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_arc_weak_free(weak: &mut NSTDARCWeak) -> NSTDErrorCode {
+    release_weak(&mut weak.state)
+}
@@ -1,15 +1,81 @@
 //! A bit mask type with a small memory footprint.
 use crate::{
-    core::def::{NSTDBitValue, NSTDErrorCode},
+    core::def::{NSTDBitValue, NSTDBool, NSTDErrorCode},
     vec::NSTDVec,
 };
 
+/// The type of each word in a bit mask.
+type Word = u64;
+
+/// The number of bits in each word of a bit mask.
+const WORD_BITS: u32 = Word::BITS;
+
+/// Returned by `nstd_collections_bit_mask_first_set` and `nstd_collections_bit_mask_next_set` when
+/// no bit is found.
+pub const NSTD_COLLECTIONS_BIT_MASK_NONE: u32 = u32::MAX;
+
 /// A bit mask type with a small memory footprint.
 #[repr(C)]
 #[derive(Debug)]
 pub struct NSTDBitMask {
-    /// Vector of bytes.
-    pub bytes: NSTDVec,
+    /// The number of bits in the bit mask.
+    pub size: u32,
+    /// Vector of 64-bit words, bits past `size` in the last word are always off.
+    pub words: NSTDVec,
+}
+impl NSTDBitMask {
+    /// Returns the bit mask's words as a slice.
+    #[inline]
+    unsafe fn words(&self) -> &[Word] {
+        std::slice::from_raw_parts(self.words.buffer.ptr.raw.cast(), self.words.size)
+    }
+
+    /// Returns the bit mask's words as a mutable slice.
+    #[inline]
+    unsafe fn words_mut(&mut self) -> &mut [Word] {
+        std::slice::from_raw_parts_mut(self.words.buffer.ptr.raw.cast(), self.words.size)
+    }
+
+    /// Returns a mask of the bits that are in use in the last word.
+    #[inline]
+    fn tail_mask(&self) -> Word {
+        match self.size % WORD_BITS {
+            0 => !0,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    /// Turns off the unused bits in the last word.
+    #[inline]
+    unsafe fn clear_tail(&mut self) {
+        let tail_mask = self.tail_mask();
+        if let Some(last) = self.words_mut().last_mut() {
+            *last &= tail_mask;
+        }
+    }
+
+    /// Returns the index of the first bit that is on, starting at word `index` with the bits in
+    /// `word`.
+    #[inline]
+    unsafe fn scan(&self, mut index: usize, mut word: Word) -> u32 {
+        let words = self.words();
+        loop {
+            if word != 0 {
+                return index as u32 * WORD_BITS + word.trailing_zeros();
+            }
+            index += 1;
+            match words.get(index) {
+                Some(next) => word = *next,
+                None => return NSTD_COLLECTIONS_BIT_MASK_NONE,
+            }
+        }
+    }
+}
+
+/// Returns the number of words needed to store `size` bits.
+#[inline]
+unsafe fn word_count(size: u32) -> usize {
+    crate::core::math::nstd_core_math_div_ceil_u32(size, WORD_BITS) as usize
 }
 
 /// Creates a new bit mask with `size` number of bits.
@@ -24,10 +90,10 @@ pub struct NSTDBitMask {
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_collections_bit_mask_new(size: u32) -> NSTDBitMask {
-    let size = crate::core::math::nstd_core_math_div_ceil_u32(size, u8::BITS) as usize;
-    let mut bytes = crate::vec::nstd_vec_new_with_capacity(1, size);
-    crate::vec::nstd_vec_resize(&mut bytes, size);
-    NSTDBitMask { bytes }
+    let count = word_count(size);
+    let mut words = crate::vec::nstd_vec_new_with_capacity(std::mem::size_of::<Word>(), count);
+    crate::vec::nstd_vec_resize(&mut words, count);
+    NSTDBitMask { size, words }
 }
 
 /// Sets a bit to either on (1) or off (0) depending on `mode` where `NSTD_BIT_VALUE_ON` is on.
@@ -36,7 +102,8 @@ pub unsafe extern "C" fn nstd_collections_bit_mask_new(size: u32) -> NSTDBitMask
 ///
 /// - `NSTDBitMask *const mask` - The bit mask.
 ///
-/// - `const NSTDUInt32 pos` - The bit index to set.
+/// - `const NSTDUInt32 pos` - The bit index to set, nothing is done if it is not less than the
+///   bit mask's size.
 ///
 /// - `const NSTDBitValue mode` - The mode to set the bit.
 #[cfg_attr(feature = "clib", no_mangle)]
@@ -45,12 +112,16 @@ pub unsafe extern "C" fn nstd_collections_bit_mask_set(
     pos: u32,
     mode: NSTDBitValue,
 ) {
-    let byte_pos = crate::core::math::nstd_core_math_div_floor_u32(pos, u8::BITS) as usize;
-    let bit_pos = pos % u8::BITS;
-    let byte = mask.bytes.buffer.ptr.raw.cast::<u8>().add(byte_pos);
-    match mode {
-        NSTDBitValue::NSTD_BIT_VALUE_ON => *byte |= 1 << bit_pos,
-        NSTDBitValue::NSTD_BIT_VALUE_OFF => *byte &= !(1 << bit_pos),
+    if pos >= mask.size {
+        return;
+    }
+    let word_pos = crate::core::math::nstd_core_math_div_floor_u32(pos, WORD_BITS) as usize;
+    let bit_pos = pos % WORD_BITS;
+    if let Some(word) = mask.words_mut().get_mut(word_pos) {
+        match mode {
+            NSTDBitValue::NSTD_BIT_VALUE_ON => *word |= 1 << bit_pos,
+            NSTDBitValue::NSTD_BIT_VALUE_OFF => *word &= !(1 << bit_pos),
+        }
     }
 }
 
@@ -64,16 +135,19 @@ pub unsafe extern "C" fn nstd_collections_bit_mask_set(
 ///
 /// # Returns
 ///
-/// `NSTDBitValue is_on` - `NSTD_BIT_VALUE_ON` if the bit is on (1).
+/// `NSTDBitValue is_on` - `NSTD_BIT_VALUE_ON` if the bit is on (1), `NSTD_BIT_VALUE_OFF` if `pos`
+/// is not less than the bit mask's size.
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_collections_bit_mask_get(
     mask: &NSTDBitMask,
     pos: u32,
 ) -> NSTDBitValue {
-    let byte_pos = crate::core::math::nstd_core_math_div_floor_u32(pos, u8::BITS) as usize;
-    let bit_pos = pos % u8::BITS;
-    let byte = mask.bytes.buffer.ptr.raw.cast::<u8>().add(byte_pos);
-    (((*byte >> bit_pos) & 1) != 0).into()
+    let word_pos = crate::core::math::nstd_core_math_div_floor_u32(pos, WORD_BITS) as usize;
+    let bit_pos = pos % WORD_BITS;
+    match mask.words().get(word_pos) {
+        Some(word) if pos < mask.size => ((word >> bit_pos) & 1 != 0).into(),
+        _ => NSTDBitValue::NSTD_BIT_VALUE_OFF,
+    }
 }
 
 /// Sets all bits to `mode`.
@@ -92,9 +166,257 @@ pub unsafe extern "C" fn nstd_collections_bit_mask_set_all(
         NSTDBitValue::NSTD_BIT_VALUE_ON => !0,
         NSTDBitValue::NSTD_BIT_VALUE_OFF => 0,
     };
-    for byte in mask.bytes.buffer.as_byte_slice_mut() {
-        *byte = mode;
+    mask.words_mut().fill(mode);
+    mask.clear_tail();
+}
+
+/// Returns the number of bits in a bit mask.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDUInt32 size` - The number of bits in the bit mask.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_len(mask: &NSTDBitMask) -> u32 {
+    mask.size
+}
+
+/// Resizes a bit mask, any new bits are off.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask.
+///
+/// - `const NSTDUInt32 new_size` - The new number of bits in the bit mask.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_resize(
+    mask: &mut NSTDBitMask,
+    new_size: u32,
+) -> NSTDErrorCode {
+    let errc = crate::vec::nstd_vec_resize(&mut mask.words, word_count(new_size));
+    if errc == 0 {
+        mask.size = new_size;
+        mask.clear_tail();
     }
+    errc
+}
+
+/// Turns off each bit in `mask` that is off in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `other` are treated as off.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask to modify.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_and(
+    mask: &mut NSTDBitMask,
+    other: &NSTDBitMask,
+) {
+    let other = other.words();
+    for (i, word) in mask.words_mut().iter_mut().enumerate() {
+        *word &= other.get(i).copied().unwrap_or(0);
+    }
+}
+
+/// Turns on each bit in `mask` that is on in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `mask` are ignored.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask to modify.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_or(mask: &mut NSTDBitMask, other: &NSTDBitMask) {
+    for (word, other) in mask.words_mut().iter_mut().zip(other.words()) {
+        *word |= *other;
+    }
+    mask.clear_tail();
+}
+
+/// Toggles each bit in `mask` that is on in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `mask` are ignored.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask to modify.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_xor(
+    mask: &mut NSTDBitMask,
+    other: &NSTDBitMask,
+) {
+    for (word, other) in mask.words_mut().iter_mut().zip(other.words()) {
+        *word ^= *other;
+    }
+    mask.clear_tail();
+}
+
+/// Toggles every bit in a bit mask.
+///
+/// # Parameters
+///
+/// - `NSTDBitMask *const mask` - The bit mask.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_not(mask: &mut NSTDBitMask) {
+    for word in mask.words_mut() {
+        *word = !*word;
+    }
+    mask.clear_tail();
+}
+
+/// Returns the number of bits that are on in a bit mask.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDUInt32 count` - The number of bits that are on.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_count(mask: &NSTDBitMask) -> u32 {
+    mask.words().iter().map(|word| word.count_ones()).sum()
+}
+
+/// Returns the index of the first bit that is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDUInt32 pos` - The index of the first bit that is on, `NSTD_COLLECTIONS_BIT_MASK_NONE` if
+/// all bits are off.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_first_set(mask: &NSTDBitMask) -> u32 {
+    match mask.words().first() {
+        Some(word) => mask.scan(0, *word),
+        None => NSTD_COLLECTIONS_BIT_MASK_NONE,
+    }
+}
+
+/// Returns the index of the first bit after `pos` that is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// - `const NSTDUInt32 pos` - The bit index to search after.
+///
+/// # Returns
+///
+/// `NSTDUInt32 next` - The index of the next bit that is on, `NSTD_COLLECTIONS_BIT_MASK_NONE` if
+/// there are none.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_next_set(mask: &NSTDBitMask, pos: u32) -> u32 {
+    if pos >= mask.size.saturating_sub(1) {
+        return NSTD_COLLECTIONS_BIT_MASK_NONE;
+    }
+    let start = pos + 1;
+    let index = (start / WORD_BITS) as usize;
+    // Ignore the bits before `start` in the first word.
+    let word = mask.words()[index] & (!0 << (start % WORD_BITS));
+    mask.scan(index, word)
+}
+
+/// Determines whether any bit in a bit mask is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool any` - `NSTD_BOOL_TRUE` if at least one bit is on.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_any(mask: &NSTDBitMask) -> NSTDBool {
+    mask.words().iter().any(|word| *word != 0).into()
+}
+
+/// Determines whether every bit in a bit mask is on.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool all` - `NSTD_BOOL_TRUE` if every bit is on, or the bit mask is empty.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_all(mask: &NSTDBitMask) -> NSTDBool {
+    match mask.words().split_last() {
+        Some((last, rest)) => {
+            (rest.iter().all(|word| *word == !0) && *last == mask.tail_mask()).into()
+        }
+        None => NSTDBool::NSTD_BOOL_TRUE,
+    }
+}
+
+/// Determines whether every bit in a bit mask is off.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool none` - `NSTD_BOOL_TRUE` if every bit is off.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_none(mask: &NSTDBitMask) -> NSTDBool {
+    mask.words().iter().all(|word| *word == 0).into()
+}
+
+/// Determines whether every bit that is on in `mask` is also on in `other`.
+///
+/// # Note
+///
+/// Bits past the end of `other` are treated as off.
+///
+/// # Parameters
+///
+/// - `const NSTDBitMask *const mask` - The bit mask.
+///
+/// - `const NSTDBitMask *const other` - The other bit mask.
+///
+/// # Returns
+///
+/// `NSTDBool is_subset` - `NSTD_BOOL_TRUE` if `mask` is a subset of `other`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_bit_mask_is_subset(
+    mask: &NSTDBitMask,
+    other: &NSTDBitMask,
+) -> NSTDBool {
+    let other = other.words();
+    let is_subset = mask.words().iter().enumerate().all(|(i, word)| {
+        let other = other.get(i).copied().unwrap_or(0);
+        word & !other == 0
+    });
+    is_subset.into()
 }
 
 /// Frees an `NSTDBitMask`.
@@ -109,5 +431,5 @@ pub unsafe extern "C" fn nstd_collections_bit_mask_set_all(
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_collections_bit_mask_free(mask: &mut NSTDBitMask) -> NSTDErrorCode {
-    crate::vec::nstd_vec_free(&mut mask.bytes)
+    crate::vec::nstd_vec_free(&mut mask.words)
 }
@@ -0,0 +1,373 @@
+//! A double-ended queue backed by a growable ring buffer.
+use crate::core::{
+    def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+    slice::NSTDSlice,
+    NSTD_CORE_NULL,
+};
+
+/// A double-ended queue backed by a growable ring buffer.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NSTDDeque {
+    /// The number of active elements in the deque.
+    pub size: usize,
+    /// The buffer index of the first element.
+    pub head: usize,
+    /// Buffer of allocated memory where `buffer.size` is the capacity, `buffer.ptr.size` is the
+    /// size of each element, and `buffer.ptr.raw` is a raw pointer to the buffer.
+    pub buffer: NSTDSlice,
+}
+impl NSTDDeque {
+    /// Returns a pointer to the element stored at buffer index `index`.
+    #[inline]
+    unsafe fn slot(&self, index: usize) -> NSTDAny {
+        self.buffer.ptr.raw.add(index * self.buffer.ptr.size)
+    }
+
+    /// Converts a deque position into a buffer index.
+    #[inline]
+    fn wrap(&self, pos: usize) -> usize {
+        let index = self.head + pos;
+        match index >= self.buffer.size {
+            true => index - self.buffer.size,
+            false => index,
+        }
+    }
+
+    /// Makes sure there is room for at least one more element.
+    #[inline]
+    unsafe fn grow(&mut self) -> NSTDErrorCode {
+        match self.size == self.buffer.size {
+            true => {
+                let new_cap = (self.buffer.size as f32 * 1.5).ceil() as usize;
+                nstd_collections_deque_reserve(self, new_cap.max(self.buffer.size + 1))
+            }
+            false => 0,
+        }
+    }
+}
+
+/// Creates a new deque.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the deque.
+///
+/// # Returns
+///
+/// `NSTDDeque deque` - The new deque.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_new(element_size: usize) -> NSTDDeque {
+    const INITIAL_CAPACITY: usize = 1;
+    nstd_collections_deque_new_with_capacity(element_size, INITIAL_CAPACITY)
+}
+
+/// Creates a new deque with the specified capacity.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the deque.
+///
+/// - `const NSTDUSize capacity` - The capacity to give the deque, must be greater than 0.
+///
+/// # Returns
+///
+/// `NSTDDeque deque` - The new deque.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_new_with_capacity(
+    element_size: usize,
+    capacity: usize,
+) -> NSTDDeque {
+    let data = crate::alloc::nstd_alloc_allocate(capacity * element_size);
+    NSTDDeque {
+        size: 0,
+        head: 0,
+        buffer: crate::core::slice::nstd_core_slice_new(capacity, element_size, data),
+    }
+}
+
+/// Gets a pointer to an element from a deque, where position 0 is the front of the deque.
+///
+/// # Note
+///
+/// The returned element pointer can quickly become a dangling pointer if the deque's memory
+/// gets reallocated or deallocated, so it is advised to create a copy of the element after
+/// getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDUSize pos` - The position of the element to get.
+///
+/// # Returns
+///
+/// `NSTDAny element` - Pointer to the element.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_get(deque: &NSTDDeque, pos: usize) -> NSTDAny {
+    match deque.size > pos {
+        true => deque.slot(deque.wrap(pos)),
+        false => NSTD_CORE_NULL,
+    }
+}
+
+/// Gets the element at the front of the deque.
+///
+/// # Note
+///
+/// This function follows the same behaviour rules as `nstd_collections_deque_get`.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - Pointer to the front element.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_front(deque: &NSTDDeque) -> NSTDAny {
+    nstd_collections_deque_get(deque, 0)
+}
+
+/// Gets the element at the back of the deque.
+///
+/// # Note
+///
+/// This function follows the same behaviour rules as `nstd_collections_deque_get`.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - Pointer to the back element.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_back(deque: &NSTDDeque) -> NSTDAny {
+    match deque.size > 0 {
+        true => nstd_collections_deque_get(deque, deque.size - 1),
+        false => NSTD_CORE_NULL,
+    }
+}
+
+/// Pushes an element onto the back of a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDAnyConst element` - Pointer to the new element.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_push_back(
+    deque: &mut NSTDDeque,
+    element: NSTDAnyConst,
+) -> NSTDErrorCode {
+    let errc = deque.grow();
+    if errc != 0 {
+        return errc;
+    }
+    let slot = deque.slot(deque.wrap(deque.size));
+    std::ptr::copy_nonoverlapping(element, slot, deque.buffer.ptr.size);
+    deque.size += 1;
+    0
+}
+
+/// Pushes an element onto the front of a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDAnyConst element` - Pointer to the new element.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_push_front(
+    deque: &mut NSTDDeque,
+    element: NSTDAnyConst,
+) -> NSTDErrorCode {
+    let errc = deque.grow();
+    if errc != 0 {
+        return errc;
+    }
+    deque.head = match deque.head {
+        0 => deque.buffer.size - 1,
+        head => head - 1,
+    };
+    std::ptr::copy_nonoverlapping(element, deque.slot(deque.head), deque.buffer.ptr.size);
+    deque.size += 1;
+    0
+}
+
+/// Pops a value off of the back of a deque and returns a pointer to it.
+///
+/// # Note
+///
+/// The returned element pointer is only valid until the next element is pushed onto the deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - The element that was removed, null if the deque is empty.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_pop_back(deque: &mut NSTDDeque) -> NSTDAny {
+    if deque.size > 0 {
+        deque.size -= 1;
+        return deque.slot(deque.wrap(deque.size));
+    }
+    NSTD_CORE_NULL
+}
+
+/// Pops a value off of the front of a deque and returns a pointer to it.
+///
+/// # Note
+///
+/// The returned element pointer is only valid until the next element is pushed onto the deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDAny element` - The element that was removed, null if the deque is empty.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_pop_front(deque: &mut NSTDDeque) -> NSTDAny {
+    if deque.size > 0 {
+        let element = deque.slot(deque.head);
+        deque.head = deque.wrap(1);
+        deque.size -= 1;
+        return element;
+    }
+    NSTD_CORE_NULL
+}
+
+/// Returns the contents of a deque, front to back, as two slices.
+///
+/// # Note
+///
+/// `second` will be empty if the deque's elements are contiguous in memory.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// - `NSTDSlice *const first` - Returns as the front part of the deque.
+///
+/// - `NSTDSlice *const second` - Returns as the back part of the deque.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_as_slices(
+    deque: &NSTDDeque,
+    first: &mut NSTDSlice,
+    second: &mut NSTDSlice,
+) {
+    let element_size = deque.buffer.ptr.size;
+    let first_size = deque.size.min(deque.buffer.size - deque.head);
+    let head = deque.slot(deque.head);
+    *first = crate::core::slice::nstd_core_slice_new(first_size, element_size, head);
+    let second_size = deque.size - first_size;
+    let start = deque.buffer.ptr.raw;
+    *second = crate::core::slice::nstd_core_slice_new(second_size, element_size, start);
+}
+
+/// Returns the number of elements in a deque.
+///
+/// # Parameters
+///
+/// - `const NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The length of the deque.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_len(deque: &NSTDDeque) -> usize {
+    deque.size
+}
+
+/// Clears the contents of a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_clear(deque: &mut NSTDDeque) {
+    deque.size = 0;
+    deque.head = 0;
+}
+
+/// Reserves memory for the deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// - `const NSTDUSize new_cap` - The new, greater capacity for the deque.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_reserve(
+    deque: &mut NSTDDeque,
+    new_cap: usize,
+) -> NSTDErrorCode {
+    if deque.buffer.size < new_cap {
+        let old_cap = deque.buffer.size;
+        let old_byte_count = deque.buffer.byte_count();
+        let new_byte_count = new_cap * deque.buffer.ptr.size;
+        let errc = crate::alloc::nstd_alloc_reallocate(
+            &mut deque.buffer.ptr.raw,
+            old_byte_count,
+            new_byte_count,
+        );
+        if errc != 0 {
+            return errc;
+        }
+        deque.buffer.size = new_cap;
+        // Move the front part of a wrapped deque to the end of the new buffer.
+        if deque.head + deque.size > old_cap {
+            let count = old_cap - deque.head;
+            let new_head = new_cap - count;
+            let bytes = count * deque.buffer.ptr.size;
+            std::ptr::copy(deque.slot(deque.head), deque.slot(new_head), bytes);
+            deque.head = new_head;
+        }
+        0
+    } else {
+        1
+    }
+}
+
+/// Frees a deque.
+///
+/// # Parameters
+///
+/// - `NSTDDeque *const deque` - The deque.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_deque_free(deque: &mut NSTDDeque) -> NSTDErrorCode {
+    let bytes = deque.buffer.byte_count();
+    crate::alloc::nstd_alloc_deallocate(&mut deque.buffer.ptr.raw, bytes)
+}
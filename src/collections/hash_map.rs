@@ -0,0 +1,456 @@
+//! An unordered map of keys to values.
+use crate::{
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDBool, NSTDErrorCode},
+        str::NSTDStr,
+        NSTD_CORE_NULL,
+    },
+    vec::*,
+};
+
+/// A slot that has never held an entry.
+const SLOT_EMPTY: u8 = 0;
+/// A slot that currently holds an entry.
+const SLOT_OCCUPIED: u8 = 1;
+/// A slot that held an entry that has since been removed.
+const SLOT_REMOVED: u8 = 2;
+/// The number of slots to allocate on the first insertion.
+const INITIAL_CAPACITY: usize = 8;
+
+/// An unordered map of keys to values.
+#[repr(C)]
+pub struct NSTDHashMap {
+    /// The number of entries in the map.
+    pub size: usize,
+    /// The number of slots that have been marked as removed.
+    pub removed: usize,
+    /// The state of each slot, one byte per slot.
+    pub states: NSTDVec,
+    /// The key stored in each slot, `keys.buffer.ptr.size` is the size of each key.
+    pub keys: NSTDVec,
+    /// The value stored in each slot, `values.buffer.ptr.size` is the size of each value.
+    pub values: NSTDVec,
+    /// Hashes a key.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst key` - A pointer to the key.
+    ///
+    /// - `NSTDUSize key_size` - The size of the key in bytes.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDUInt64 hash` - The key's hash.
+    pub hash: unsafe extern "C" fn(NSTDAnyConst, usize) -> u64,
+    /// Checks if two keys are equal.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst key1` - A pointer to the first key.
+    ///
+    /// - `NSTDAnyConst key2` - A pointer to the second key.
+    ///
+    /// - `NSTDUSize key_size` - The size of each key in bytes.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDBool is_eq` - True if the two keys are equal.
+    pub compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst, usize) -> NSTDBool,
+}
+impl NSTDHashMap {
+    /// Returns the number of slots in the map.
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.states.size
+    }
+
+    /// Returns a pointer to the state of the slot at `index`.
+    #[inline]
+    unsafe fn state(&self, index: usize) -> *mut u8 {
+        self.states.buffer.ptr.raw.cast::<u8>().add(index)
+    }
+
+    /// Returns a pointer to the key of the slot at `index`.
+    #[inline]
+    unsafe fn key(&self, index: usize) -> NSTDAny {
+        self.keys
+            .buffer
+            .ptr
+            .raw
+            .add(index * self.keys.buffer.ptr.size)
+    }
+
+    /// Returns a pointer to the value of the slot at `index`.
+    #[inline]
+    unsafe fn value(&self, index: usize) -> NSTDAny {
+        self.values
+            .buffer
+            .ptr
+            .raw
+            .add(index * self.values.buffer.ptr.size)
+    }
+
+    /// Searches for `key`, returning the slot that holds it or the slot it should be inserted into.
+    unsafe fn find_slot(&self, key: NSTDAnyConst) -> (bool, usize) {
+        let key_size = self.keys.buffer.ptr.size;
+        let mask = self.capacity() - 1;
+        let mut index = (self.hash)(key, key_size) as usize & mask;
+        let mut first_removed = None;
+        for _ in 0..self.capacity() {
+            match *self.state(index) {
+                SLOT_EMPTY => return (false, first_removed.unwrap_or(index)),
+                SLOT_OCCUPIED => {
+                    if (self.compare)(key, self.key(index), key_size).into() {
+                        return (true, index);
+                    }
+                }
+                _ => {
+                    first_removed.get_or_insert(index);
+                }
+            }
+            index = (index + 1) & mask;
+        }
+        (false, first_removed.unwrap_or(usize::MAX))
+    }
+
+    /// Moves every entry into a new set of `capacity` slots, dropping removed slots.
+    unsafe fn rehash(&mut self, capacity: usize) -> NSTDErrorCode {
+        // Allocate the new slots.
+        let mut states = nstd_vec_new_with_capacity(1, capacity);
+        let mut keys = nstd_vec_new_with_capacity(self.keys.buffer.ptr.size, capacity);
+        let mut values = nstd_vec_new_with_capacity(self.values.buffer.ptr.size, capacity);
+        let mut errc = nstd_vec_resize(&mut states, capacity);
+        errc |= nstd_vec_resize(&mut keys, capacity);
+        errc |= nstd_vec_resize(&mut values, capacity);
+        if errc != 0 {
+            nstd_vec_free(&mut states);
+            nstd_vec_free(&mut keys);
+            nstd_vec_free(&mut values);
+            return errc;
+        }
+        // Swap the new slots in and move each entry over.
+        let old_capacity = self.capacity();
+        let mut old_states = std::mem::replace(&mut self.states, states);
+        let mut old_keys = std::mem::replace(&mut self.keys, keys);
+        let mut old_values = std::mem::replace(&mut self.values, values);
+        for i in 0..old_capacity {
+            if *old_states.buffer.ptr.raw.cast::<u8>().add(i) == SLOT_OCCUPIED {
+                let key = nstd_vec_get(&old_keys, i);
+                let value = nstd_vec_get(&old_values, i);
+                let (_, index) = self.find_slot(key);
+                *self.state(index) = SLOT_OCCUPIED;
+                std::ptr::copy_nonoverlapping(key, self.key(index), self.keys.buffer.ptr.size);
+                std::ptr::copy_nonoverlapping(
+                    value,
+                    self.value(index),
+                    self.values.buffer.ptr.size,
+                );
+            }
+        }
+        self.removed = 0;
+        errc |= nstd_vec_free(&mut old_states);
+        errc |= nstd_vec_free(&mut old_keys);
+        errc |= nstd_vec_free(&mut old_values);
+        errc
+    }
+}
+
+/// Creates a new hash map.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize key_size` - The size of each key in the map.
+///
+/// - `const NSTDUSize value_size` - The size of each value in the map.
+///
+/// - `NSTDUInt64 (*hash)(NSTDAnyConst, NSTDUSize)` - The function used to hash keys.
+///
+/// - `NSTDBool (*compare)(NSTDAnyConst, NSTDAnyConst, NSTDUSize)` - Checks keys for equality.
+///
+/// # Returns
+///
+/// `NSTDHashMap map` - The new hash map.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_new(
+    key_size: usize,
+    value_size: usize,
+    hash: unsafe extern "C" fn(NSTDAnyConst, usize) -> u64,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst, usize) -> NSTDBool,
+) -> NSTDHashMap {
+    NSTDHashMap {
+        size: 0,
+        removed: 0,
+        states: nstd_vec_new_with_capacity(1, 0),
+        keys: nstd_vec_new_with_capacity(key_size, 0),
+        values: nstd_vec_new_with_capacity(value_size, 0),
+        hash,
+        compare,
+    }
+}
+
+/// Inserts a key-value pair into a hash map, overwriting the value if the key already exists.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to copy into the map.
+///
+/// - `const NSTDAnyConst value` - A pointer to the value to copy into the map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_insert(
+    map: &mut NSTDHashMap,
+    key: NSTDAnyConst,
+    value: NSTDAnyConst,
+) -> NSTDErrorCode {
+    // Make sure there will still be an empty slot after this insertion.
+    if (map.size + map.removed + 1) * 4 > map.capacity() * 3 {
+        let capacity = match (map.size + 1) * 2 > map.capacity() {
+            true => (map.capacity() * 2).max(INITIAL_CAPACITY),
+            false => map.capacity(),
+        };
+        let errc = map.rehash(capacity);
+        if errc != 0 {
+            return errc;
+        }
+    }
+    // Write the entry.
+    let (found, index) = map.find_slot(key);
+    if !found {
+        if *map.state(index) == SLOT_REMOVED {
+            map.removed -= 1;
+        }
+        *map.state(index) = SLOT_OCCUPIED;
+        std::ptr::copy_nonoverlapping(key, map.key(index), map.keys.buffer.ptr.size);
+        map.size += 1;
+    }
+    std::ptr::copy_nonoverlapping(value, map.value(index), map.values.buffer.ptr.size);
+    0
+}
+
+/// Gets a pointer to the value associated with `key`.
+///
+/// # Note
+///
+/// The returned value pointer can quickly become a dangling pointer if the map's memory gets
+/// reallocated or deallocated, so it is advised to create a copy of the value after getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDHashMap *const map` - The hash map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key.
+///
+/// # Returns
+///
+/// `NSTDAny value` - Pointer to the value, null if `key` is not in the map.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_get(
+    map: &NSTDHashMap,
+    key: NSTDAnyConst,
+) -> NSTDAny {
+    if map.size > 0 {
+        if let (true, index) = map.find_slot(key) {
+            return map.value(index);
+        }
+    }
+    NSTD_CORE_NULL
+}
+
+/// Removes a key-value pair from a hash map.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key of the entry to remove.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `key` is not in the map.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_remove(
+    map: &mut NSTDHashMap,
+    key: NSTDAnyConst,
+) -> NSTDErrorCode {
+    if map.size > 0 {
+        if let (true, index) = map.find_slot(key) {
+            *map.state(index) = SLOT_REMOVED;
+            map.size -= 1;
+            map.removed += 1;
+            return 0;
+        }
+    }
+    1
+}
+
+/// Returns the number of entries in a hash map.
+///
+/// # Parameters
+///
+/// - `const NSTDHashMap *const map` - The hash map.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of entries in the map.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_len(map: &NSTDHashMap) -> usize {
+    map.size
+}
+
+/// Removes all entries from a hash map without freeing it's memory.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_clear(map: &mut NSTDHashMap) {
+    map.states.buffer.as_byte_slice_mut().fill(SLOT_EMPTY);
+    map.size = 0;
+    map.removed = 0;
+}
+
+/// Calls `callback` with each key-value pair in a hash map, in no particular order.
+///
+/// # Parameters
+///
+/// - `const NSTDHashMap *const map` - The hash map.
+///
+/// - `void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny)` - Called with each key, value and `data`.
+///
+/// - `NSTDAny data` - User data to pass to `callback`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_iterate(
+    map: &NSTDHashMap,
+    callback: unsafe extern "C" fn(NSTDAnyConst, NSTDAny, NSTDAny),
+    data: NSTDAny,
+) {
+    for i in 0..map.capacity() {
+        if *map.state(i) == SLOT_OCCUPIED {
+            callback(map.key(i), map.value(i), data);
+        }
+    }
+}
+
+/// Frees a hash map.
+///
+/// # Parameters
+///
+/// - `NSTDHashMap *const map` - The hash map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_free(map: &mut NSTDHashMap) -> NSTDErrorCode {
+    let mut errc = nstd_vec_free(&mut map.states);
+    errc |= nstd_vec_free(&mut map.keys);
+    errc |= nstd_vec_free(&mut map.values);
+    map.size = 0;
+    map.removed = 0;
+    errc
+}
+
+/// Hashes a key byte-by-byte using FNV-1a.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key` - A pointer to the key.
+///
+/// - `const NSTDUSize key_size` - The size of the key in bytes.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The key's hash.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_hash_bytes(
+    key: NSTDAnyConst,
+    key_size: usize,
+) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+    const FNV_PRIME: u64 = 0x100000001B3;
+    let bytes = std::slice::from_raw_parts(key.cast::<u8>(), key_size);
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Checks if two keys contain the same bytes.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key1` - A pointer to the first key.
+///
+/// - `const NSTDAnyConst key2` - A pointer to the second key.
+///
+/// - `const NSTDUSize key_size` - The size of each key in bytes.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - True if the two keys are equal.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_compare_bytes(
+    key1: NSTDAnyConst,
+    key2: NSTDAnyConst,
+    key_size: usize,
+) -> NSTDBool {
+    let key1 = std::slice::from_raw_parts(key1.cast::<u8>(), key_size);
+    let key2 = std::slice::from_raw_parts(key2.cast::<u8>(), key_size);
+    NSTDBool::from(key1 == key2)
+}
+
+/// Hashes an `NSTDStr` key by the string data it views.
+///
+/// # Note
+///
+/// Only the `NSTDStr` view is copied into the map, the string data it points to must outlive the
+/// map's entry.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key` - A pointer to the `NSTDStr` key.
+///
+/// - `const NSTDUSize key_size` - The size of the key, `sizeof(NSTDStr)`.
+///
+/// # Returns
+///
+/// `NSTDUInt64 hash` - The key's hash.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_hash_str(key: NSTDAnyConst, _: usize) -> u64 {
+    let str = &*key.cast::<NSTDStr>();
+    nstd_collections_hash_map_hash_bytes(str.bytes.ptr.raw, str.bytes.byte_count())
+}
+
+/// Checks if two `NSTDStr` keys view the same string data.
+///
+/// # Parameters
+///
+/// - `const NSTDAnyConst key1` - A pointer to the first `NSTDStr` key.
+///
+/// - `const NSTDAnyConst key2` - A pointer to the second `NSTDStr` key.
+///
+/// - `const NSTDUSize key_size` - The size of each key, `sizeof(NSTDStr)`.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - True if the two keys are equal.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_hash_map_compare_str(
+    key1: NSTDAnyConst,
+    key2: NSTDAnyConst,
+    _: usize,
+) -> NSTDBool {
+    let str1 = &*key1.cast::<NSTDStr>();
+    let str2 = &*key2.cast::<NSTDStr>();
+    crate::core::str::nstd_core_str_compare(str1, str2)
+}
@@ -0,0 +1,767 @@
+//! A sorted map of keys to values backed by a B-tree.
+use crate::core::{
+    def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+    NSTD_CORE_NULL,
+};
+use std::ptr::{addr_of_mut, null, null_mut};
+
+/// The minimum degree of the B-tree.
+const B: usize = 6;
+/// The maximum number of entries a node can hold.
+const MAX_ENTRIES: usize = 2 * B - 1;
+
+/// A node in an ordered map's B-tree.
+///
+/// Each node is a single allocation, `MAX_ENTRIES` keys and then `MAX_ENTRIES` values are stored
+/// directly after the node's header.
+#[repr(C)]
+struct NSTDOrderedMapNode {
+    /// The number of entries in this node.
+    len: usize,
+    /// The node's children, all null if this node is a leaf.
+    children: [*mut NSTDOrderedMapNode; MAX_ENTRIES + 1],
+}
+impl NSTDOrderedMapNode {
+    /// Checks if this node is a leaf.
+    #[inline]
+    fn is_leaf(&self) -> bool {
+        self.children[0].is_null()
+    }
+}
+
+/// A key-value pair in an ordered map.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NSTDOrderedMapEntry {
+    /// A pointer to the entry's key, null if there is no such entry.
+    pub key: NSTDAnyConst,
+    /// A pointer to the entry's value, null if there is no such entry.
+    pub value: NSTDAny,
+}
+impl Default for NSTDOrderedMapEntry {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            key: null(),
+            value: null_mut(),
+        }
+    }
+}
+
+/// A sorted map of keys to values backed by a B-tree.
+#[repr(C)]
+pub struct NSTDOrderedMap {
+    /// The number of entries in the map.
+    pub size: usize,
+    /// The size of each key in the map.
+    pub key_size: usize,
+    /// The size of each value in the map.
+    pub value_size: usize,
+    /// A pointer to the root node of the B-tree, null if nothing has been inserted yet.
+    pub root: NSTDAny,
+    /// Compares two keys.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst key1` - A pointer to the first key.
+    ///
+    /// - `NSTDAnyConst key2` - A pointer to the second key.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDInt32 ordering` - Negative if `key1` is less than `key2`, 0 if they are equal and
+    /// positive if `key1` is greater than `key2`.
+    pub compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+}
+/// Node access methods.
+impl NSTDOrderedMap {
+    /// Returns the number of bytes reserved for the keys in each node.
+    #[inline]
+    fn keys_byte_count(&self) -> usize {
+        const ALIGN: usize = 16;
+        (MAX_ENTRIES * self.key_size + ALIGN - 1) & !(ALIGN - 1)
+    }
+
+    /// Returns the number of bytes allocated for each node.
+    #[inline]
+    fn node_byte_count(&self) -> usize {
+        std::mem::size_of::<NSTDOrderedMapNode>()
+            + self.keys_byte_count()
+            + MAX_ENTRIES * self.value_size
+    }
+
+    /// Allocates a new node with no entries or children.
+    #[inline]
+    unsafe fn new_node(&self) -> *mut NSTDOrderedMapNode {
+        crate::alloc::nstd_alloc_allocate_zeroed(self.node_byte_count()).cast()
+    }
+
+    /// Deallocates a node, without freeing it's children.
+    #[inline]
+    unsafe fn free_node(&self, node: *mut NSTDOrderedMapNode) -> NSTDErrorCode {
+        let mut node = node.cast();
+        crate::alloc::nstd_alloc_deallocate(&mut node, self.node_byte_count())
+    }
+
+    /// Deallocates a node along with all of it's children.
+    unsafe fn free_tree(&self, node: *mut NSTDOrderedMapNode) -> NSTDErrorCode {
+        let mut errc = 0;
+        if !(*node).is_leaf() {
+            for i in 0..=(*node).len {
+                errc |= self.free_tree((*node).children[i]);
+            }
+        }
+        errc | self.free_node(node)
+    }
+
+    /// Returns a pointer to the key at `index` in `node`.
+    #[inline]
+    unsafe fn key(&self, node: *mut NSTDOrderedMapNode, index: usize) -> NSTDAny {
+        node.add(1).cast::<u8>().add(index * self.key_size).cast()
+    }
+
+    /// Returns a pointer to the value at `index` in `node`.
+    #[inline]
+    unsafe fn value(&self, node: *mut NSTDOrderedMapNode, index: usize) -> NSTDAny {
+        let values = node.add(1).cast::<u8>().add(self.keys_byte_count());
+        values.add(index * self.value_size).cast()
+    }
+
+    /// Returns the entry at `index` in `node`.
+    #[inline]
+    unsafe fn entry(&self, node: *mut NSTDOrderedMapNode, index: usize) -> NSTDOrderedMapEntry {
+        NSTDOrderedMapEntry {
+            key: self.key(node, index),
+            value: self.value(node, index),
+        }
+    }
+
+    /// Copies the entry at `src_index` in `src` to `dst_index` in `dst`.
+    #[inline]
+    unsafe fn copy_entry(
+        &self,
+        dst: *mut NSTDOrderedMapNode,
+        dst_index: usize,
+        src: *mut NSTDOrderedMapNode,
+        src_index: usize,
+    ) {
+        let (key, value) = (self.key(src, src_index), self.value(src, src_index));
+        std::ptr::copy(key, self.key(dst, dst_index), self.key_size);
+        std::ptr::copy(value, self.value(dst, dst_index), self.value_size);
+    }
+
+    /// Moves `count` entries in `node` from `from` to `to`.
+    #[inline]
+    unsafe fn shift_entries(
+        &self,
+        node: *mut NSTDOrderedMapNode,
+        from: usize,
+        to: usize,
+        count: usize,
+    ) {
+        std::ptr::copy(
+            self.key(node, from),
+            self.key(node, to),
+            count * self.key_size,
+        );
+        std::ptr::copy(
+            self.value(node, from),
+            self.value(node, to),
+            count * self.value_size,
+        );
+    }
+
+    /// Moves `count` children in `node` from `from` to `to`.
+    #[inline]
+    unsafe fn shift_children(node: *mut NSTDOrderedMapNode, from: usize, to: usize, count: usize) {
+        let children = addr_of_mut!((*node).children).cast::<*mut NSTDOrderedMapNode>();
+        std::ptr::copy(children.add(from), children.add(to), count);
+    }
+
+    /// Finds the first index in `node` whose key is not less than `key`, also returning whether
+    /// the key at that index is equal to `key`.
+    unsafe fn search(&self, node: *mut NSTDOrderedMapNode, key: NSTDAnyConst) -> (usize, bool) {
+        let (mut low, mut high) = (0, (*node).len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match (self.compare)(self.key(node, mid), key) {
+                ordering if ordering < 0 => low = mid + 1,
+                0 => return (mid, true),
+                _ => high = mid,
+            }
+        }
+        (low, false)
+    }
+}
+/// B-tree algorithms.
+impl NSTDOrderedMap {
+    /// Splits the full child at `index` of `parent`, moving it's median entry into `parent`.
+    unsafe fn split_child(&self, parent: *mut NSTDOrderedMapNode, index: usize) -> NSTDErrorCode {
+        let left = (*parent).children[index];
+        let right = self.new_node();
+        if right.is_null() {
+            return 1;
+        }
+        // Move the upper half of `left` into `right`.
+        for i in 0..B - 1 {
+            self.copy_entry(right, i, left, i + B);
+        }
+        if !(*left).is_leaf() {
+            for i in 0..B {
+                (*right).children[i] = (*left).children[i + B];
+                (*left).children[i + B] = null_mut();
+            }
+        }
+        (*right).len = B - 1;
+        (*left).len = B - 1;
+        // Make room in `parent` for the median entry and `right`.
+        let len = (*parent).len;
+        self.shift_entries(parent, index, index + 1, len - index);
+        Self::shift_children(parent, index + 1, index + 2, len - index);
+        self.copy_entry(parent, index, left, B - 1);
+        (*parent).children[index + 1] = right;
+        (*parent).len += 1;
+        0
+    }
+
+    /// Merges the child at `index + 1` of `parent` and the entry at `index` into the child at
+    /// `index`.
+    unsafe fn merge_children(
+        &self,
+        parent: *mut NSTDOrderedMapNode,
+        index: usize,
+    ) -> NSTDErrorCode {
+        let left = (*parent).children[index];
+        let right = (*parent).children[index + 1];
+        let left_len = (*left).len;
+        let right_len = (*right).len;
+        self.copy_entry(left, left_len, parent, index);
+        for i in 0..right_len {
+            self.copy_entry(left, left_len + 1 + i, right, i);
+        }
+        if !(*left).is_leaf() {
+            for i in 0..=right_len {
+                (*left).children[left_len + 1 + i] = (*right).children[i];
+            }
+        }
+        (*left).len += right_len + 1;
+        // Close the gap in `parent`.
+        let len = (*parent).len;
+        self.shift_entries(parent, index + 1, index, len - index - 1);
+        Self::shift_children(parent, index + 2, index + 1, len - index - 1);
+        (*parent).children[len] = null_mut();
+        (*parent).len -= 1;
+        self.free_node(right)
+    }
+
+    /// Moves an entry from the child at `index - 1` of `parent`, through `parent`, into the child at
+    /// `index`.
+    unsafe fn rotate_right(&self, parent: *mut NSTDOrderedMapNode, index: usize) {
+        let left = (*parent).children[index - 1];
+        let child = (*parent).children[index];
+        let left_len = (*left).len;
+        self.shift_entries(child, 0, 1, (*child).len);
+        self.copy_entry(child, 0, parent, index - 1);
+        self.copy_entry(parent, index - 1, left, left_len - 1);
+        if !(*child).is_leaf() {
+            Self::shift_children(child, 0, 1, (*child).len + 1);
+            (*child).children[0] = (*left).children[left_len];
+            (*left).children[left_len] = null_mut();
+        }
+        (*left).len -= 1;
+        (*child).len += 1;
+    }
+
+    /// Moves an entry from the child at `index + 1` of `parent`, through `parent`, into the child at
+    /// `index`.
+    unsafe fn rotate_left(&self, parent: *mut NSTDOrderedMapNode, index: usize) {
+        let child = (*parent).children[index];
+        let right = (*parent).children[index + 1];
+        let child_len = (*child).len;
+        let right_len = (*right).len;
+        self.copy_entry(child, child_len, parent, index);
+        self.copy_entry(parent, index, right, 0);
+        self.shift_entries(right, 1, 0, right_len - 1);
+        if !(*child).is_leaf() {
+            (*child).children[child_len + 1] = (*right).children[0];
+            Self::shift_children(right, 1, 0, right_len);
+            (*right).children[right_len] = null_mut();
+        }
+        (*child).len += 1;
+        (*right).len -= 1;
+    }
+
+    /// Makes sure the child at `index` of `parent` has more than the minimum number of entries,
+    /// returning the index of the child that should be descended into.
+    unsafe fn fill_child(
+        &self,
+        parent: *mut NSTDOrderedMapNode,
+        index: usize,
+        errc: &mut NSTDErrorCode,
+    ) -> usize {
+        if (*(*parent).children[index]).len >= B {
+            index
+        } else if index > 0 && (*(*parent).children[index - 1]).len >= B {
+            self.rotate_right(parent, index);
+            index
+        } else if index < (*parent).len && (*(*parent).children[index + 1]).len >= B {
+            self.rotate_left(parent, index);
+            index
+        } else if index < (*parent).len {
+            *errc |= self.merge_children(parent, index);
+            index
+        } else {
+            *errc |= self.merge_children(parent, index - 1);
+            index - 1
+        }
+    }
+
+    /// Removes `key` from the subtree rooted at `node`, returning whether it was found.
+    unsafe fn remove_from(
+        &self,
+        mut node: *mut NSTDOrderedMapNode,
+        key: NSTDAnyConst,
+        errc: &mut NSTDErrorCode,
+    ) -> bool {
+        loop {
+            let (index, found) = self.search(node, key);
+            if (*node).is_leaf() {
+                if found {
+                    self.shift_entries(node, index + 1, index, (*node).len - index - 1);
+                    (*node).len -= 1;
+                }
+                return found;
+            }
+            if !found {
+                node = (*node).children[self.fill_child(node, index, errc)];
+                continue;
+            }
+            // The key is in an internal node, replace it with it's predecessor or successor.
+            let left = (*node).children[index];
+            let right = (*node).children[index + 1];
+            if (*left).len >= B {
+                let mut pred = left;
+                while !(*pred).is_leaf() {
+                    pred = (*pred).children[(*pred).len];
+                }
+                self.copy_entry(node, index, pred, (*pred).len - 1);
+                return self.remove_from(left, self.key(node, index), errc);
+            } else if (*right).len >= B {
+                let mut succ = right;
+                while !(*succ).is_leaf() {
+                    succ = (*succ).children[0];
+                }
+                self.copy_entry(node, index, succ, 0);
+                return self.remove_from(right, self.key(node, index), errc);
+            }
+            *errc |= self.merge_children(node, index);
+            node = left;
+        }
+    }
+
+    /// Calls `callback` with each entry in the subtree rooted at `node` whose key is within
+    /// `[start, end)`, returning false once an entry at or beyond `end` is reached.
+    unsafe fn visit(
+        &self,
+        node: *mut NSTDOrderedMapNode,
+        start: NSTDAnyConst,
+        end: NSTDAnyConst,
+        callback: unsafe extern "C" fn(NSTDAnyConst, NSTDAny, NSTDAny),
+        data: NSTDAny,
+    ) -> bool {
+        let first = match start.is_null() {
+            true => 0,
+            false => self.search(node, start).0,
+        };
+        for i in first..=(*node).len {
+            if !(*node).is_leaf() && !self.visit((*node).children[i], start, end, callback, data) {
+                return false;
+            }
+            if i < (*node).len {
+                let key = self.key(node, i);
+                if !end.is_null() && (self.compare)(key, end) >= 0 {
+                    return false;
+                }
+                callback(key, self.value(node, i), data);
+            }
+        }
+        true
+    }
+
+    /// Finds the first entry whose key is greater than `key`, or not less than `key` if
+    /// `inclusive` is true.
+    unsafe fn bound(&self, key: NSTDAnyConst, inclusive: bool) -> NSTDOrderedMapEntry {
+        let mut bound = NSTDOrderedMapEntry::default();
+        let mut node: *mut NSTDOrderedMapNode = self.root.cast();
+        while !node.is_null() {
+            let (mut index, found) = self.search(node, key);
+            if found {
+                match inclusive {
+                    true => return self.entry(node, index),
+                    false => index += 1,
+                }
+            }
+            if index < (*node).len {
+                bound = self.entry(node, index);
+            }
+            node = (*node).children[index];
+        }
+        bound
+    }
+}
+
+/// Creates a new ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize key_size` - The size of each key in the map.
+///
+/// - `const NSTDUSize value_size` - The size of each value in the map.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - The function used to order keys.
+///
+/// # Returns
+///
+/// `NSTDOrderedMap map` - The new ordered map.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_new(
+    key_size: usize,
+    value_size: usize,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) -> NSTDOrderedMap {
+    NSTDOrderedMap {
+        size: 0,
+        key_size,
+        value_size,
+        root: NSTD_CORE_NULL,
+        compare,
+    }
+}
+
+/// Inserts a key-value pair into an ordered map, overwriting the value if the key already exists.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to copy into the map.
+///
+/// - `const NSTDAnyConst value` - A pointer to the value to copy into the map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_insert(
+    map: &mut NSTDOrderedMap,
+    key: NSTDAnyConst,
+    value: NSTDAnyConst,
+) -> NSTDErrorCode {
+    // Create the root or grow the tree upwards if the root is full.
+    if map.root.is_null() {
+        map.root = map.new_node().cast();
+        if map.root.is_null() {
+            return 1;
+        }
+    }
+    let mut node: *mut NSTDOrderedMapNode = map.root.cast();
+    if (*node).len == MAX_ENTRIES {
+        let root = map.new_node();
+        if root.is_null() {
+            return 1;
+        }
+        (*root).children[0] = node;
+        let errc = map.split_child(root, 0);
+        if errc != 0 {
+            map.free_node(root);
+            return errc;
+        }
+        map.root = root.cast();
+        node = root;
+    }
+    // Descend to the leaf the key belongs in, splitting full nodes along the way.
+    loop {
+        let (mut index, found) = map.search(node, key);
+        if found {
+            std::ptr::copy(value, map.value(node, index), map.value_size);
+            return 0;
+        }
+        if (*node).is_leaf() {
+            map.shift_entries(node, index, index + 1, (*node).len - index);
+            std::ptr::copy(key, map.key(node, index), map.key_size);
+            std::ptr::copy(value, map.value(node, index), map.value_size);
+            (*node).len += 1;
+            map.size += 1;
+            return 0;
+        }
+        if (*(*node).children[index]).len == MAX_ENTRIES {
+            let errc = map.split_child(node, index);
+            if errc != 0 {
+                return errc;
+            }
+            match (map.compare)(key, map.key(node, index)) {
+                0 => {
+                    std::ptr::copy(value, map.value(node, index), map.value_size);
+                    return 0;
+                }
+                ordering if ordering > 0 => index += 1,
+                _ => (),
+            }
+        }
+        node = (*node).children[index];
+    }
+}
+
+/// Gets a pointer to the value associated with `key`.
+///
+/// # Note
+///
+/// The returned value pointer can quickly become a dangling pointer if the map is modified, so it
+/// is advised to create a copy of the value after getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key.
+///
+/// # Returns
+///
+/// `NSTDAny value` - Pointer to the value, null if `key` is not in the map.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_get(
+    map: &NSTDOrderedMap,
+    key: NSTDAnyConst,
+) -> NSTDAny {
+    let mut node: *mut NSTDOrderedMapNode = map.root.cast();
+    while !node.is_null() {
+        match map.search(node, key) {
+            (index, true) => return map.value(node, index),
+            (index, false) => node = (*node).children[index],
+        }
+    }
+    NSTD_CORE_NULL
+}
+
+/// Removes a key-value pair from an ordered map.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key of the entry to remove.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `key` is not in the map or on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_remove(
+    map: &mut NSTDOrderedMap,
+    key: NSTDAnyConst,
+) -> NSTDErrorCode {
+    let root: *mut NSTDOrderedMapNode = map.root.cast();
+    if root.is_null() {
+        return 1;
+    }
+    let mut errc = 0;
+    let found = map.remove_from(root, key, &mut errc);
+    // Shrink the tree if the root has been emptied.
+    if (*root).len == 0 {
+        map.root = (*root).children[0].cast();
+        errc |= map.free_node(root);
+    }
+    match found {
+        true => {
+            map.size -= 1;
+            errc
+        }
+        false => 1,
+    }
+}
+
+/// Returns the number of entries in an ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of entries in the map.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_len(map: &NSTDOrderedMap) -> usize {
+    map.size
+}
+
+/// Returns the entry with the smallest key in an ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry first` - The first entry, null pointers if the map is empty.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_first(
+    map: &NSTDOrderedMap,
+) -> NSTDOrderedMapEntry {
+    let mut node: *mut NSTDOrderedMapNode = map.root.cast();
+    if node.is_null() || (*node).len == 0 {
+        return NSTDOrderedMapEntry::default();
+    }
+    while !(*node).is_leaf() {
+        node = (*node).children[0];
+    }
+    map.entry(node, 0)
+}
+
+/// Returns the entry with the largest key in an ordered map.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry last` - The last entry, null pointers if the map is empty.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_last(
+    map: &NSTDOrderedMap,
+) -> NSTDOrderedMapEntry {
+    let mut node: *mut NSTDOrderedMapNode = map.root.cast();
+    if node.is_null() || (*node).len == 0 {
+        return NSTDOrderedMapEntry::default();
+    }
+    while !(*node).is_leaf() {
+        node = (*node).children[(*node).len];
+    }
+    map.entry(node, (*node).len - 1)
+}
+
+/// Returns the first entry whose key is not less than `key`.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry entry` - The lower bound entry, null pointers if there is none.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_lower_bound(
+    map: &NSTDOrderedMap,
+    key: NSTDAnyConst,
+) -> NSTDOrderedMapEntry {
+    map.bound(key, true)
+}
+
+/// Returns the first entry whose key is greater than `key`.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst key` - A pointer to the key to search for.
+///
+/// # Returns
+///
+/// `NSTDOrderedMapEntry entry` - The upper bound entry, null pointers if there is none.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_upper_bound(
+    map: &NSTDOrderedMap,
+    key: NSTDAnyConst,
+) -> NSTDOrderedMapEntry {
+    map.bound(key, false)
+}
+
+/// Calls `callback` with each key-value pair in an ordered map, in ascending key order.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny)` - Called with each key, value and `data`.
+///
+/// - `NSTDAny data` - User data to pass to `callback`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_iterate(
+    map: &NSTDOrderedMap,
+    callback: unsafe extern "C" fn(NSTDAnyConst, NSTDAny, NSTDAny),
+    data: NSTDAny,
+) {
+    nstd_collections_ordered_map_iterate_range(map, null(), null(), callback, data);
+}
+
+/// Calls `callback` with each key-value pair in an ordered map whose key is within the range
+/// `[start, end)`, in ascending key order.
+///
+/// # Parameters
+///
+/// - `const NSTDOrderedMap *const map` - The ordered map.
+///
+/// - `const NSTDAnyConst start` - The start of the key range (included), null for no lower bound.
+///
+/// - `const NSTDAnyConst end` - The end of the key range (excluded), null for no upper bound.
+///
+/// - `void (*callback)(NSTDAnyConst, NSTDAny, NSTDAny)` - Called with each key, value and `data`.
+///
+/// - `NSTDAny data` - User data to pass to `callback`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_iterate_range(
+    map: &NSTDOrderedMap,
+    start: NSTDAnyConst,
+    end: NSTDAnyConst,
+    callback: unsafe extern "C" fn(NSTDAnyConst, NSTDAny, NSTDAny),
+    data: NSTDAny,
+) {
+    if !map.root.is_null() {
+        map.visit(map.root.cast(), start, end, callback, data);
+    }
+}
+
+/// Removes all entries from an ordered map, freeing it's nodes.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_clear(
+    map: &mut NSTDOrderedMap,
+) -> NSTDErrorCode {
+    nstd_collections_ordered_map_free(map)
+}
+
+/// Frees an ordered map.
+///
+/// # Parameters
+///
+/// - `NSTDOrderedMap *const map` - The ordered map.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_ordered_map_free(
+    map: &mut NSTDOrderedMap,
+) -> NSTDErrorCode {
+    let mut errc = 0;
+    if !map.root.is_null() {
+        errc = map.free_tree(map.root.cast());
+        map.root = NSTD_CORE_NULL;
+    }
+    map.size = 0;
+    errc
+}
@@ -0,0 +1,409 @@
+//! A priority queue backed by a binary heap.
+use crate::{
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+        slice::NSTDSlice,
+        NSTD_CORE_NULL,
+    },
+    vec::*,
+};
+use std::ptr::addr_of;
+
+/// The position of a handle that is not currently in use.
+const NO_POSITION: usize = usize::MAX;
+/// The size of a handle or position.
+const USIZE_SIZE: usize = std::mem::size_of::<usize>();
+
+/// A priority queue backed by a binary heap.
+///
+/// The element that compares the least is kept at the front of the queue.
+#[repr(C)]
+pub struct NSTDPriorityQueue {
+    /// The heap of elements.
+    pub buffer: NSTDVec,
+    /// The handle of each element in `buffer`.
+    pub handles: NSTDVec,
+    /// The position in `buffer` of each handle, -1/usize::MAX if the handle is not in use.
+    pub positions: NSTDVec,
+    /// Handles that have been released and can be reused.
+    pub free_handles: NSTDVec,
+    /// Compares two elements.
+    ///
+    /// # Parameters
+    ///
+    /// - `NSTDAnyConst element1` - A pointer to the first element.
+    ///
+    /// - `NSTDAnyConst element2` - A pointer to the second element.
+    ///
+    /// # Returns
+    ///
+    /// `NSTDInt32 ordering` - Negative if `element1` is less than `element2`, 0 if they are equal
+    /// and positive if `element1` is greater than `element2`.
+    pub compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+}
+impl NSTDPriorityQueue {
+    /// Returns the handle of the element at `index`.
+    #[inline]
+    unsafe fn handle(&self, index: usize) -> usize {
+        *nstd_vec_get(&self.handles, index).cast()
+    }
+
+    /// Returns the position of `handle`, -1/usize::MAX if it is invalid.
+    #[inline]
+    unsafe fn position(&self, handle: usize) -> usize {
+        match nstd_vec_get(&self.positions, handle) {
+            position if position.is_null() => NO_POSITION,
+            position => *position.cast(),
+        }
+    }
+
+    /// Sets the position of `handle`.
+    #[inline]
+    unsafe fn set_position(&mut self, handle: usize, position: usize) {
+        *nstd_vec_get(&self.positions, handle).cast() = position;
+    }
+
+    /// Checks if the element at `i` should be closer to the front than the element at `j`.
+    #[inline]
+    unsafe fn less(&self, i: usize, j: usize) -> bool {
+        let element1 = nstd_vec_get(&self.buffer, i);
+        let element2 = nstd_vec_get(&self.buffer, j);
+        (self.compare)(element1, element2) < 0
+    }
+
+    /// Swaps the elements at `i` and `j`, keeping their handles up to date.
+    unsafe fn swap(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        crate::core::slice::nstd_core_slice_swap(&mut self.buffer.buffer, i, j);
+        crate::core::slice::nstd_core_slice_swap(&mut self.handles.buffer, i, j);
+        self.set_position(self.handle(i), i);
+        self.set_position(self.handle(j), j);
+    }
+
+    /// Moves the element at `index` towards the front until the heap is ordered.
+    unsafe fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.less(index, parent) {
+                break;
+            }
+            self.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the element at `index` towards the back until the heap is ordered.
+    unsafe fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let (left, right) = (index * 2 + 1, index * 2 + 2);
+            let mut least = index;
+            if left < self.buffer.size && self.less(left, least) {
+                least = left;
+            }
+            if right < self.buffer.size && self.less(right, least) {
+                least = right;
+            }
+            if least == index {
+                break;
+            }
+            self.swap(index, least);
+            index = least;
+        }
+    }
+
+    /// Removes the element at `index`, returning a pointer to it.
+    unsafe fn remove_at(&mut self, index: usize) -> (NSTDAny, NSTDErrorCode) {
+        let handle = self.handle(index);
+        let last = self.buffer.size - 1;
+        self.swap(index, last);
+        let element = nstd_vec_pop(&mut self.buffer);
+        nstd_vec_pop(&mut self.handles);
+        self.set_position(handle, NO_POSITION);
+        let errc = nstd_vec_push(&mut self.free_handles, addr_of!(handle).cast());
+        if index < last {
+            self.sift_down(index);
+            self.sift_up(index);
+        }
+        (element, errc)
+    }
+}
+
+/// Creates a new priority queue.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the queue.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - The function used to order elements.
+///
+/// # Returns
+///
+/// `NSTDPriorityQueue queue` - The new priority queue.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_new(
+    element_size: usize,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) -> NSTDPriorityQueue {
+    NSTDPriorityQueue {
+        buffer: nstd_vec_new(element_size),
+        handles: nstd_vec_new(USIZE_SIZE),
+        positions: nstd_vec_new(USIZE_SIZE),
+        free_handles: nstd_vec_new(USIZE_SIZE),
+        compare,
+    }
+}
+
+/// Creates a new priority queue from the elements in a slice.
+///
+/// # Note
+///
+/// The handle of each element is it's index in `slice`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The elements to build the queue out of.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - The function used to order elements.
+///
+/// # Returns
+///
+/// `NSTDPriorityQueue queue` - The new priority queue.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_from_slice(
+    slice: &NSTDSlice,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) -> NSTDPriorityQueue {
+    let capacity = slice.size.max(1);
+    let mut queue = NSTDPriorityQueue {
+        buffer: nstd_vec_new_with_capacity(slice.ptr.size, capacity),
+        handles: nstd_vec_new_with_capacity(USIZE_SIZE, capacity),
+        positions: nstd_vec_new_with_capacity(USIZE_SIZE, capacity),
+        free_handles: nstd_vec_new(USIZE_SIZE),
+        compare,
+    };
+    nstd_vec_extend(&mut queue.buffer, slice);
+    for i in 0..queue.buffer.size {
+        nstd_vec_push(&mut queue.handles, addr_of!(i).cast());
+        nstd_vec_push(&mut queue.positions, addr_of!(i).cast());
+    }
+    // Order the heap from the bottom up.
+    for i in (0..queue.buffer.size / 2).rev() {
+        queue.sift_down(i);
+    }
+    queue
+}
+
+/// Pushes an element onto a priority queue.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDAnyConst element` - A pointer to the element to push onto the queue.
+///
+/// - `NSTDUSize *const handle` - Returns as the element's handle.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_push(
+    queue: &mut NSTDPriorityQueue,
+    element: NSTDAnyConst,
+    handle: &mut usize,
+) -> NSTDErrorCode {
+    // Push the element and make room for it's handle.
+    let no_position = NO_POSITION;
+    let index = queue.buffer.size;
+    let mut errc = nstd_vec_push(&mut queue.buffer, element);
+    if errc != 0 {
+        return errc;
+    }
+    errc = nstd_vec_push(&mut queue.handles, addr_of!(no_position).cast());
+    if errc != 0 {
+        nstd_vec_pop(&mut queue.buffer);
+        return errc;
+    }
+    // Reuse a released handle or create a new one.
+    *handle = match nstd_vec_pop(&mut queue.free_handles) {
+        free_handle if !free_handle.is_null() => *free_handle.cast(),
+        _ => {
+            errc = nstd_vec_push(&mut queue.positions, addr_of!(no_position).cast());
+            if errc != 0 {
+                nstd_vec_pop(&mut queue.buffer);
+                nstd_vec_pop(&mut queue.handles);
+                return errc;
+            }
+            queue.positions.size - 1
+        }
+    };
+    *nstd_vec_get(&queue.handles, index).cast() = *handle;
+    queue.set_position(*handle, index);
+    queue.sift_up(index);
+    0
+}
+
+/// Returns a pointer to the element at the front of a priority queue.
+///
+/// # Note
+///
+/// The returned element pointer can quickly become a dangling pointer if the queue is modified,
+/// so it is advised to create a copy of the element after getting it.
+///
+/// # Parameters
+///
+/// - `const NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDAny front` - The element at the front of the queue, null if the queue is empty.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_peek(
+    queue: &NSTDPriorityQueue,
+) -> NSTDAny {
+    nstd_vec_first(&queue.buffer)
+}
+
+/// Removes the element at the front of a priority queue and returns a pointer to it.
+///
+/// # Note
+///
+/// The returned element pointer is only valid until the next element is pushed onto the queue.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDAny front` - The element that was removed, null if the queue is empty.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_pop(
+    queue: &mut NSTDPriorityQueue,
+) -> NSTDAny {
+    match queue.buffer.size > 0 {
+        true => queue.remove_at(0).0,
+        false => NSTD_CORE_NULL,
+    }
+}
+
+/// Returns a pointer to the element referred to by `handle`.
+///
+/// # Note
+///
+/// This function follows the same behaviour rules as `nstd_collections_priority_queue_peek`.
+///
+/// # Parameters
+///
+/// - `const NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDUSize handle` - The element's handle.
+///
+/// # Returns
+///
+/// `NSTDAny element` - The element, null if `handle` does not refer to an element in the queue.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_get(
+    queue: &NSTDPriorityQueue,
+    handle: usize,
+) -> NSTDAny {
+    nstd_vec_get(&queue.buffer, queue.position(handle))
+}
+
+/// Replaces the element referred to by `handle`, moving it to it's new place in the queue.
+///
+/// # Note
+///
+/// This can be used to both decrease and increase an element's priority.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDUSize handle` - The element's handle.
+///
+/// - `const NSTDAnyConst element` - A pointer to the new element.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `handle` does not refer to an element in the queue.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_update(
+    queue: &mut NSTDPriorityQueue,
+    handle: usize,
+    element: NSTDAnyConst,
+) -> NSTDErrorCode {
+    let index = queue.position(handle);
+    let slot = nstd_vec_get(&queue.buffer, index);
+    if slot.is_null() {
+        return 1;
+    }
+    std::ptr::copy(element, slot, queue.buffer.buffer.ptr.size);
+    queue.sift_down(index);
+    queue.sift_up(index);
+    0
+}
+
+/// Removes the element referred to by `handle` from a priority queue, releasing the handle.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// - `const NSTDUSize handle` - The element's handle.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `handle` does not refer to an element in the queue or on
+/// error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_remove(
+    queue: &mut NSTDPriorityQueue,
+    handle: usize,
+) -> NSTDErrorCode {
+    match queue.position(handle) {
+        NO_POSITION => 1,
+        index => queue.remove_at(index).1,
+    }
+}
+
+/// Returns the number of elements in a priority queue.
+///
+/// # Parameters
+///
+/// - `const NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of elements in the queue.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_len(queue: &NSTDPriorityQueue) -> usize {
+    queue.buffer.size
+}
+
+/// Frees a priority queue.
+///
+/// # Parameters
+///
+/// - `NSTDPriorityQueue *const queue` - The priority queue.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_priority_queue_free(
+    queue: &mut NSTDPriorityQueue,
+) -> NSTDErrorCode {
+    let mut errc = nstd_vec_free(&mut queue.buffer);
+    errc |= nstd_vec_free(&mut queue.handles);
+    errc |= nstd_vec_free(&mut queue.positions);
+    errc |= nstd_vec_free(&mut queue.free_handles);
+    errc
+}
@@ -15,8 +15,12 @@ use std::ptr::addr_of_mut;
 pub struct NSTDRCState {
     /// The number of reference counters that own `data`.
     pub count: usize,
+    /// The number of weak references to this state.
+    pub weak_count: usize,
     /// The shared data.
     pub data: NSTDHeap,
+    /// An optional function that is called with a pointer to the shared data before it is freed.
+    pub drop: Option<unsafe extern "C" fn(NSTDAny)>,
 }
 
 /// A reference counter. A heap allocated type that can be shared across multiple objects, once the
@@ -28,6 +32,14 @@ pub struct NSTDRC {
     pub state: NSTDHeap,
 }
 
+/// A weak reference to the data owned by an `NSTDRC`. Weak references do not keep the data alive.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NSTDRCWeak {
+    /// The reference counter state. Only free once `state.count` and `state.weak_count` == 0.
+    pub state: NSTDHeap,
+}
+
 /// Creates a new reference counter.
 ///
 /// # Parameters
@@ -42,7 +54,9 @@ pub unsafe extern "C" fn nstd_collections_rc_new(ptr: &NSTDPointer) -> NSTDRC {
     // Create a new RC state.
     let mut state = NSTDRCState {
         count: 1,
+        weak_count: 0,
         data: crate::alloc::heap::nstd_alloc_heap_new(ptr),
+        drop: None,
     };
     // Construct a reference counter with a new state.
     NSTDRC {
@@ -92,6 +106,23 @@ pub unsafe extern "C" fn nstd_collections_rc_get(rc: &NSTDRC) -> NSTDAny {
     (*state).data.ptr.raw
 }
 
+/// Sets the function that is called with a pointer to the shared data before it is freed.
+///
+/// # Parameters
+///
+/// - `const NSTDRC *const rc` - A pointer to the reference counter.
+///
+/// - `void (*drop)(NSTDAny)` - The drop callback, null to disable dropping the data.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_rc_set_drop(
+    rc: &NSTDRC,
+    drop: Option<unsafe extern "C" fn(NSTDAny)>,
+) {
+    let state = rc.state.ptr.raw as *mut NSTDRCState;
+    (*state).drop = drop;
+}
+
 /// Frees a reference counter, only frees the underlying data once all other reference counters have
 /// been freed as well.
 ///
@@ -110,8 +141,81 @@ pub unsafe extern "C" fn nstd_collections_rc_free(rc: &mut NSTDRC) -> NSTDErrorC
     state.count -= 1;
     // If the count is 0, free the referenced memory.
     if state.count == 0 {
+        if let Some(drop) = state.drop {
+            drop(state.data.ptr.raw);
+        }
         errc |= crate::alloc::heap::nstd_alloc_heap_free(&mut state.data);
-        errc |= crate::alloc::heap::nstd_alloc_heap_free(&mut rc.state);
+        // The state is still needed while there are weak references to it.
+        if state.weak_count == 0 {
+            errc |= crate::alloc::heap::nstd_alloc_heap_free(&mut rc.state);
+        }
     }
     errc
 }
+
+/// Creates a weak reference to a reference counter's data.
+///
+/// # Parameters
+///
+/// - `const NSTDRC *const rc` - A pointer to the reference counter.
+///
+/// # Returns
+///
+/// `NSTDRCWeak weak` - The new weak reference.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_rc_downgrade(rc: &NSTDRC) -> NSTDRCWeak {
+    let state = rc.state.ptr.raw as *mut NSTDRCState;
+    (*state).weak_count += 1;
+    NSTDRCWeak {
+        state: crate::alloc::heap::nstd_alloc_heap_from_existing(&rc.state.ptr),
+    }
+}
+
+/// Attempts to create a new reference counter from a weak reference.
+///
+/// # Parameters
+///
+/// - `const NSTDRCWeak *const weak` - A pointer to the weak reference.
+///
+/// - `NSTDRC *const rc` - Returns as the new reference counter on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the data has already been freed.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_rc_weak_upgrade(
+    weak: &NSTDRCWeak,
+    rc: &mut NSTDRC,
+) -> NSTDErrorCode {
+    let state = weak.state.ptr.raw as *mut NSTDRCState;
+    if (*state).count == 0 {
+        return 1;
+    }
+    (*state).count += 1;
+    *rc = NSTDRC {
+        state: crate::alloc::heap::nstd_alloc_heap_from_existing(&weak.state.ptr),
+    };
+    0
+}
+
+/// Frees a weak reference, the reference counter state is freed once there are no references to it
+/// left.
+///
+/// # Parameters
+///
+/// - `NSTDRCWeak *const weak` - The weak reference to free.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_rc_weak_free(weak: &mut NSTDRCWeak) -> NSTDErrorCode {
+    let state = &mut *(weak.state.ptr.raw as *mut NSTDRCState);
+    state.weak_count -= 1;
+    if state.weak_count == 0 && state.count == 0 {
+        return crate::alloc::heap::nstd_alloc_heap_free(&mut weak.state);
+    }
+    0
+}
@@ -1,5 +1,6 @@
 //! A stack collection type.
 use crate::{
+    alloc::allocator::NSTDAllocator,
     core::def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
     vec::*,
 };
@@ -28,6 +29,50 @@ pub unsafe extern "C" fn nstd_collections_stack_new(element_size: usize) -> NSTD
     }
 }
 
+/// Creates a new stack that allocates it's memory with `allocator`.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element on the stack.
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDStack stack` - The new stack.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_stack_new_in(
+    element_size: usize,
+    allocator: *mut NSTDAllocator,
+) -> NSTDStack {
+    NSTDStack {
+        buffer: nstd_vec_new_in(element_size, allocator),
+    }
+}
+
+/// Sets the function that is called with a pointer to each element that is dropped by the stack.
+///
+/// # Note
+///
+/// Elements are dropped when the stack is freed or cleared, and by
+/// `nstd_collections_stack_pop_discard`. Elements returned by `nstd_collections_stack_pop` are not
+/// dropped.
+///
+/// # Parameters
+///
+/// - `NSTDStack *const stack` - Pointer to the stack.
+///
+/// - `void (*drop)(NSTDAny)` - The drop callback, null to disable dropping elements.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_stack_set_drop(
+    stack: &mut NSTDStack,
+    drop: Option<unsafe extern "C" fn(NSTDAny)>,
+) {
+    nstd_vec_set_drop(&mut stack.buffer, drop);
+}
+
 /// Gets a pointer to the value at the top of the stack.
 ///
 /// # Note
@@ -86,6 +131,23 @@ pub unsafe extern "C" fn nstd_collections_stack_pop(stack: &mut NSTDStack) -> NS
     nstd_vec_pop(&mut stack.buffer)
 }
 
+/// Pops a value off of the stack and drops it.
+///
+/// # Parameters
+///
+/// - `NSTDStack *const stack` - Pointer to the stack.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the stack is empty.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_collections_stack_pop_discard(
+    stack: &mut NSTDStack,
+) -> NSTDErrorCode {
+    nstd_vec_pop_discard(&mut stack.buffer)
+}
+
 /// Pops all values off the stack.
 ///
 /// # Parameters
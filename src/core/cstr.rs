@@ -1,6 +1,6 @@
 //! Provides functionality for examining/operating on C strings.
 use crate::core::{
-    def::{NSTDAny, NSTDBool, NSTDChar},
+    def::{NSTDAny, NSTDBool, NSTDChar, NSTDInt32},
     slice::NSTDSlice,
 };
 
@@ -98,3 +98,55 @@ pub unsafe extern "C" fn nstd_core_cstr_compare(
         cstr2 = cstr2.add(1);
     }
 }
+
+/// Orders two C strings lexicographically, comparing their characters as unsigned bytes.
+///
+/// # Parameters
+///
+/// - `const NSTDChar *cstr1` - The first C string.
+///
+/// - `const NSTDChar *cstr2` - The second C string.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `cstr1` is less than `cstr2`, 0 if they are equal and
+/// a positive value if `cstr1` is greater than `cstr2`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_cstr_order(
+    cstr1: *const NSTDChar,
+    cstr2: *const NSTDChar,
+) -> NSTDInt32 {
+    let cstr1 = nstd_core_cstr_as_slice(cstr1);
+    let cstr2 = nstd_core_cstr_as_slice(cstr2);
+    cstr1.as_byte_slice().cmp(cstr2.as_byte_slice()) as NSTDInt32
+}
+
+/// Orders two C strings naturally, so that "file9" is ordered before "file10".
+///
+/// # Note
+///
+/// Runs of ASCII digits are compared by their numeric value, everything else is compared as
+/// unsigned bytes. Numbers that only differ by leading zeros are ordered by the number of leading
+/// zeros.
+///
+/// # Parameters
+///
+/// - `const NSTDChar *cstr1` - The first C string.
+///
+/// - `const NSTDChar *cstr2` - The second C string.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `cstr1` is less than `cstr2`, 0 if they are equal and
+/// a positive value if `cstr1` is greater than `cstr2`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_cstr_order_natural(
+    cstr1: *const NSTDChar,
+    cstr2: *const NSTDChar,
+) -> NSTDInt32 {
+    let cstr1 = nstd_core_cstr_as_slice(cstr1);
+    let cstr2 = nstd_core_cstr_as_slice(cstr2);
+    crate::core::str::natural_order(cstr1.as_byte_slice(), cstr2.as_byte_slice()) as NSTDInt32
+}
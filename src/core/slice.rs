@@ -1,6 +1,6 @@
 //! A dynamically-sized view into a contiguous sequence of values.
 use crate::core::{
-    def::{NSTDAny, NSTDAnyConst, NSTDBool},
+    def::{NSTDAny, NSTDAnyConst, NSTDBool, NSTDErrorCode, NSTDInt32},
     pointer::NSTDPointer,
     range::NSTDURange,
     NSTD_CORE_NULL,
@@ -46,6 +46,225 @@ impl NSTDSlice {
     }
 }
 
+/// Slices with this many elements or less are sorted with insertion sort.
+const INSERTION_SORT_THRESHOLD: usize = 16;
+
+/// Orders the elements of a slice with an `is_less` function.
+struct Sorter<F: Fn(NSTDAnyConst, NSTDAnyConst) -> bool> {
+    /// A pointer to the first element.
+    ptr: *mut u8,
+    /// The size of each element.
+    element_size: usize,
+    /// Returns true if the first element should be placed before the second.
+    is_less: F,
+}
+impl<F: Fn(NSTDAnyConst, NSTDAnyConst) -> bool> Sorter<F> {
+    /// Creates a sorter for `slice`.
+    #[inline]
+    fn new(slice: &NSTDSlice, is_less: F) -> Self {
+        Self {
+            ptr: slice.ptr.raw.cast(),
+            element_size: slice.ptr.size,
+            is_less,
+        }
+    }
+
+    /// Returns a pointer to the element at `i`.
+    #[inline]
+    unsafe fn get(&self, i: usize) -> *mut u8 {
+        self.ptr.add(i * self.element_size)
+    }
+
+    /// Returns true if the element at `i` should be placed before the element at `j`.
+    #[inline]
+    unsafe fn less(&self, i: usize, j: usize) -> bool {
+        (self.is_less)(self.get(i).cast(), self.get(j).cast())
+    }
+
+    /// Swaps the elements at `i` and `j`.
+    #[inline]
+    unsafe fn swap(&self, i: usize, j: usize) {
+        if i != j {
+            core::ptr::swap_nonoverlapping(self.get(i), self.get(j), self.element_size);
+        }
+    }
+
+    /// Rotates the elements in [`lo`, `hi`) so that the element at `mid` becomes the first.
+    #[inline]
+    unsafe fn rotate(&self, lo: usize, mid: usize, hi: usize) {
+        let bytes = core::slice::from_raw_parts_mut(self.get(lo), (hi - lo) * self.element_size);
+        bytes.rotate_left((mid - lo) * self.element_size);
+    }
+
+    /// Stable insertion sort of the elements in [`lo`, `hi`).
+    unsafe fn insertion_sort(&self, lo: usize, hi: usize) {
+        for i in lo + 1..hi {
+            let mut j = i;
+            while j > lo && self.less(j, j - 1) {
+                self.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Moves the element at `root` down the heap stored in [`lo`, `lo` + `len`).
+    unsafe fn sift_down(&self, lo: usize, mut root: usize, len: usize) {
+        loop {
+            let mut child = 2 * root + 1;
+            if child >= len {
+                return;
+            }
+            if child + 1 < len && self.less(lo + child, lo + child + 1) {
+                child += 1;
+            }
+            if !self.less(lo + root, lo + child) {
+                return;
+            }
+            self.swap(lo + root, lo + child);
+            root = child;
+        }
+    }
+
+    /// Heap sort of the elements in [`lo`, `hi`).
+    unsafe fn heap_sort(&self, lo: usize, hi: usize) {
+        let len = hi - lo;
+        for root in (0..len / 2).rev() {
+            self.sift_down(lo, root, len);
+        }
+        for end in (1..len).rev() {
+            self.swap(lo, lo + end);
+            self.sift_down(lo, 0, end);
+        }
+    }
+
+    /// Partitions the elements in [`lo`, `hi`) around a median of three pivot, returning the
+    /// pivot's new index.
+    unsafe fn partition(&self, lo: usize, hi: usize) -> usize {
+        let mid = lo + (hi - lo) / 2;
+        let last = hi - 1;
+        if self.less(mid, lo) {
+            self.swap(mid, lo);
+        }
+        if self.less(last, mid) {
+            self.swap(last, mid);
+            if self.less(mid, lo) {
+                self.swap(mid, lo);
+            }
+        }
+        // Move the pivot to the end of the range.
+        self.swap(mid, last);
+        let mut store = lo;
+        for i in lo..last {
+            if self.less(i, last) {
+                self.swap(i, store);
+                store += 1;
+            }
+        }
+        self.swap(store, last);
+        store
+    }
+
+    /// Introspective sort of the elements in [`lo`, `hi`).
+    unsafe fn unstable_sort(&self, mut lo: usize, mut hi: usize, mut depth: u32) {
+        loop {
+            if hi - lo <= INSERTION_SORT_THRESHOLD {
+                return self.insertion_sort(lo, hi);
+            }
+            if depth == 0 {
+                return self.heap_sort(lo, hi);
+            }
+            depth -= 1;
+            // Recurse into the smaller side to keep the stack small.
+            let pivot = self.partition(lo, hi);
+            if pivot - lo < hi - pivot {
+                self.unstable_sort(lo, pivot, depth);
+                lo = pivot + 1;
+            } else {
+                self.unstable_sort(pivot + 1, hi, depth);
+                hi = pivot;
+            }
+        }
+    }
+
+    /// Merges the sorted ranges [`lo`, `mid`) and [`mid`, `hi`) in place.
+    unsafe fn merge(&self, lo: usize, mid: usize, hi: usize) {
+        if lo == mid || mid == hi {
+            return;
+        }
+        if hi - lo == 2 {
+            if self.less(mid, lo) {
+                self.swap(mid, lo);
+            }
+            return;
+        }
+        let (cut1, cut2) = match mid - lo > hi - mid {
+            true => {
+                let cut1 = lo + (mid - lo) / 2;
+                (cut1, self.lower_bound(mid, hi, cut1))
+            }
+            false => {
+                let cut2 = mid + (hi - mid) / 2;
+                (self.upper_bound(lo, mid, cut2), cut2)
+            }
+        };
+        self.rotate(cut1, mid, cut2);
+        let new_mid = cut1 + (cut2 - mid);
+        self.merge(lo, cut1, new_mid);
+        self.merge(new_mid, cut2, hi);
+    }
+
+    /// Returns the first index in [`lo`, `hi`) whose element is not less than the element at
+    /// `key`.
+    unsafe fn lower_bound(&self, mut lo: usize, mut hi: usize, key: usize) -> usize {
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.less(mid, key) {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+        lo
+    }
+
+    /// Returns the first index in [`lo`, `hi`) whose element is greater than the element at `key`.
+    unsafe fn upper_bound(&self, mut lo: usize, mut hi: usize, key: usize) -> usize {
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.less(key, mid) {
+                true => hi = mid,
+                false => lo = mid + 1,
+            }
+        }
+        lo
+    }
+
+    /// Stable merge sort of the first `len` elements.
+    unsafe fn stable_sort(&self, len: usize) {
+        let mut lo = 0;
+        while lo < len {
+            let hi = len.min(lo + INSERTION_SORT_THRESHOLD);
+            self.insertion_sort(lo, hi);
+            lo = hi;
+        }
+        let mut width = INSERTION_SORT_THRESHOLD;
+        while width < len {
+            let mut lo = 0;
+            while lo + width < len {
+                let hi = len.min(lo + 2 * width);
+                self.merge(lo, lo + width, hi);
+                lo = hi;
+            }
+            width *= 2;
+        }
+    }
+}
+
+/// Returns the depth limit for introspective sorting `len` elements.
+#[inline]
+fn depth_limit(len: usize) -> u32 {
+    2 * (usize::BITS - len.leading_zeros())
+}
+
 /// Creates a new slice from raw data.
 ///
 /// # Parameters
@@ -162,6 +381,63 @@ pub unsafe extern "C" fn nstd_core_slice_compare(s1: &NSTDSlice, s2: &NSTDSlice)
     }
 }
 
+/// Orders two slices lexicographically by their bytes.
+///
+/// # Note
+///
+/// A slice that is a prefix of the other slice is ordered first.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const s1` - The first slice.
+///
+/// - `const NSTDSlice *const s2` - The second slice.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `s1` is less than `s2`, 0 if they are equal and a
+/// positive value if `s1` is greater than `s2`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_order(s1: &NSTDSlice, s2: &NSTDSlice) -> NSTDInt32 {
+    s1.as_byte_slice().cmp(s2.as_byte_slice()) as NSTDInt32
+}
+
+/// Orders two slices lexicographically with a comparator function.
+///
+/// # Note
+///
+/// `compare` returns a negative value if the first element is less than the second, 0 if they are
+/// equal and a positive value if the first element is greater than the second. A slice that is a
+/// prefix of the other slice is ordered first.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const s1` - The first slice.
+///
+/// - `const NSTDSlice *const s2` - The second slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `s1` is less than `s2`, 0 if they are equal and a
+/// positive value if `s1` is greater than `s2`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_order_by(
+    s1: &NSTDSlice,
+    s2: &NSTDSlice,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) -> NSTDInt32 {
+    for i in 0..s1.size.min(s2.size) {
+        let ordering = compare(nstd_core_slice_get(s1, i), nstd_core_slice_get(s2, i));
+        if ordering != 0 {
+            return ordering.signum();
+        }
+    }
+    s1.size.cmp(&s2.size) as NSTDInt32
+}
+
 /// Checks if a slice contains `element`.
 ///
 /// # Parameters
@@ -410,8 +686,7 @@ pub unsafe extern "C" fn nstd_core_slice_reverse(slice: &mut NSTDSlice) {
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_core_slice_shift_right(slice: &mut NSTDSlice, x: usize) {
-    let rot = x % slice.size * slice.ptr.size;
-    slice.as_byte_slice_mut().rotate_right(rot);
+    nstd_core_slice_rotate_right(slice, x);
 }
 
 /// Shifts a slice `x` times to the left.
@@ -424,8 +699,39 @@ pub unsafe extern "C" fn nstd_core_slice_shift_right(slice: &mut NSTDSlice, x: u
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_core_slice_shift_left(slice: &mut NSTDSlice, x: usize) {
-    let rot = x % slice.size * slice.ptr.size;
-    slice.as_byte_slice_mut().rotate_left(rot);
+    nstd_core_slice_rotate_left(slice, x);
+}
+
+/// Rotates a slice's elements `x` places to the right in place.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize x` - Number of places to rotate the slice.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_rotate_right(slice: &mut NSTDSlice, x: usize) {
+    if slice.size > 0 {
+        let rot = x % slice.size * slice.ptr.size;
+        slice.as_byte_slice_mut().rotate_right(rot);
+    }
+}
+
+/// Rotates a slice's elements `x` places to the left in place.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize x` - Number of places to rotate the slice.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_rotate_left(slice: &mut NSTDSlice, x: usize) {
+    if slice.size > 0 {
+        let rot = x % slice.size * slice.ptr.size;
+        slice.as_byte_slice_mut().rotate_left(rot);
+    }
 }
 
 /// Copies elements from `s2` to `s1`. The slices must be the same size in bytes.
@@ -477,3 +783,454 @@ pub unsafe extern "C" fn nstd_core_slice_move(s1: &mut NSTDSlice, s2: &mut NSTDS
     nstd_core_slice_copy_from_slice(s1, s2);
     s2.as_byte_slice_mut().fill(0);
 }
+
+/// Sorts a slice with a comparator function, keeping equal elements in their original order.
+///
+/// # Note
+///
+/// `compare` returns a negative value if the first element is less than the second, 0 if they are
+/// equal and a positive value if the first element is greater than the second.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_sort(
+    slice: &mut NSTDSlice,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) {
+    Sorter::new(slice, |a, b| compare(a, b) < 0).stable_sort(slice.size);
+}
+
+/// Sorts a slice with a comparator function, equal elements may be reordered.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_sort_unstable(
+    slice: &mut NSTDSlice,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) {
+    let sorter = Sorter::new(slice, |a, b| compare(a, b) < 0);
+    sorter.unstable_sort(0, slice.size, depth_limit(slice.size));
+}
+
+/// Sorts a slice by comparing the raw bytes of each element.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_sort_bytes(slice: &mut NSTDSlice) {
+    let element_size = slice.ptr.size;
+    let sorter = Sorter::new(slice, |a, b| {
+        let a = core::slice::from_raw_parts(a.cast::<u8>(), element_size);
+        let b = core::slice::from_raw_parts(b.cast::<u8>(), element_size);
+        a < b
+    });
+    sorter.unstable_sort(0, slice.size, depth_limit(slice.size));
+}
+
+/// Checks if a slice is sorted.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+///
+/// # Returns
+///
+/// `NSTDBool is_sorted` - True if no element is less than the element before it.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_is_sorted(
+    slice: &NSTDSlice,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) -> NSTDBool {
+    let sorter = Sorter::new(slice, |a, b| compare(a, b) < 0);
+    NSTDBool::from((1..slice.size).all(|i| !sorter.less(i, i - 1)))
+}
+
+/// Searches a sorted slice for `element`.
+///
+/// # Note
+///
+/// `index` is set to the index of the first element that is not less than `element`, which is
+/// where `element` would be inserted to keep the slice sorted.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The sorted slice.
+///
+/// - `const NSTDAnyConst element` - The element to search for.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+///
+/// - `NSTDUSize *const index` - Returns as the found or insertion index.
+///
+/// # Returns
+///
+/// `NSTDBool found` - True if the element at `index` is equal to `element`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_binary_search(
+    slice: &NSTDSlice,
+    element: NSTDAnyConst,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+    index: &mut usize,
+) -> NSTDBool {
+    let (mut lo, mut hi) = (0, slice.size);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match compare(nstd_core_slice_get(slice, mid), element) < 0 {
+            true => lo = mid + 1,
+            false => hi = mid,
+        }
+    }
+    *index = lo;
+    NSTDBool::from(lo < slice.size && compare(nstd_core_slice_get(slice, lo), element) == 0)
+}
+
+/// Moves every element that matches `predicate` to the front of a slice.
+///
+/// # Note
+///
+/// The order of the elements is not preserved.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `NSTDBool (*predicate)(NSTDAnyConst, NSTDAny)` - Returns true for elements to move.
+///
+/// - `NSTDAny data` - Data to pass to `predicate`.
+///
+/// # Returns
+///
+/// `NSTDUSize count` - The number of elements that matched `predicate`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_partition(
+    slice: &mut NSTDSlice,
+    predicate: unsafe extern "C" fn(NSTDAnyConst, NSTDAny) -> NSTDBool,
+    data: NSTDAny,
+) -> usize {
+    let mut count = 0;
+    for i in 0..slice.size {
+        if predicate(nstd_core_slice_get(slice, i), data) == NSTDBool::NSTD_BOOL_TRUE {
+            if i != count {
+                nstd_core_slice_swap(slice, i, count);
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Reorders a slice so that the element at `n` is the element that would be there if the slice
+/// was sorted, every element before it is not greater and every element after it is not less.
+///
+/// # Note
+///
+/// Nothing is done if `n` is out of bounds.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize n` - The index of the element to select.
+///
+/// - `NSTDInt32 (*compare)(NSTDAnyConst, NSTDAnyConst)` - Compares two elements.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_select_nth(
+    slice: &mut NSTDSlice,
+    n: usize,
+    compare: unsafe extern "C" fn(NSTDAnyConst, NSTDAnyConst) -> i32,
+) {
+    if n >= slice.size {
+        return;
+    }
+    let sorter = Sorter::new(slice, |a, b| compare(a, b) < 0);
+    let (mut lo, mut hi) = (0, slice.size);
+    let mut depth = depth_limit(slice.size);
+    loop {
+        if hi - lo <= INSERTION_SORT_THRESHOLD {
+            return sorter.insertion_sort(lo, hi);
+        }
+        if depth == 0 {
+            return sorter.heap_sort(lo, hi);
+        }
+        depth -= 1;
+        let pivot = sorter.partition(lo, hi);
+        match n.cmp(&pivot) {
+            core::cmp::Ordering::Less => hi = pivot,
+            core::cmp::Ordering::Equal => return,
+            core::cmp::Ordering::Greater => lo = pivot + 1,
+        }
+    }
+}
+
+/// Creates a view into the elements of a slice that are within `range`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDURange *const range` - The range of elements to view.
+///
+/// - `NSTDSlice *const subslice` - Returns as the view into `slice`.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `range` is not within the slice's bounds.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_subslice(
+    slice: &NSTDSlice,
+    range: &NSTDURange,
+    subslice: &mut NSTDSlice,
+) -> NSTDErrorCode {
+    if range.start <= range.end && range.end <= slice.size {
+        let ptr = slice.ptr.raw.add(range.start * slice.ptr.size);
+        *subslice = nstd_core_slice_new(range.end - range.start, slice.ptr.size, ptr);
+        return 0;
+    }
+    1
+}
+
+/// Splits a slice into two views at `index`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize index` - The index to split at, this element starts the second view.
+///
+/// - `NSTDSlice *const first` - Returns as a view into the elements before `index`.
+///
+/// - `NSTDSlice *const second` - Returns as a view into the elements from `index` onward.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `index` is greater than the slice's length.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_split_at(
+    slice: &NSTDSlice,
+    index: usize,
+    first: &mut NSTDSlice,
+    second: &mut NSTDSlice,
+) -> NSTDErrorCode {
+    if index <= slice.size {
+        let element_size = slice.ptr.size;
+        *first = nstd_core_slice_new(index, element_size, slice.ptr.raw);
+        let ptr = slice.ptr.raw.add(index * element_size);
+        *second = nstd_core_slice_new(slice.size - index, element_size, ptr);
+        return 0;
+    }
+    1
+}
+
+/// Splits a slice on the first occurrence of `delimiter`.
+///
+/// # Note
+///
+/// To split on every occurrence of `delimiter`, call this again with `rest` until it returns
+/// false, at which point `first` holds the last part of the slice.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDAnyConst delimiter` - The element to split on.
+///
+/// - `NSTDSlice *const first` - Returns as a view into the elements before `delimiter`.
+///
+/// - `NSTDSlice *const rest` - Returns as a view into the elements after `delimiter`.
+///
+/// # Returns
+///
+/// `NSTDBool found` - True if `delimiter` was found, otherwise `first` views the whole slice and
+/// `rest` is empty.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_split(
+    slice: &NSTDSlice,
+    delimiter: NSTDAnyConst,
+    first: &mut NSTDSlice,
+    rest: &mut NSTDSlice,
+) -> NSTDBool {
+    match nstd_core_slice_find_first(slice, delimiter) {
+        usize::MAX => {
+            *first = *slice;
+            *rest = nstd_core_slice_new(0, slice.ptr.size, slice.end_unchecked().cast());
+            NSTDBool::NSTD_BOOL_FALSE
+        }
+        index => {
+            let element_size = slice.ptr.size;
+            *first = nstd_core_slice_new(index, element_size, slice.ptr.raw);
+            let ptr = slice.ptr.raw.add((index + 1) * element_size);
+            *rest = nstd_core_slice_new(slice.size - index - 1, element_size, ptr);
+            NSTDBool::NSTD_BOOL_TRUE
+        }
+    }
+}
+
+/// Returns the number of chunks a slice is divided into by `nstd_core_slice_chunk`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize chunk_size` - The number of elements in each chunk.
+///
+/// # Returns
+///
+/// `NSTDUSize count` - The number of chunks, 0 if `chunk_size` is 0.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_chunk_count(
+    slice: &NSTDSlice,
+    chunk_size: usize,
+) -> usize {
+    match chunk_size {
+        0 => 0,
+        _ => crate::core::math::nstd_core_math_div_ceil_usize(slice.size, chunk_size),
+    }
+}
+
+/// Creates a view into the chunk at `index` when a slice is divided into chunks of `chunk_size`
+/// elements.
+///
+/// # Note
+///
+/// The last chunk will be shorter than `chunk_size` if the slice's length is not a multiple of
+/// `chunk_size`.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize chunk_size` - The number of elements in each chunk.
+///
+/// - `const NSTDUSize index` - The index of the chunk.
+///
+/// - `NSTDSlice *const chunk` - Returns as the view into the chunk.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if there is no chunk at `index`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_chunk(
+    slice: &NSTDSlice,
+    chunk_size: usize,
+    index: usize,
+    chunk: &mut NSTDSlice,
+) -> NSTDErrorCode {
+    if index < nstd_core_slice_chunk_count(slice, chunk_size) {
+        let start = index * chunk_size;
+        let range = NSTDURange {
+            start,
+            end: slice.size.min(start + chunk_size),
+        };
+        return nstd_core_slice_subslice(slice, &range, chunk);
+    }
+    1
+}
+
+/// Returns the number of windows of `window_size` elements in a slice.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize window_size` - The number of elements in each window.
+///
+/// # Returns
+///
+/// `NSTDUSize count` - The number of windows, 0 if `window_size` is 0 or greater than the slice's
+/// length.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_window_count(
+    slice: &NSTDSlice,
+    window_size: usize,
+) -> usize {
+    match window_size {
+        0 => 0,
+        _ => (slice.size + 1).saturating_sub(window_size),
+    }
+}
+
+/// Creates a view into the `window_size` elements starting at `index`, sliding one element at a
+/// time.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const slice` - The slice.
+///
+/// - `const NSTDUSize window_size` - The number of elements in each window.
+///
+/// - `const NSTDUSize index` - The index of the window.
+///
+/// - `NSTDSlice *const window` - Returns as the view into the window.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if there is no window at `index`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_window(
+    slice: &NSTDSlice,
+    window_size: usize,
+    index: usize,
+    window: &mut NSTDSlice,
+) -> NSTDErrorCode {
+    if index < nstd_core_slice_window_count(slice, window_size) {
+        let range = NSTDURange {
+            start: index,
+            end: index + window_size,
+        };
+        return nstd_core_slice_subslice(slice, &range, window);
+    }
+    1
+}
+
+/// Removes consecutive repeated elements from a slice by moving the first element of each run to
+/// the front of the slice.
+///
+/// # Note
+///
+/// Elements are compared byte for byte. The elements after the returned length are left in an
+/// unspecified order.
+///
+/// # Parameters
+///
+/// - `NSTDSlice *const slice` - The slice.
+///
+/// # Returns
+///
+/// `NSTDUSize len` - The number of elements left at the front of the slice.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_slice_dedup(slice: &mut NSTDSlice) -> usize {
+    let element_size = slice.ptr.size;
+    let element = |i: usize| {
+        let ptr = slice.ptr.raw.add(i * element_size);
+        core::slice::from_raw_parts(ptr as *const u8, element_size)
+    };
+    let mut len = match slice.size {
+        0 => return 0,
+        _ => 1,
+    };
+    for i in 1..slice.size {
+        if element(i) != element(len - 1) {
+            if i != len {
+                let src = slice.ptr.raw.add(i * element_size) as *const u8;
+                let dst = slice.ptr.raw.add(len * element_size).cast();
+                core::ptr::copy_nonoverlapping(src, dst, element_size);
+            }
+            len += 1;
+        }
+    }
+    len
+}
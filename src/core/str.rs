@@ -1,6 +1,6 @@
 //! A UTF-8 encoded byte slice.
 use crate::core::{
-    def::{NSTDAny, NSTDBool, NSTDChar, NSTDErrorCode},
+    def::{NSTDAny, NSTDBool, NSTDChar, NSTDErrorCode, NSTDInt32, NSTDUInt32, NSTDUnichar},
     range::NSTDURange,
     slice::NSTDSlice,
     NSTD_CORE_NULL,
@@ -14,6 +14,202 @@ pub struct NSTDStr {
     pub bytes: NSTDSlice,
 }
 
+/// An iterator over the characters of a string slice.
+///
+/// # Note
+///
+/// The iterator can be advanced from either end, iteration stops once the two ends meet.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NSTDStrChars {
+    /// The string slice being iterated over.
+    pub str: NSTDStr,
+    /// The byte offset of the next character to yield from the front.
+    pub front: usize,
+    /// The byte offset just past the next character to yield from the back.
+    pub back: usize,
+}
+
+/// Describes how an [NSTDStrSplit] iterator separates a string slice.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDStrSplitKind {
+    /// Splits on each occurrence of a pattern.
+    NSTD_STR_SPLIT_KIND_PATTERN,
+    /// Splits on runs of Unicode whitespace, never yielding empty substrings.
+    NSTD_STR_SPLIT_KIND_WHITESPACE,
+    /// Splits on "\n" or "\r\n" line endings, the final line ending is optional.
+    NSTD_STR_SPLIT_KIND_LINES,
+}
+impl Default for NSTDStrSplitKind {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_STR_SPLIT_KIND_PATTERN
+    }
+}
+
+/// A lazy iterator over the substrings of a string slice.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NSTDStrSplit {
+    /// The string slice being split.
+    pub str: NSTDStr,
+    /// The separator, only used by `NSTD_STR_SPLIT_KIND_PATTERN`.
+    pub pattern: NSTDStr,
+    /// How the string slice is separated.
+    pub kind: NSTDStrSplitKind,
+    /// The byte offset of the remaining part of the string slice.
+    pub position: usize,
+    /// Set once the iterator has been exhausted.
+    pub finished: NSTDBool,
+}
+
+/// Describes the Unicode text segments that an [NSTDStrSegments] iterator yields.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDStrSegmentKind {
+    /// Extended grapheme clusters, the characters that a user perceives.
+    NSTD_STR_SEGMENT_KIND_GRAPHEME,
+    /// The text between word boundaries, including runs of whitespace and punctuation.
+    NSTD_STR_SEGMENT_KIND_WORD,
+    /// The text between sentence boundaries.
+    NSTD_STR_SEGMENT_KIND_SENTENCE,
+}
+#[cfg(feature = "nstd_core_segment")]
+impl Default for NSTDStrSegmentKind {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_STR_SEGMENT_KIND_GRAPHEME
+    }
+}
+
+/// An iterator over the Unicode text segments of a string slice, as defined by UAX #29.
+///
+/// # Note
+///
+/// The iterator can be advanced from either end, iteration stops once the two ends meet. Segment
+/// iterators are only available with the `nstd_core_segment` feature.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NSTDStrSegments {
+    /// The string slice being segmented.
+    pub str: NSTDStr,
+    /// The kind of segments to yield.
+    pub kind: NSTDStrSegmentKind,
+    /// The byte offset of the next segment to yield from the front.
+    pub front: usize,
+    /// The byte offset just past the next segment to yield from the back.
+    pub back: usize,
+}
+
+/// Describes why parsing a number from a string slice failed.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDStrParseErrorKind {
+    /// No error occurred.
+    NSTD_STR_PARSE_ERROR_NONE,
+    /// There were no digits to parse.
+    NSTD_STR_PARSE_ERROR_EMPTY,
+    /// A character that isn't a digit in the radix, or a misplaced sign or separator, was found.
+    NSTD_STR_PARSE_ERROR_INVALID_DIGIT,
+    /// The number is too large for the type.
+    NSTD_STR_PARSE_ERROR_POS_OVERFLOW,
+    /// The number is too small for the type.
+    NSTD_STR_PARSE_ERROR_NEG_OVERFLOW,
+    /// The radix isn't supported.
+    NSTD_STR_PARSE_ERROR_INVALID_RADIX,
+    /// A float with separators is too long to be parsed.
+    NSTD_STR_PARSE_ERROR_TOO_LONG,
+}
+impl Default for NSTDStrParseErrorKind {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_STR_PARSE_ERROR_NONE
+    }
+}
+
+/// Describes why and where parsing a number from a string slice failed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct NSTDStrParseError {
+    /// Why parsing failed.
+    pub kind: NSTDStrParseErrorKind,
+    /// The byte offset into the string slice where parsing failed, -1/usize::MAX on success.
+    pub offset: usize,
+}
+
+/// Options for parsing numbers from string slices.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct NSTDStrParseOptions {
+    /// The radix of integers, 2-36. 0 detects the radix from a "0x", "0o" or "0b" prefix and
+    /// otherwise uses 10. Floats must use 0 or 10.
+    pub radix: NSTDUInt32,
+    /// Whether integers in radix 16, 8 or 2 may start with their "0x", "0o" or "0b" prefix.
+    pub prefix: NSTDBool,
+    /// A character that may appear between two digits and is ignored, such as '_', 0 for none.
+    pub separator: NSTDUnichar,
+}
+
+/// Selects the language specific rules used for case conversions and comparisons.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDStrCaseLocale {
+    /// The language independent Unicode case mappings.
+    NSTD_STR_CASE_LOCALE_DEFAULT,
+    /// Turkish and Azerbaijani, where `I` and `ı` are paired as are `İ` and `i`.
+    NSTD_STR_CASE_LOCALE_TURKIC,
+}
+impl Default for NSTDStrCaseLocale {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_STR_CASE_LOCALE_DEFAULT
+    }
+}
+
+/// Returns the case folded form of `chr`.
+fn fold_case(chr: char, locale: NSTDStrCaseLocale) -> impl Iterator<Item = char> {
+    let (chr, fold) = match (locale, chr) {
+        (NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_TURKIC, 'I') => ('ı', false),
+        (NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_TURKIC, 'İ') => ('i', false),
+        // Dotless i has no case folding, even though it's uppercase form folds to `i`.
+        (_, 'ı') => ('ı', false),
+        _ => (chr, true),
+    };
+    // Lowercasing the uppercase form folds characters such as `ß`, `ς` and `ﬁ` like their
+    // uppercase counterparts.
+    let folded = fold.then(|| chr.to_uppercase().flat_map(char::to_lowercase));
+    (!fold)
+        .then_some(chr)
+        .into_iter()
+        .chain(folded.into_iter().flatten())
+}
+
+/// Compares two string slices after case folding them, falling back to a bytewise comparison if
+/// either isn't valid UTF-8.
+unsafe fn compare_folded(
+    str1: &NSTDStr,
+    str2: &NSTDStr,
+    locale: NSTDStrCaseLocale,
+) -> core::cmp::Ordering {
+    let (bytes1, bytes2) = (str1.bytes.as_byte_slice(), str2.bytes.as_byte_slice());
+    match (core::str::from_utf8(bytes1), core::str::from_utf8(bytes2)) {
+        (Ok(str1), Ok(str2)) => {
+            let str1 = str1.chars().flat_map(|chr| fold_case(chr, locale));
+            str1.cmp(str2.chars().flat_map(|chr| fold_case(chr, locale)))
+        }
+        _ => bytes1.cmp(bytes2),
+    }
+}
+
 /// Creates a new `NSTDStr` from a cstring.
 ///
 /// # Parameters
@@ -123,6 +319,232 @@ pub unsafe extern "C" fn nstd_core_str_get(str: &NSTDStr, range: &NSTDURange) ->
     nstd_core_str_from_bytes(&slice)
 }
 
+/// Decodes the UTF-8 character that starts at `offset`, returning it and it's length in bytes.
+fn decode_at(bytes: &[u8], offset: usize) -> Option<(char, usize)> {
+    let bytes = bytes.get(offset..)?;
+    let bytes = &bytes[..bytes.len().min(4)];
+    let valid = match core::str::from_utf8(bytes) {
+        Ok(valid) => valid,
+        Err(err) => core::str::from_utf8(&bytes[..err.valid_up_to()]).ok()?,
+    };
+    valid.chars().next().map(|chr| (chr, chr.len_utf8()))
+}
+
+/// Decodes the UTF-8 character that ends at `end`, returning it and it's length in bytes.
+fn decode_before(bytes: &[u8], end: usize) -> Option<(char, usize)> {
+    // A character is at most 4 bytes, so at most 3 continuation bytes precede it's end.
+    let start = (end.saturating_sub(4)..end)
+        .rev()
+        .find(|&i| bytes[i] & 0xC0 != 0x80)?;
+    match decode_at(bytes, start)? {
+        (chr, len) if start + len == end => Some((chr, len)),
+        _ => None,
+    }
+}
+
+/// Returns an iterator over the characters of a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrChars chars` - The character iterator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_chars(str: &NSTDStr) -> NSTDStrChars {
+    NSTDStrChars {
+        str: *str,
+        front: 0,
+        back: str.bytes.byte_count(),
+    }
+}
+
+/// Yields the next character from the front of a character iterator.
+///
+/// # Note
+///
+/// Each byte that is not part of a valid UTF-8 sequence is yielded as U+FFFD.
+///
+/// # Parameters
+///
+/// - `NSTDStrChars *const chars` - The character iterator.
+///
+/// - `NSTDUnichar *const chr` - Returns as the next character.
+///
+/// - `NSTDUSize *const offset` - Returns as the byte offset of the next character.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_chars_next(
+    chars: &mut NSTDStrChars,
+    chr: &mut NSTDUnichar,
+    offset: &mut usize,
+) -> NSTDBool {
+    if chars.front >= chars.back {
+        return NSTDBool::NSTD_BOOL_FALSE;
+    }
+    let bytes = &chars.str.bytes.as_byte_slice()[..chars.back];
+    let (next, len) = decode_at(bytes, chars.front).unwrap_or((char::REPLACEMENT_CHARACTER, 1));
+    *chr = NSTDUnichar::from(next);
+    *offset = chars.front;
+    chars.front += len;
+    NSTDBool::NSTD_BOOL_TRUE
+}
+
+/// Yields the next character from the back of a character iterator.
+///
+/// # Note
+///
+/// Each byte that is not part of a valid UTF-8 sequence is yielded as U+FFFD.
+///
+/// # Parameters
+///
+/// - `NSTDStrChars *const chars` - The character iterator.
+///
+/// - `NSTDUnichar *const chr` - Returns as the next character.
+///
+/// - `NSTDUSize *const offset` - Returns as the byte offset of the next character.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_chars_next_back(
+    chars: &mut NSTDStrChars,
+    chr: &mut NSTDUnichar,
+    offset: &mut usize,
+) -> NSTDBool {
+    if chars.front >= chars.back {
+        return NSTDBool::NSTD_BOOL_FALSE;
+    }
+    let bytes = &chars.str.bytes.as_byte_slice()[chars.front..chars.back];
+    let end = bytes.len();
+    let (next, len) = decode_before(bytes, end).unwrap_or((char::REPLACEMENT_CHARACTER, 1));
+    *chr = NSTDUnichar::from(next);
+    chars.back -= len;
+    *offset = chars.back;
+    NSTDBool::NSTD_BOOL_TRUE
+}
+
+/// Gets the character at a character index of a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize n` - The index of the character.
+///
+/// - `NSTDUnichar *const chr` - Returns as the character on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `n` is out of bounds or `str` isn't valid UTF-8.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_nth_char(
+    str: &NSTDStr,
+    n: usize,
+    chr: &mut NSTDUnichar,
+) -> NSTDErrorCode {
+    if let Ok(str) = core::str::from_utf8(str.bytes.as_byte_slice()) {
+        if let Some(nth) = str.chars().nth(n) {
+            *chr = NSTDUnichar::from(nth);
+            return 0;
+        }
+    }
+    1
+}
+
+/// Converts a character index into a byte index.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize char_index` - The character index, may be the string slice's length.
+///
+/// # Returns
+///
+/// `NSTDUSize byte_index` - The byte offset of the character, -1/usize::MAX if `char_index` is out
+/// of bounds or `str` isn't valid UTF-8.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_char_to_byte_index(
+    str: &NSTDStr,
+    char_index: usize,
+) -> usize {
+    if let Ok(str) = core::str::from_utf8(str.bytes.as_byte_slice()) {
+        return match str.char_indices().nth(char_index) {
+            Some((byte_index, _)) => byte_index,
+            None if str.chars().count() == char_index => str.len(),
+            None => usize::MAX,
+        };
+    }
+    usize::MAX
+}
+
+/// Converts a byte index into a character index.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize byte_index` - The byte index, may be the string slice's byte length.
+///
+/// # Returns
+///
+/// `NSTDUSize char_index` - The index of the character starting at `byte_index`, -1/usize::MAX if
+/// `byte_index` is out of bounds, is not on a character boundary or `str` isn't valid UTF-8.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_byte_to_char_index(
+    str: &NSTDStr,
+    byte_index: usize,
+) -> usize {
+    if let Ok(str) = core::str::from_utf8(str.bytes.as_byte_slice()) {
+        if str.is_char_boundary(byte_index) {
+            return str[..byte_index].chars().count();
+        }
+    }
+    usize::MAX
+}
+
+/// Decodes the UTF-8 encoded character that starts at a byte offset of a string slice.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize offset` - The byte offset of the character.
+///
+/// - `NSTDUnichar *const chr` - Returns as the decoded character on success.
+///
+/// - `NSTDUSize *const len` - Returns as the number of bytes the character uses on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if there isn't a valid UTF-8 sequence at `offset`.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_decode_char(
+    str: &NSTDStr,
+    offset: usize,
+    chr: &mut NSTDUnichar,
+    len: &mut usize,
+) -> NSTDErrorCode {
+    match decode_at(str.bytes.as_byte_slice(), offset) {
+        Some((decoded, decoded_len)) => {
+            *chr = NSTDUnichar::from(decoded);
+            *len = decoded_len;
+            0
+        }
+        None => 1,
+    }
+}
+
 /// Checks if a string slice is entirely ASCII.
 ///
 /// # Parameters
@@ -158,6 +580,147 @@ pub unsafe extern "C" fn nstd_core_str_compare(str1: &NSTDStr, str2: &NSTDStr) -
     NSTDBool::from(str1.bytes.as_byte_slice() == str2.bytes.as_byte_slice())
 }
 
+/// Orders two string slices lexicographically by Unicode scalar value.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `str1` is less than `str2`, 0 if they are equal and a
+/// positive value if `str1` is greater than `str2`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_order(str1: &NSTDStr, str2: &NSTDStr) -> NSTDInt32 {
+    // UTF-8 preserves the order of Unicode scalar values.
+    str1.bytes.as_byte_slice().cmp(str2.bytes.as_byte_slice()) as NSTDInt32
+}
+
+/// Orders two string slices naturally, so that "file9" is ordered before "file10".
+///
+/// # Note
+///
+/// Runs of ASCII digits are compared by their numeric value, everything else is compared by
+/// Unicode scalar value. Numbers that only differ by leading zeros are ordered by the number of
+/// leading zeros.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `str1` is less than `str2`, 0 if they are equal and a
+/// positive value if `str1` is greater than `str2`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_order_natural(str1: &NSTDStr, str2: &NSTDStr) -> NSTDInt32 {
+    natural_order(str1.bytes.as_byte_slice(), str2.bytes.as_byte_slice()) as NSTDInt32
+}
+
+/// Orders two byte strings naturally.
+pub(crate) fn natural_order(bytes1: &[u8], bytes2: &[u8]) -> core::cmp::Ordering {
+    use core::cmp::Ordering;
+    /// Splits the run of ASCII digits off the front of `bytes`.
+    fn split_digits(bytes: &[u8]) -> (&[u8], &[u8]) {
+        let len = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+        bytes.split_at(len)
+    }
+    let (mut rest1, mut rest2) = (bytes1, bytes2);
+    // Differences in leading zeros only decide the order if nothing else does.
+    let mut zeros_order = Ordering::Equal;
+    loop {
+        match (rest1.first(), rest2.first()) {
+            (Some(b1), Some(b2)) if b1.is_ascii_digit() && b2.is_ascii_digit() => {
+                let (num1, tail1) = split_digits(rest1);
+                let (num2, tail2) = split_digits(rest2);
+                let trimmed1 = &num1[num1.iter().take_while(|&&b| b == b'0').count()..];
+                let trimmed2 = &num2[num2.iter().take_while(|&&b| b == b'0').count()..];
+                let ordering = trimmed1
+                    .len()
+                    .cmp(&trimmed2.len())
+                    .then(trimmed1.cmp(trimmed2));
+                if ordering.is_ne() {
+                    return ordering;
+                }
+                zeros_order = zeros_order.then(num1.len().cmp(&num2.len()));
+                (rest1, rest2) = (tail1, tail2);
+            }
+            (Some(b1), Some(b2)) => {
+                if b1 != b2 {
+                    return b1.cmp(b2);
+                }
+                (rest1, rest2) = (&rest1[1..], &rest2[1..]);
+            }
+            (None, None) => return zeros_order,
+            (None, _) => return Ordering::Less,
+            (_, None) => return Ordering::Greater,
+        }
+    }
+}
+
+/// Checks if two string slices are equal when case is ignored.
+///
+/// # Note
+///
+/// The string slices are compared using full Unicode case folding, so "STRASSE" is equal to
+/// "straße". Invalid UTF-8 is compared byte for byte.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// # Returns
+///
+/// `NSTDBool is_eq` - True if the two slices are equal when case is ignored.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_eq_ignore_case(
+    str1: &NSTDStr,
+    str2: &NSTDStr,
+    locale: NSTDStrCaseLocale,
+) -> NSTDBool {
+    NSTDBool::from(compare_folded(str1, str2, locale).is_eq())
+}
+
+/// Orders two string slices when case is ignored.
+///
+/// # Note
+///
+/// The case folded string slices are ordered by Unicode scalar value. Invalid UTF-8 is compared
+/// byte for byte.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str1` - The first string slice.
+///
+/// - `const NSTDStr *const str2` - The second string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// # Returns
+///
+/// `NSTDInt32 ordering` - A negative value if `str1` is less than `str2`, 0 if they are equal and a
+/// positive value if `str1` is greater than `str2`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_compare_ignore_case(
+    str1: &NSTDStr,
+    str2: &NSTDStr,
+    locale: NSTDStrCaseLocale,
+) -> NSTDInt32 {
+    compare_folded(str1, str2, locale) as NSTDInt32
+}
+
 /// Generates pattern checking functions.
 macro_rules! nstd_str_pat_check {
     ($fn_name: ident, $method: ident) => {
@@ -195,37 +758,527 @@ macro_rules! nstd_str_find {
 nstd_str_find!(nstd_core_str_find, find);
 nstd_str_find!(nstd_core_str_find_last, rfind);
 
-/// Generates `nstd_core_str_to_*case` functions.
-macro_rules! nstd_core_str_to_case {
-    ($name: ident, $method: ident) => {
-        ///
-        #[inline]
-        #[cfg_attr(feature = "clib", no_mangle)]
-        pub unsafe extern "C" fn $name(str: &mut NSTDStr) -> NSTDErrorCode {
-            if let Ok(str) = core::str::from_utf8_mut(str.bytes.as_byte_slice_mut()) {
-                str.$method();
-                return 0;
-            }
-            1
-        }
-    };
+/// Creates a string slice over the bytes in the range [`start`, `end`) of `str`.
+#[inline]
+unsafe fn substr(str: &NSTDStr, start: usize, end: usize) -> NSTDStr {
+    let ptr = str.bytes.ptr.raw.add(start);
+    let bytes = crate::core::slice::nstd_core_slice_new(end - start, 1, ptr);
+    NSTDStr { bytes }
 }
-nstd_core_str_to_case!(nstd_core_str_to_uppercase, make_ascii_uppercase);
-nstd_core_str_to_case!(nstd_core_str_to_lowercase, make_ascii_lowercase);
 
-/// Generates str => number conversion functions.
-macro_rules! nstd_str_to_num {
-    ($name: ident, $type: ty) => {
-        ///
-        #[cfg_attr(feature = "clib", no_mangle)]
-        pub unsafe extern "C" fn $name(str: &NSTDStr, is_err: &mut NSTDErrorCode) -> $type {
-            if let Ok(str) = core::str::from_utf8(str.bytes.as_byte_slice()) {
-                if let Ok(v) = str.parse::<$type>() {
-                    return v;
-                }
-            }
-            *is_err = 1;
-            <$type>::default()
+/// Creates a split iterator of `kind` over `str`.
+#[inline]
+fn new_split(str: &NSTDStr, pattern: &NSTDStr, kind: NSTDStrSplitKind) -> NSTDStrSplit {
+    NSTDStrSplit {
+        str: *str,
+        pattern: *pattern,
+        kind,
+        position: 0,
+        finished: NSTDBool::NSTD_BOOL_FALSE,
+    }
+}
+
+/// Returns an iterator over the substrings of a string slice separated by a pattern.
+///
+/// # Note
+///
+/// Empty substrings are yielded between adjacent separators and at either end of `str`. An empty
+/// pattern never matches, so the whole string slice is yielded.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const pattern` - The separator.
+///
+/// # Returns
+///
+/// `NSTDStrSplit split` - The split iterator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_split(str: &NSTDStr, pattern: &NSTDStr) -> NSTDStrSplit {
+    new_split(str, pattern, NSTDStrSplitKind::NSTD_STR_SPLIT_KIND_PATTERN)
+}
+
+/// Returns an iterator over the substrings of a string slice separated by Unicode whitespace.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSplit split` - The split iterator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_split_whitespace(str: &NSTDStr) -> NSTDStrSplit {
+    new_split(
+        str,
+        &substr(str, 0, 0),
+        NSTDStrSplitKind::NSTD_STR_SPLIT_KIND_WHITESPACE,
+    )
+}
+
+/// Returns an iterator over the lines of a string slice, without their line endings.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSplit lines` - The line iterator.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_lines(str: &NSTDStr) -> NSTDStrSplit {
+    new_split(
+        str,
+        &substr(str, 0, 0),
+        NSTDStrSplitKind::NSTD_STR_SPLIT_KIND_LINES,
+    )
+}
+
+/// Yields the next substring from a split iterator.
+///
+/// # Parameters
+///
+/// - `NSTDStrSplit *const split` - The split iterator.
+///
+/// - `NSTDStr *const substr` - Returns as the next substring.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_split_next(
+    split: &mut NSTDStrSplit,
+    substr: &mut NSTDStr,
+) -> NSTDBool {
+    if split.finished == NSTDBool::NSTD_BOOL_TRUE {
+        return NSTDBool::NSTD_BOOL_FALSE;
+    }
+    let bytes = split.str.bytes.as_byte_slice();
+    let start = split.position;
+    let end = match split.kind {
+        NSTDStrSplitKind::NSTD_STR_SPLIT_KIND_PATTERN => {
+            let pattern = split.pattern.bytes.as_byte_slice();
+            let found = match pattern.is_empty() {
+                true => None,
+                false => bytes[start..]
+                    .windows(pattern.len())
+                    .position(|window| window == pattern),
+            };
+            match found {
+                Some(index) => {
+                    split.position = start + index + pattern.len();
+                    start + index
+                }
+                None => {
+                    split.finished = NSTDBool::NSTD_BOOL_TRUE;
+                    bytes.len()
+                }
+            }
+        }
+        NSTDStrSplitKind::NSTD_STR_SPLIT_KIND_WHITESPACE => {
+            let is_space =
+                |offset| matches!(decode_at(bytes, offset), Some((chr, _)) if chr.is_whitespace());
+            let mut start = start;
+            while start < bytes.len() && is_space(start) {
+                start += decode_at(bytes, start).map_or(1, |(_, len)| len);
+            }
+            if start == bytes.len() {
+                split.finished = NSTDBool::NSTD_BOOL_TRUE;
+                return NSTDBool::NSTD_BOOL_FALSE;
+            }
+            let mut end = start;
+            while end < bytes.len() && !is_space(end) {
+                end += decode_at(bytes, end).map_or(1, |(_, len)| len);
+            }
+            split.position = end;
+            *substr = self::substr(&split.str, start, end);
+            return NSTDBool::NSTD_BOOL_TRUE;
+        }
+        NSTDStrSplitKind::NSTD_STR_SPLIT_KIND_LINES => {
+            if start == bytes.len() {
+                split.finished = NSTDBool::NSTD_BOOL_TRUE;
+                return NSTDBool::NSTD_BOOL_FALSE;
+            }
+            match bytes[start..].iter().position(|&byte| byte == b'\n') {
+                Some(index) => {
+                    split.position = start + index + 1;
+                    match index > 0 && bytes[start + index - 1] == b'\r' {
+                        true => start + index - 1,
+                        false => start + index,
+                    }
+                }
+                None => {
+                    split.position = bytes.len();
+                    bytes.len()
+                }
+            }
+        }
+    };
+    *substr = self::substr(&split.str, start, end);
+    NSTDBool::NSTD_BOOL_TRUE
+}
+
+/// Creates a segment iterator of `kind` over the valid UTF-8 prefix of `str`.
+#[cfg(feature = "nstd_core_segment")]
+unsafe fn new_segments(str: &NSTDStr, kind: NSTDStrSegmentKind) -> NSTDStrSegments {
+    let back = match core::str::from_utf8(str.bytes.as_byte_slice()) {
+        Ok(valid) => valid.len(),
+        Err(err) => err.valid_up_to(),
+    };
+    NSTDStrSegments {
+        str: *str,
+        kind,
+        front: 0,
+        back,
+    }
+}
+
+/// Returns an iterator over the extended grapheme clusters of a string slice.
+///
+/// # Note
+///
+/// Iteration stops at the first invalid UTF-8 sequence in `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSegments graphemes` - The grapheme cluster iterator.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_graphemes(str: &NSTDStr) -> NSTDStrSegments {
+    new_segments(str, NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_GRAPHEME)
+}
+
+/// Returns an iterator over the segments of a string slice between word boundaries.
+///
+/// # Note
+///
+/// Iteration stops at the first invalid UTF-8 sequence in `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSegments words` - The word segment iterator.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_words(str: &NSTDStr) -> NSTDStrSegments {
+    new_segments(str, NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_WORD)
+}
+
+/// Returns an iterator over the sentences of a string slice.
+///
+/// # Note
+///
+/// Iteration stops at the first invalid UTF-8 sequence in `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStrSegments sentences` - The sentence iterator.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_sentences(str: &NSTDStr) -> NSTDStrSegments {
+    new_segments(str, NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_SENTENCE)
+}
+
+/// Returns the byte length of the first or last segment of `text`.
+#[cfg(feature = "nstd_core_segment")]
+fn segment_len(text: &str, kind: NSTDStrSegmentKind, from_back: bool) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+    let segment = match (kind, from_back) {
+        (NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_GRAPHEME, false) => text.graphemes(true).next(),
+        (NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_GRAPHEME, true) => {
+            text.graphemes(true).next_back()
+        }
+        (NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_WORD, false) => text.split_word_bounds().next(),
+        (NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_WORD, true) => {
+            text.split_word_bounds().next_back()
+        }
+        (NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_SENTENCE, false) => {
+            text.split_sentence_bounds().next()
+        }
+        // Sentence boundaries can only be found from the front, so start at the last paragraph.
+        (NSTDStrSegmentKind::NSTD_STR_SEGMENT_KIND_SENTENCE, true) => {
+            text[last_paragraph(text)..].split_sentence_bounds().last()
+        }
+    };
+    segment.map_or(text.len(), str::len)
+}
+
+/// Returns the byte offset of the last paragraph in `text`, ignoring trailing paragraph separators.
+///
+/// There is always a sentence boundary after a paragraph separator, except between CR and LF.
+#[cfg(feature = "nstd_core_segment")]
+fn last_paragraph(text: &str) -> usize {
+    let is_separator = |c| matches!(c, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}');
+    let body = text.trim_end_matches(is_separator);
+    body.rfind(is_separator).map_or(0, |index| {
+        index + body[index..].chars().next().map_or(1, char::len_utf8)
+    })
+}
+
+/// Yields the next segment from the front of a segment iterator.
+///
+/// # Parameters
+///
+/// - `NSTDStrSegments *const segments` - The segment iterator.
+///
+/// - `NSTDStr *const segment` - Returns as the next segment.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_segments_next(
+    segments: &mut NSTDStrSegments,
+    segment: &mut NSTDStr,
+) -> NSTDBool {
+    if segments.front >= segments.back {
+        return NSTDBool::NSTD_BOOL_FALSE;
+    }
+    let bytes = &segments.str.bytes.as_byte_slice()[segments.front..segments.back];
+    let text = core::str::from_utf8_unchecked(bytes);
+    let len = segment_len(text, segments.kind, false);
+    *segment = substr(&segments.str, segments.front, segments.front + len);
+    segments.front += len;
+    NSTDBool::NSTD_BOOL_TRUE
+}
+
+/// Yields the next segment from the back of a segment iterator.
+///
+/// # Note
+///
+/// After a segment is yielded, `segments.back` is the byte offset of that segment. Iterating a
+/// string slice that ends at a text cursor from the back yields the grapheme cluster that a
+/// backspace should remove.
+///
+/// Sentence boundaries can only be found from the front, so each sentence yielded from the back
+/// takes time proportional to the length of it's paragraph, and iterating a whole paragraph from
+/// the back takes time proportional to the square of it's length.
+///
+/// # Parameters
+///
+/// - `NSTDStrSegments *const segments` - The segment iterator.
+///
+/// - `NSTDStr *const segment` - Returns as the next segment.
+///
+/// # Returns
+///
+/// `NSTDBool has_next` - False if the iterator has been exhausted.
+#[cfg(feature = "nstd_core_segment")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_core_segment")))]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_segments_next_back(
+    segments: &mut NSTDStrSegments,
+    segment: &mut NSTDStr,
+) -> NSTDBool {
+    if segments.front >= segments.back {
+        return NSTDBool::NSTD_BOOL_FALSE;
+    }
+    let bytes = &segments.str.bytes.as_byte_slice()[segments.front..segments.back];
+    let text = core::str::from_utf8_unchecked(bytes);
+    let len = segment_len(text, segments.kind, true);
+    *segment = substr(&segments.str, segments.back - len, segments.back);
+    segments.back -= len;
+    NSTDBool::NSTD_BOOL_TRUE
+}
+
+/// Returns the part of `str` left after removing leading and/or trailing characters that match
+/// `is_trimmed`. Trimming stops at the first byte that is not valid UTF-8.
+unsafe fn trim_by<F: Fn(char) -> bool>(
+    str: &NSTDStr,
+    start: bool,
+    end: bool,
+    is_trimmed: F,
+) -> NSTDStr {
+    let bytes = str.bytes.as_byte_slice();
+    let mut first = 0;
+    let mut last = bytes.len();
+    if start {
+        while let Some((chr, len)) = decode_at(&bytes[..last], first) {
+            if !is_trimmed(chr) {
+                break;
+            }
+            first += len;
+        }
+    }
+    if end {
+        while let Some((chr, len)) = decode_before(&bytes[first..], last - first) {
+            if !is_trimmed(chr) {
+                break;
+            }
+            last -= len;
+        }
+    }
+    substr(str, first, last)
+}
+
+/// Returns the characters of `chars`, or `None` if it isn't valid UTF-8.
+#[inline]
+unsafe fn char_set(chars: &NSTDStr) -> Option<&str> {
+    core::str::from_utf8(chars.bytes.as_byte_slice()).ok()
+}
+
+/// Returns a string slice with leading and trailing Unicode whitespace removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_trim(str: &NSTDStr) -> NSTDStr {
+    trim_by(str, true, true, char::is_whitespace)
+}
+
+/// Returns a string slice with leading Unicode whitespace removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_trim_start(str: &NSTDStr) -> NSTDStr {
+    trim_by(str, true, false, char::is_whitespace)
+}
+
+/// Returns a string slice with trailing Unicode whitespace removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_trim_end(str: &NSTDStr) -> NSTDStr {
+    trim_by(str, false, true, char::is_whitespace)
+}
+
+/// Returns a string slice with leading and trailing characters from a character set removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const chars` - The characters to remove.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`. This is all of `str` if
+/// `chars` isn't valid UTF-8.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_trim_chars(str: &NSTDStr, chars: &NSTDStr) -> NSTDStr {
+    match char_set(chars) {
+        Some(chars) => trim_by(str, true, true, |chr| chars.contains(chr)),
+        None => *str,
+    }
+}
+
+/// Returns a string slice with leading characters from a character set removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const chars` - The characters to remove.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`. This is all of `str` if
+/// `chars` isn't valid UTF-8.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_trim_start_chars(str: &NSTDStr, chars: &NSTDStr) -> NSTDStr {
+    match char_set(chars) {
+        Some(chars) => trim_by(str, true, false, |chr| chars.contains(chr)),
+        None => *str,
+    }
+}
+
+/// Returns a string slice with trailing characters from a character set removed.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const chars` - The characters to remove.
+///
+/// # Returns
+///
+/// `NSTDStr trimmed` - The trimmed string slice, a view into `str`. This is all of `str` if
+/// `chars` isn't valid UTF-8.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_trim_end_chars(str: &NSTDStr, chars: &NSTDStr) -> NSTDStr {
+    match char_set(chars) {
+        Some(chars) => trim_by(str, false, true, |chr| chars.contains(chr)),
+        None => *str,
+    }
+}
+
+/// Generates `nstd_core_str_to_*case` functions.
+macro_rules! nstd_core_str_to_case {
+    ($name: ident, $method: ident) => {
+        ///
+        #[inline]
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(str: &mut NSTDStr) -> NSTDErrorCode {
+            if let Ok(str) = core::str::from_utf8_mut(str.bytes.as_byte_slice_mut()) {
+                str.$method();
+                return 0;
+            }
+            1
+        }
+    };
+}
+nstd_core_str_to_case!(nstd_core_str_to_uppercase, make_ascii_uppercase);
+nstd_core_str_to_case!(nstd_core_str_to_lowercase, make_ascii_lowercase);
+
+/// Generates str => number conversion functions.
+macro_rules! nstd_str_to_num {
+    ($name: ident, $type: ty) => {
+        ///
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(str: &NSTDStr, is_err: &mut NSTDErrorCode) -> $type {
+            if let Ok(str) = core::str::from_utf8(str.bytes.as_byte_slice()) {
+                if let Ok(v) = str.parse::<$type>() {
+                    return v;
+                }
+            }
+            *is_err = 1;
+            <$type>::default()
         }
     };
 }
@@ -241,3 +1294,278 @@ nstd_str_to_num!(nstd_core_str_to_i64, i64);
 nstd_str_to_num!(nstd_core_str_to_u64, u64);
 nstd_str_to_num!(nstd_core_str_to_isize, isize);
 nstd_str_to_num!(nstd_core_str_to_usize, usize);
+
+/// Returns the default options for parsing numbers, decimal without prefixes or separators.
+///
+/// # Returns
+///
+/// `NSTDStrParseOptions options` - The default parsing options.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_core_str_parse_options_default() -> NSTDStrParseOptions {
+    NSTDStrParseOptions {
+        radix: 10,
+        prefix: NSTDBool::NSTD_BOOL_FALSE,
+        separator: 0,
+    }
+}
+
+/// A parsing failure, it's kind and the byte offset where it occurred.
+type ParseFailure = (NSTDStrParseErrorKind, usize);
+
+/// Encodes a separator into `buffer`, returning an empty slice if there is no separator.
+fn separator_bytes(separator: NSTDUnichar, buffer: &mut [u8; 4]) -> &[u8] {
+    match char::from_u32(separator) {
+        Some(separator) if separator != '\0' => separator.encode_utf8(buffer).as_bytes(),
+        _ => &[],
+    }
+}
+
+/// Scans a run of digits in `radix` starting at `pos`, calling `digit` with the offset and value
+/// of each one. Separators must be between two digits. Returns the end of the run and the number
+/// of digits in it.
+fn scan_digits<F: FnMut(usize, u32) -> Result<(), ParseFailure>>(
+    bytes: &[u8],
+    mut pos: usize,
+    radix: u32,
+    separator: &[u8],
+    mut digit: F,
+) -> Result<(usize, usize), ParseFailure> {
+    let mut count = 0;
+    while let Some(&byte) = bytes.get(pos) {
+        if let Some(value) = char::from(byte).to_digit(radix) {
+            digit(pos, value)?;
+            count += 1;
+            pos += 1;
+        } else if !separator.is_empty() && bytes[pos..].starts_with(separator) {
+            let next = bytes.get(pos + separator.len()).copied().map(char::from);
+            if count == 0 || !next.is_some_and(|next| next.is_digit(radix)) {
+                return Err((
+                    NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_DIGIT,
+                    pos,
+                ));
+            }
+            pos += separator.len();
+        } else {
+            break;
+        }
+    }
+    Ok((pos, count))
+}
+
+/// Parses an integer, returning whether it is negative and it's magnitude. `max_pos` and
+/// `max_neg` are the largest magnitudes that the type can hold for each sign.
+unsafe fn parse_int(
+    str: &NSTDStr,
+    options: &NSTDStrParseOptions,
+    max_pos: u128,
+    max_neg: u128,
+) -> Result<(bool, u128), ParseFailure> {
+    let bytes = str.bytes.as_byte_slice();
+    let mut radix = options.radix;
+    if radix != 0 && !(2..=36).contains(&radix) {
+        return Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_RADIX, 0));
+    }
+    let (negative, mut pos) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    if negative && max_neg == 0 {
+        return Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_DIGIT, 0));
+    }
+    if radix == 0 || options.prefix == NSTDBool::NSTD_BOOL_TRUE {
+        let prefix_radix = match bytes.get(pos..pos + 2) {
+            Some([b'0', b'x' | b'X']) => 16,
+            Some([b'0', b'o' | b'O']) => 8,
+            Some([b'0', b'b' | b'B']) => 2,
+            _ => 0,
+        };
+        if prefix_radix != 0 && (radix == 0 || radix == prefix_radix) {
+            radix = prefix_radix;
+            pos += 2;
+        }
+    }
+    if radix == 0 {
+        radix = 10;
+    }
+    let (max, overflow) = match negative {
+        true => (
+            max_neg,
+            NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_NEG_OVERFLOW,
+        ),
+        false => (
+            max_pos,
+            NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_POS_OVERFLOW,
+        ),
+    };
+    let mut magnitude = 0u128;
+    let mut separator = [0; 4];
+    let separator = separator_bytes(options.separator, &mut separator);
+    let (end, count) = scan_digits(bytes, pos, radix, separator, |offset, digit| {
+        magnitude = magnitude * u128::from(radix) + u128::from(digit);
+        match magnitude > max {
+            true => Err((overflow, offset)),
+            false => Ok(()),
+        }
+    })?;
+    if end < bytes.len() {
+        return Err((
+            NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_DIGIT,
+            end,
+        ));
+    } else if count == 0 {
+        return Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_EMPTY, end));
+    }
+    Ok((negative, magnitude))
+}
+
+/// The most bytes that a float with separators may have once the separators are removed.
+const FLOAT_BUFFER_LEN: usize = 1024;
+
+/// Checks the syntax of a decimal float, copying it into `buffer` without it's separators if it
+/// has any.
+unsafe fn float_text<'a>(
+    str: &'a NSTDStr,
+    options: &NSTDStrParseOptions,
+    buffer: &'a mut [u8; FLOAT_BUFFER_LEN],
+) -> Result<&'a str, ParseFailure> {
+    const INVALID_DIGIT: NSTDStrParseErrorKind =
+        NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_DIGIT;
+    let bytes = str.bytes.as_byte_slice();
+    if options.radix != 0 && options.radix != 10 {
+        return Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_RADIX, 0));
+    }
+    let sign_len = usize::from(matches!(bytes.first(), Some(b'-' | b'+')));
+    let special = &bytes[sign_len..];
+    if ["inf", "infinity", "nan"]
+        .iter()
+        .any(|name| special.eq_ignore_ascii_case(name.as_bytes()))
+    {
+        return core::str::from_utf8(bytes).map_err(|_| (INVALID_DIGIT, 0));
+    }
+    let mut separator = [0; 4];
+    let separator = separator_bytes(options.separator, &mut separator);
+    let ignore = |_, _| Ok(());
+    let (mut pos, mut count) = scan_digits(bytes, sign_len, 10, separator, ignore)?;
+    if bytes.get(pos) == Some(&b'.') {
+        let (end, fraction) = scan_digits(bytes, pos + 1, 10, separator, ignore)?;
+        pos = end;
+        count += fraction;
+    }
+    if count == 0 {
+        return match bytes.len() == sign_len {
+            true => Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_EMPTY, sign_len)),
+            false => Err((INVALID_DIGIT, sign_len)),
+        };
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        pos += 1 + usize::from(matches!(bytes.get(pos + 1), Some(b'-' | b'+')));
+        let (end, exponent) = scan_digits(bytes, pos, 10, separator, ignore)?;
+        if exponent == 0 {
+            return Err((INVALID_DIGIT, end));
+        }
+        pos = end;
+    }
+    if pos < bytes.len() {
+        return Err((INVALID_DIGIT, pos));
+    }
+    if separator.is_empty() || !bytes.windows(separator.len()).any(|w| w == separator) {
+        return core::str::from_utf8(bytes).map_err(|_| (INVALID_DIGIT, 0));
+    }
+    let mut len = 0;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if bytes[pos..].starts_with(separator) {
+            pos += separator.len();
+            continue;
+        }
+        if len == FLOAT_BUFFER_LEN {
+            return Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_TOO_LONG, pos));
+        }
+        buffer[len] = bytes[pos];
+        len += 1;
+        pos += 1;
+    }
+    core::str::from_utf8(&buffer[..len]).map_err(|_| (INVALID_DIGIT, 0))
+}
+
+/// Records the outcome of parsing a number in `error`, returning the parsed value or the default
+/// value on failure.
+fn parse_result<T: Default>(result: Result<T, ParseFailure>, error: &mut NSTDStrParseError) -> T {
+    match result {
+        Ok(value) => {
+            *error = NSTDStrParseError {
+                kind: NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_NONE,
+                offset: usize::MAX,
+            };
+            value
+        }
+        Err((kind, offset)) => {
+            *error = NSTDStrParseError { kind, offset };
+            T::default()
+        }
+    }
+}
+
+/// Generates integer parsing functions.
+macro_rules! nstd_str_parse_int {
+    ($name: ident, $type: ty) => {
+        ///
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(
+            str: &NSTDStr,
+            options: &NSTDStrParseOptions,
+            error: &mut NSTDStrParseError,
+        ) -> $type {
+            let max_neg = (<$type>::MIN as i128).unsigned_abs();
+            let result = parse_int(str, options, <$type>::MAX as u128, max_neg);
+            let result = result.map(|(negative, magnitude)| match negative {
+                true => (magnitude as i128).wrapping_neg() as $type,
+                false => magnitude as $type,
+            });
+            parse_result(result, error)
+        }
+    };
+}
+nstd_str_parse_int!(nstd_core_str_parse_i8, i8);
+nstd_str_parse_int!(nstd_core_str_parse_u8, u8);
+nstd_str_parse_int!(nstd_core_str_parse_i16, i16);
+nstd_str_parse_int!(nstd_core_str_parse_u16, u16);
+nstd_str_parse_int!(nstd_core_str_parse_i32, i32);
+nstd_str_parse_int!(nstd_core_str_parse_u32, u32);
+nstd_str_parse_int!(nstd_core_str_parse_i64, i64);
+nstd_str_parse_int!(nstd_core_str_parse_u64, u64);
+nstd_str_parse_int!(nstd_core_str_parse_isize, isize);
+nstd_str_parse_int!(nstd_core_str_parse_usize, usize);
+
+/// Generates float parsing functions.
+macro_rules! nstd_str_parse_float {
+    ($name: ident, $type: ty) => {
+        ///
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(
+            str: &NSTDStr,
+            options: &NSTDStrParseOptions,
+            error: &mut NSTDStrParseError,
+        ) -> $type {
+            let mut buffer = [0; FLOAT_BUFFER_LEN];
+            let result = float_text(str, options, &mut buffer).and_then(|text| {
+                let invalid = (NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_INVALID_DIGIT, 0);
+                let value = text.parse::<$type>().map_err(|_| invalid)?;
+                // Only a finite number that was too large to represent parses to infinity.
+                let is_finite = text.as_bytes().iter().any(u8::is_ascii_digit);
+                match value.is_infinite() && is_finite {
+                    true if value < 0.0 => {
+                        Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_NEG_OVERFLOW, 0))
+                    }
+                    true => Err((NSTDStrParseErrorKind::NSTD_STR_PARSE_ERROR_POS_OVERFLOW, 0)),
+                    false => Ok(value),
+                }
+            });
+            parse_result(result, error)
+        }
+    };
+}
+nstd_str_parse_float!(nstd_core_str_parse_f32, f32);
+nstd_str_parse_float!(nstd_core_str_parse_f64, f64);
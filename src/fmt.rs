@@ -0,0 +1,705 @@
+//! printf-style string formatting.
+//!
+//! # Format specifications
+//!
+//! Each `%` in a format string starts a specification of the form
+//! `%[flags][width][.precision][@radix]conversion`, "%%" writes a single `%`.
+//!
+//! ## Flags
+//!
+//! - `-` - Left align the output within the width.
+//!
+//! - `^` - Center the output within the width, extra fill goes to the right.
+//!
+//! - `+` - Always write a sign for signed numbers.
+//!
+//! - ` ` - Write a space in place of the sign for positive signed numbers.
+//!
+//! - `#` - Alternate form, prefixes hex with "0x", binary with "0b" and octal with "0o" and keeps
+//!   the decimal point and trailing zeros of floats.
+//!
+//! - `0` - Pad numbers with zeros after the sign and prefix, ignored if the output is aligned or
+//!   an integer has a precision.
+//!
+//! - `'c` - Pad with the character `c` instead of spaces.
+//!
+//! ## Width, precision and radix
+//!
+//! The width is the minimum number of characters to write. For integers the precision is the
+//! minimum number of digits, for floats it is the number of digits after the decimal point
+//! (significant digits for `g`) and for strings it is the maximum number of characters. The
+//! width, precision and radix can be `*` to take them from the next argument, a negative width
+//! left aligns and a negative precision is ignored. Widths and precisions greater than
+//! `NSTD_FMT_MAX_COUNT` are errors.
+//!
+//! ## Conversions
+//!
+//! - `d`, `i` - Decimal integer.
+//!
+//! - `u` - Decimal integer without a sign, negative integers are reinterpreted as unsigned 64-bit
+//!   integers, so -1 is written as 18446744073709551615.
+//!
+//! - `x`, `X` - Hexadecimal integer.
+//!
+//! - `o` - Octal integer.
+//!
+//! - `b` - Binary integer.
+//!
+//! - `r`, `R` - Integer in the radix given by `@radix`, which must be within 2-36.
+//!
+//! - `f`, `F` - Float in fixed notation.
+//!
+//! - `e`, `E` - Float in exponent notation.
+//!
+//! - `g`, `G` - Float in whichever of fixed or exponent notation is shorter.
+//!
+//! - `s` - String slice or C string.
+//!
+//! - `c` - Unicode character.
+//!
+//! - `p` - Pointer, in hexadecimal.
+//!
+//! Other conversions write negative integers as a sign followed by their magnitude. Upper case
+//! conversions use upper case digits, exponents, prefixes, "INF" and "NAN".
+use crate::{
+    core::{
+        def::{
+            NSTDAny, NSTDAnyConst, NSTDChar, NSTDErrorCode, NSTDFloat64, NSTDInt64, NSTDUInt64,
+            NSTDUnichar,
+        },
+        slice::NSTDSlice,
+        str::NSTDStr,
+    },
+    io::output_stream::NSTDOutputStream,
+    string::NSTDString,
+};
+use std::ffi::CStr;
+
+/// The largest width or precision that a format specification may have.
+pub const NSTD_FMT_MAX_COUNT: usize = 32767;
+
+/// Describes the type of a formatting argument.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDFormatArgKind {
+    /// A signed integer.
+    NSTD_FORMAT_ARG_KIND_INT,
+    /// An unsigned integer.
+    NSTD_FORMAT_ARG_KIND_UINT,
+    /// A float.
+    NSTD_FORMAT_ARG_KIND_FLOAT,
+    /// A string slice.
+    NSTD_FORMAT_ARG_KIND_STR,
+    /// A null terminated C string.
+    NSTD_FORMAT_ARG_KIND_CSTR,
+    /// A Unicode character.
+    NSTD_FORMAT_ARG_KIND_CHAR,
+    /// A pointer.
+    NSTD_FORMAT_ARG_KIND_PTR,
+}
+impl Default for NSTDFormatArgKind {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_FORMAT_ARG_KIND_INT
+    }
+}
+
+/// The value of a formatting argument, the active field is given by the argument's kind.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union NSTDFormatArgValue {
+    /// A signed integer.
+    pub int64: NSTDInt64,
+    /// An unsigned integer.
+    pub uint64: NSTDUInt64,
+    /// A float.
+    pub float64: NSTDFloat64,
+    /// A string slice.
+    pub str: NSTDStr,
+    /// A null terminated C string.
+    pub cstr: *const NSTDChar,
+    /// A Unicode character.
+    pub chr: NSTDUnichar,
+    /// A pointer.
+    pub ptr: NSTDAnyConst,
+}
+
+/// A tagged formatting argument.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct NSTDFormatArg {
+    /// The type of the argument.
+    pub kind: NSTDFormatArgKind,
+    /// The argument's value.
+    pub value: NSTDFormatArgValue,
+}
+
+/// Generates formatting argument constructors.
+macro_rules! nstd_fmt_arg {
+    ($name: ident, $type: ty, $kind: ident, $field: ident) => {
+        ///
+        #[inline]
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(value: $type) -> NSTDFormatArg {
+            NSTDFormatArg {
+                kind: NSTDFormatArgKind::$kind,
+                value: NSTDFormatArgValue { $field: value },
+            }
+        }
+    };
+}
+nstd_fmt_arg!(nstd_fmt_arg_int, NSTDInt64, NSTD_FORMAT_ARG_KIND_INT, int64);
+nstd_fmt_arg!(
+    nstd_fmt_arg_uint,
+    NSTDUInt64,
+    NSTD_FORMAT_ARG_KIND_UINT,
+    uint64
+);
+nstd_fmt_arg!(
+    nstd_fmt_arg_float,
+    NSTDFloat64,
+    NSTD_FORMAT_ARG_KIND_FLOAT,
+    float64
+);
+nstd_fmt_arg!(
+    nstd_fmt_arg_cstr,
+    *const NSTDChar,
+    NSTD_FORMAT_ARG_KIND_CSTR,
+    cstr
+);
+nstd_fmt_arg!(
+    nstd_fmt_arg_char,
+    NSTDUnichar,
+    NSTD_FORMAT_ARG_KIND_CHAR,
+    chr
+);
+nstd_fmt_arg!(
+    nstd_fmt_arg_ptr,
+    NSTDAnyConst,
+    NSTD_FORMAT_ARG_KIND_PTR,
+    ptr
+);
+
+/// Creates a string slice formatting argument.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice, it must outlive the argument.
+///
+/// # Returns
+///
+/// `NSTDFormatArg arg` - The formatting argument.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_fmt_arg_str(str: &NSTDStr) -> NSTDFormatArg {
+    NSTDFormatArg {
+        kind: NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_STR,
+        value: NSTDFormatArgValue { str: *str },
+    }
+}
+
+/// How a formatted value is aligned within it's width.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Align {
+    /// Fill goes after the value.
+    Left,
+    /// Fill is split between both sides of the value.
+    Center,
+}
+
+/// A parsed format specification.
+struct Spec {
+    /// The character used to pad the value to it's width.
+    fill: char,
+    /// Where the fill goes, `None` to right align.
+    align: Option<Align>,
+    /// Always write a sign for signed numbers.
+    plus: bool,
+    /// Write a space in place of the sign for positive signed numbers.
+    space: bool,
+    /// Use the alternate form.
+    alt: bool,
+    /// Pad numbers with zeros.
+    zero: bool,
+    /// The minimum number of characters to write.
+    width: usize,
+    /// The precision, if one was given.
+    precision: Option<usize>,
+    /// The radix for the `r` conversions.
+    radix: Option<u32>,
+    /// The conversion character.
+    conversion: u8,
+}
+
+/// A value that has been converted to text but not yet padded.
+struct Converted {
+    /// The sign and radix prefix.
+    prefix: String,
+    /// The digits or text.
+    body: String,
+    /// Whether zero padding may be inserted between the prefix and the body.
+    numeric: bool,
+}
+
+/// Walks a format string, consuming arguments as specifications are parsed.
+struct Formatter<'a> {
+    /// The format string.
+    format: &'a str,
+    /// The byte offset of the next character to read from `format`.
+    pos: usize,
+    /// The arguments.
+    args: std::slice::Iter<'a, NSTDFormatArg>,
+}
+impl Formatter<'_> {
+    /// Reads the next byte of the format string if it is `byte`.
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.format.as_bytes().get(self.pos) == Some(&byte);
+        self.pos += usize::from(found);
+        found
+    }
+
+    /// Reads a decimal number or `*` from the format string, `None` if neither are present.
+    unsafe fn count(&mut self) -> Result<Option<i128>, ()> {
+        if self.eat(b'*') {
+            let arg = self.args.next().ok_or(())?;
+            return match arg.kind {
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_INT => Ok(Some(arg.value.int64.into())),
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_UINT => Ok(Some(arg.value.uint64.into())),
+                _ => Err(()),
+            };
+        }
+        let digits = &self.format.as_bytes()[self.pos..];
+        let len = digits
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return Ok(None);
+        }
+        self.pos += len;
+        let digits = &self.format[self.pos - len..self.pos];
+        digits
+            .parse::<u64>()
+            .map(|count| Some(count.into()))
+            .map_err(|_| ())
+    }
+
+    /// Parses a format specification, `pos` must be just past it's `%`.
+    unsafe fn spec(&mut self) -> Result<Spec, ()> {
+        let mut spec = Spec {
+            fill: ' ',
+            align: None,
+            plus: false,
+            space: false,
+            alt: false,
+            zero: false,
+            width: 0,
+            precision: None,
+            radix: None,
+            conversion: 0,
+        };
+        loop {
+            match self.format.as_bytes().get(self.pos) {
+                Some(b'-') => spec.align = Some(Align::Left),
+                Some(b'^') => spec.align = Some(Align::Center),
+                Some(b'+') => spec.plus = true,
+                Some(b' ') => spec.space = true,
+                Some(b'#') => spec.alt = true,
+                Some(b'0') => spec.zero = true,
+                Some(b'\'') => {
+                    let fill = self.format[self.pos + 1..].chars().next().ok_or(())?;
+                    spec.fill = fill;
+                    self.pos += fill.len_utf8();
+                }
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        if let Some(width) = self.count()? {
+            if width < 0 {
+                spec.align = Some(Align::Left);
+            }
+            spec.width = max_count(width.unsigned_abs())?;
+        }
+        if self.eat(b'.') {
+            let precision = self.count()?.unwrap_or(0);
+            if precision >= 0 {
+                spec.precision = Some(max_count(precision.unsigned_abs())?);
+            }
+        }
+        if self.eat(b'@') {
+            match self.count()? {
+                Some(radix @ 2..=36) => spec.radix = Some(radix as u32),
+                _ => return Err(()),
+            }
+        }
+        spec.conversion = *self.format.as_bytes().get(self.pos).ok_or(())?;
+        self.pos += 1;
+        // A radix must be given with, and only with, the `r` conversions.
+        match matches!(spec.conversion, b'r' | b'R') == spec.radix.is_some() {
+            true => Ok(spec),
+            false => Err(()),
+        }
+    }
+
+    /// Formats the whole format string.
+    unsafe fn run(&mut self) -> Result<String, usize> {
+        let mut out = String::with_capacity(self.format.len());
+        while let Some(index) = self.format[self.pos..].find('%') {
+            let start = self.pos + index;
+            out.push_str(&self.format[self.pos..start]);
+            self.pos = start + 1;
+            if self.eat(b'%') {
+                out.push('%');
+                continue;
+            }
+            let spec = self.spec().map_err(|_| start)?;
+            let arg = self.args.next().ok_or(start)?;
+            let converted = convert(&spec, arg).ok_or(start)?;
+            pad(&mut out, &spec, converted);
+        }
+        out.push_str(&self.format[self.pos..]);
+        Ok(out)
+    }
+}
+
+/// Checks that a width or precision is no greater than `NSTD_FMT_MAX_COUNT`.
+fn max_count(count: u128) -> Result<usize, ()> {
+    match usize::try_from(count) {
+        Ok(count) if count <= NSTD_FMT_MAX_COUNT => Ok(count),
+        _ => Err(()),
+    }
+}
+
+/// Returns the sign to write for a number.
+fn sign(spec: &Spec, negative: bool) -> &'static str {
+    match negative {
+        true => "-",
+        false if spec.plus => "+",
+        false if spec.space => " ",
+        false => "",
+    }
+}
+
+/// Converts an integer's magnitude to digits in `radix`.
+fn digits(mut magnitude: u64, radix: u32, upper: bool, precision: Option<usize>) -> String {
+    let mut digits = Vec::new();
+    while magnitude > 0 {
+        let digit = std::char::from_digit((magnitude % u64::from(radix)) as u32, radix);
+        let digit = digit.unwrap_or_default();
+        digits.push(match upper {
+            true => digit.to_ascii_uppercase(),
+            false => digit,
+        });
+        magnitude /= u64::from(radix);
+    }
+    // Zero is written as "0" unless the precision is explicitly 0.
+    let min = precision.unwrap_or(1);
+    digits.resize(digits.len().max(min), '0');
+    digits.iter().rev().collect()
+}
+
+/// Formats a float in exponent notation with `precision` digits after the decimal point.
+fn exponent(value: f64, precision: usize, upper: bool) -> String {
+    let formatted = format!("{:.*e}", precision, value);
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let exponent = exponent.parse::<i32>().unwrap_or_default();
+    let e = if upper { 'E' } else { 'e' };
+    let sign = if exponent < 0 { '-' } else { '+' };
+    format!("{}{}{}{:02}", mantissa, e, sign, exponent.unsigned_abs())
+}
+
+/// Removes trailing zeros after the decimal point of `digits`, along with a bare decimal point.
+fn strip_zeros(digits: &str) -> &str {
+    match digits.contains('.') {
+        true => digits.trim_end_matches('0').trim_end_matches('.'),
+        false => digits,
+    }
+}
+
+/// Formats the magnitude of a finite float according to `spec`.
+fn float(spec: &Spec, value: f64) -> String {
+    let upper = spec.conversion.is_ascii_uppercase();
+    let precision = spec.precision.unwrap_or(6);
+    let mut body = match spec.conversion.to_ascii_lowercase() {
+        b'f' => format!("{:.*}", precision, value),
+        b'e' => exponent(value, precision, upper),
+        _ => {
+            let precision = precision.max(1);
+            let formatted = exponent(value, precision - 1, upper);
+            let exp = formatted
+                .rsplit(['e', 'E'])
+                .next()
+                .and_then(|exp| exp.parse().ok());
+            let exp: i64 = exp.unwrap_or_default();
+            let body = match exp < -4 || exp >= precision as i64 {
+                true => formatted,
+                false => format!("{:.*}", (precision as i64 - 1 - exp) as usize, value),
+            };
+            match spec.alt {
+                true => body,
+                false => match body.split_once(['e', 'E']) {
+                    Some((mantissa, exp)) => {
+                        let e = if upper { 'E' } else { 'e' };
+                        format!("{}{}{}", strip_zeros(mantissa), e, exp)
+                    }
+                    None => strip_zeros(&body).into(),
+                },
+            }
+        }
+    };
+    if spec.alt && !body.contains('.') {
+        let at = body.find(['e', 'E']).unwrap_or(body.len());
+        body.insert(at, '.');
+    }
+    body
+}
+
+/// Converts an argument according to a format specification, `None` if they don't match.
+unsafe fn convert(spec: &Spec, arg: &NSTDFormatArg) -> Option<Converted> {
+    let upper = spec.conversion.is_ascii_uppercase();
+    match spec.conversion {
+        b'd' | b'i' | b'u' | b'x' | b'X' | b'o' | b'b' | b'r' | b'R' => {
+            let (negative, magnitude) = match arg.kind {
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_INT if spec.conversion == b'u' => {
+                    (false, arg.value.int64 as u64)
+                }
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_INT => {
+                    (arg.value.int64 < 0, arg.value.int64.unsigned_abs())
+                }
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_UINT => (false, arg.value.uint64),
+                _ => return None,
+            };
+            let (radix, alt_prefix) = match spec.conversion {
+                b'x' => (16, "0x"),
+                b'X' => (16, "0X"),
+                b'o' => (8, "0o"),
+                b'b' => (2, "0b"),
+                b'r' | b'R' => (spec.radix.unwrap_or(10), ""),
+                _ => (10, ""),
+            };
+            let mut prefix = match spec.conversion {
+                b'u' => String::new(),
+                _ => sign(spec, negative).into(),
+            };
+            if spec.alt {
+                prefix.push_str(alt_prefix);
+            }
+            Some(Converted {
+                prefix,
+                body: digits(magnitude, radix, upper, spec.precision),
+                numeric: spec.precision.is_none(),
+            })
+        }
+        b'f' | b'F' | b'e' | b'E' | b'g' | b'G' => {
+            let value = match arg.kind {
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_FLOAT => arg.value.float64,
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_INT => arg.value.int64 as f64,
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_UINT => arg.value.uint64 as f64,
+                _ => return None,
+            };
+            if value.is_nan() {
+                let body = if upper { "NAN" } else { "nan" };
+                return Some(Converted {
+                    prefix: String::new(),
+                    body: body.into(),
+                    numeric: false,
+                });
+            }
+            let prefix = sign(spec, value.is_sign_negative()).into();
+            let body = match value.is_infinite() {
+                true => (if upper { "INF" } else { "inf" }).into(),
+                false => float(spec, value.abs()),
+            };
+            Some(Converted {
+                prefix,
+                numeric: value.is_finite(),
+                body,
+            })
+        }
+        b's' => {
+            let text = match arg.kind {
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_STR => {
+                    std::str::from_utf8(arg.value.str.bytes.as_byte_slice()).ok()?
+                }
+                NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_CSTR if !arg.value.cstr.is_null() => {
+                    CStr::from_ptr(arg.value.cstr).to_str().ok()?
+                }
+                _ => return None,
+            };
+            let body = match spec.precision {
+                Some(precision) => text.chars().take(precision).collect(),
+                None => text.into(),
+            };
+            Some(Converted {
+                prefix: String::new(),
+                body,
+                numeric: false,
+            })
+        }
+        b'c' => match arg.kind {
+            NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_CHAR => Some(Converted {
+                prefix: String::new(),
+                body: char::from_u32(arg.value.chr)?.into(),
+                numeric: false,
+            }),
+            _ => None,
+        },
+        b'p' => match arg.kind {
+            NSTDFormatArgKind::NSTD_FORMAT_ARG_KIND_PTR => Some(Converted {
+                prefix: "0x".into(),
+                body: digits(arg.value.ptr as usize as u64, 16, false, None),
+                numeric: true,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Pads a converted value to it's width and writes it to `out`.
+fn pad(out: &mut String, spec: &Spec, converted: Converted) {
+    let len = converted.prefix.chars().count() + converted.body.chars().count();
+    let padding = spec.width.saturating_sub(len);
+    let (before, after) = match spec.align {
+        None if spec.zero && converted.numeric => {
+            out.push_str(&converted.prefix);
+            out.extend(std::iter::repeat_n('0', padding));
+            out.push_str(&converted.body);
+            return;
+        }
+        None => (padding, 0),
+        Some(Align::Left) => (0, padding),
+        Some(Align::Center) => (padding / 2, padding - padding / 2),
+    };
+    out.extend(std::iter::repeat_n(spec.fill, before));
+    out.push_str(&converted.prefix);
+    out.push_str(&converted.body);
+    out.extend(std::iter::repeat_n(spec.fill, after));
+}
+
+/// Formats `format` with `args`, setting `error_offset` to -1/usize::MAX on success.
+unsafe fn format(
+    format: &NSTDStr,
+    args: &NSTDSlice,
+    error_offset: &mut usize,
+) -> Result<String, NSTDErrorCode> {
+    *error_offset = usize::MAX;
+    let format = match std::str::from_utf8(format.bytes.as_byte_slice()) {
+        Ok(format) => format,
+        Err(err) => {
+            *error_offset = err.valid_up_to();
+            return Err(1);
+        }
+    };
+    if args.ptr.size != std::mem::size_of::<NSTDFormatArg>() {
+        return Err(1);
+    }
+    let args = std::slice::from_raw_parts(args.ptr.raw as *const NSTDFormatArg, args.size);
+    let mut formatter = Formatter {
+        format,
+        pos: 0,
+        args: args.iter(),
+    };
+    formatter.run().map_err(|offset| {
+        *error_offset = offset;
+        1
+    })
+}
+
+/// Formats arguments onto the end of a string.
+///
+/// # Note
+///
+/// See the module documentation for the format specification syntax. `string` is left unchanged
+/// on error.
+///
+/// # Parameters
+///
+/// - `NSTDString *const string` - The string to append the formatted text to.
+///
+/// - `const NSTDStr *const format` - The format string.
+///
+/// - `const NSTDSlice *const args` - A slice of `NSTDFormatArg`.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the invalid UTF-8 sequence or
+///   format specification in `format` on error, -1/usize::MAX on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_fmt_format(
+    string: &mut NSTDString,
+    format: &NSTDStr,
+    args: &NSTDSlice,
+    error_offset: &mut usize,
+) -> NSTDErrorCode {
+    let formatted = match self::format(format, args, error_offset) {
+        Ok(formatted) => formatted,
+        Err(errc) => return errc,
+    };
+    let bytes =
+        crate::core::slice::nstd_core_slice_new(formatted.len(), 1, formatted.as_ptr() as _);
+    let len = string.bytes.size;
+    let errc = crate::vec::nstd_vec_extend(&mut string.bytes, &bytes);
+    if errc != 0 {
+        crate::vec::nstd_vec_truncate(&mut string.bytes, len);
+    }
+    errc
+}
+
+/// Formats arguments and writes them to an output stream.
+///
+/// # Note
+///
+/// See the module documentation for the format specification syntax. The formatted text is
+/// written with a single call to the stream's `write` function, nothing is written on error.
+///
+/// # Parameters
+///
+/// - `NSTDOutputStream *const stream` - The stream to write the formatted text to.
+///
+/// - `const NSTDAny owner` - The object that owns `stream`, such as an `NSTDFile`. This is passed
+///   to the stream's write function as `this`.
+///
+/// - `const NSTDStr *const format` - The format string.
+///
+/// - `const NSTDSlice *const args` - A slice of `NSTDFormatArg`.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the invalid UTF-8 sequence or
+///   format specification in `format` on error, -1/usize::MAX on success.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error, including errors that the stream sets while writing.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_fmt_write(
+    stream: &mut NSTDOutputStream,
+    owner: NSTDAny,
+    format: &NSTDStr,
+    args: &NSTDSlice,
+    error_offset: &mut usize,
+) -> NSTDErrorCode {
+    let formatted = match self::format(format, args, error_offset) {
+        Ok(formatted) => formatted,
+        Err(errc) => return errc,
+    };
+    match stream.write {
+        Some(write) => {
+            let bytes = crate::core::slice::nstd_core_slice_new(
+                formatted.len(),
+                1,
+                formatted.as_ptr() as _,
+            );
+            // Report only the error set by this write, keeping any error the stream already had.
+            let prev_errc = std::mem::replace(&mut stream.stream.errc, 0);
+            write(owner, &bytes);
+            let errc = stream.stream.errc;
+            stream.stream.errc |= prev_errc;
+            errc
+        }
+        None => 1,
+    }
+}
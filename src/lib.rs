@@ -20,6 +20,9 @@ pub mod env;
 #[cfg(feature = "nstd_events")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_events")))]
 pub mod events;
+#[cfg(feature = "nstd_fmt")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_fmt")))]
+pub mod fmt;
 #[cfg(feature = "nstd_fs")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_fs")))]
 pub mod fs;
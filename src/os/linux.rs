@@ -2,3 +2,6 @@
 #[cfg(feature = "nstd_os_alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_os_alloc")))]
 pub mod alloc;
+#[cfg(feature = "nstd_os_mem")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "nstd_os_mem")))]
+pub mod mem;
@@ -68,3 +68,99 @@ pub unsafe extern "C" fn nstd_os_linux_alloc_deallocate(ptr: &mut NSTDAny) {
     libc::free(*ptr);
     *ptr = std::ptr::null_mut();
 }
+
+/// Allocates a block of memory on the heap with a specific alignment.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the heap block of memory, null on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_alloc_allocate_aligned(
+    size: usize,
+    align: usize,
+) -> NSTDAny {
+    // `posix_memalign` requires the alignment to be a multiple of the size of a pointer.
+    let align = align.max(std::mem::size_of::<NSTDAny>());
+    let mut ptr = std::ptr::null_mut();
+    match libc::posix_memalign(&mut ptr, align, size) {
+        0 => ptr,
+        _ => std::ptr::null_mut(),
+    }
+}
+
+/// Allocates a zero-initialized block of memory on the heap with a specific alignment.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to allocate.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the heap block of memory, null on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_alloc_allocate_zeroed_aligned(
+    size: usize,
+    align: usize,
+) -> NSTDAny {
+    let ptr = nstd_os_linux_alloc_allocate_aligned(size, align);
+    if !ptr.is_null() {
+        libc::memset(ptr, 0, size);
+    }
+    ptr
+}
+
+/// Reallocates a memory block with a new size, keeping it's alignment.
+///
+/// # Note
+///
+/// The memory block is always moved to a new allocation, `ptr` is left untouched on error.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the pointer to the memory block to reallocate.
+///
+/// - `const NSTDUSize size` - The current number of bytes in the memory block.
+///
+/// - `const NSTDUSize new_size` - The number of bytes the new memory block will have.
+///
+/// - `const NSTDUSize align` - The alignment of the memory block, must be a power of two.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_alloc_reallocate_aligned(
+    ptr: &mut NSTDAny,
+    size: usize,
+    new_size: usize,
+    align: usize,
+) -> NSTDErrorCode {
+    // `realloc` does not preserve alignment.
+    let new_ptr = nstd_os_linux_alloc_allocate_aligned(new_size, align);
+    if new_ptr.is_null() {
+        return 1;
+    }
+    libc::memcpy(new_ptr, *ptr, size.min(new_size));
+    nstd_os_linux_alloc_deallocate_aligned(ptr);
+    *ptr = new_ptr;
+    0
+}
+
+/// Deallocates a block of memory that was allocated with a specific alignment.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - Pointer to the block of memory.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_alloc_deallocate_aligned(ptr: &mut NSTDAny) {
+    nstd_os_linux_alloc_deallocate(ptr);
+}
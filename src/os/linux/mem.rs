@@ -0,0 +1,292 @@
+//! Virtual memory management for Linux.
+use crate::core::def::{NSTDAny, NSTDErrorCode};
+
+/// A set of memory protection flags.
+pub type NSTDOSLinuxMemProtection = u32;
+
+/// The memory can't be accessed.
+pub const NSTD_OS_LINUX_MEM_PROTECTION_NONE: NSTDOSLinuxMemProtection = 0;
+/// The memory can be read.
+pub const NSTD_OS_LINUX_MEM_PROTECTION_READ: NSTDOSLinuxMemProtection = 1;
+/// The memory can be written to.
+pub const NSTD_OS_LINUX_MEM_PROTECTION_WRITE: NSTDOSLinuxMemProtection = 1 << 1;
+/// The memory can be executed.
+pub const NSTD_OS_LINUX_MEM_PROTECTION_EXECUTE: NSTDOSLinuxMemProtection = 1 << 2;
+
+/// Describes how a range of memory is expected to be used.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDOSLinuxMemAdvice {
+    /// No special treatment.
+    NSTD_OS_LINUX_MEM_ADVICE_NORMAL,
+    /// The memory will be accessed in a random order.
+    NSTD_OS_LINUX_MEM_ADVICE_RANDOM,
+    /// The memory will be accessed in sequential order.
+    NSTD_OS_LINUX_MEM_ADVICE_SEQUENTIAL,
+    /// The memory will be accessed soon.
+    NSTD_OS_LINUX_MEM_ADVICE_WILL_NEED,
+    /// The memory won't be accessed soon, it's contents are discarded.
+    NSTD_OS_LINUX_MEM_ADVICE_DONT_NEED,
+    /// The memory's contents are no longer needed and may be discarded lazily.
+    NSTD_OS_LINUX_MEM_ADVICE_FREE,
+}
+impl Default for NSTDOSLinuxMemAdvice {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_OS_LINUX_MEM_ADVICE_NORMAL
+    }
+}
+
+/// Converts a set of protection flags into `PROT_*` flags.
+fn prot_flags(protection: NSTDOSLinuxMemProtection) -> libc::c_int {
+    let mut flags = libc::PROT_NONE;
+    if protection & NSTD_OS_LINUX_MEM_PROTECTION_READ != 0 {
+        flags |= libc::PROT_READ;
+    }
+    if protection & NSTD_OS_LINUX_MEM_PROTECTION_WRITE != 0 {
+        flags |= libc::PROT_WRITE;
+    }
+    if protection & NSTD_OS_LINUX_MEM_PROTECTION_EXECUTE != 0 {
+        flags |= libc::PROT_EXEC;
+    }
+    flags
+}
+
+/// Converts a libc return value into an error code.
+#[inline]
+fn errc(result: libc::c_int) -> NSTDErrorCode {
+    (result != 0) as NSTDErrorCode
+}
+
+/// Rounds `size` up to a multiple of the page size, `None` on overflow.
+#[inline]
+unsafe fn round_to_page(size: usize) -> Option<usize> {
+    let page_size = nstd_os_linux_mem_page_size();
+    Some(size.checked_add(page_size - 1)? & !(page_size - 1))
+}
+
+/// Returns the number of bytes committed for a guarded allocation of `size` bytes and the total
+/// number of bytes including it's guard pages, `None` on overflow.
+unsafe fn guarded_sizes(size: usize) -> Option<(usize, usize)> {
+    let page_size = nstd_os_linux_mem_page_size();
+    let committed = round_to_page(size)?;
+    Some((committed, committed.checked_add(page_size.checked_mul(2)?)?))
+}
+
+/// Returns the size of a page of memory in bytes.
+///
+/// # Returns
+///
+/// `NSTDUSize page_size` - The size of a page of memory.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_page_size() -> usize {
+    libc::sysconf(libc::_SC_PAGESIZE) as usize
+}
+
+/// Reserves a range of virtual memory without making it accessible.
+///
+/// # Note
+///
+/// The memory must be committed before it is accessed.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to reserve, rounded up to the page size.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A page aligned pointer to the reserved memory, null on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_reserve(size: usize) -> NSTDAny {
+    const FLAGS: libc::c_int = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_NORESERVE;
+    let ptr = libc::mmap(std::ptr::null_mut(), size, libc::PROT_NONE, FLAGS, -1, 0);
+    match ptr == libc::MAP_FAILED {
+        true => std::ptr::null_mut(),
+        false => ptr,
+    }
+}
+
+/// Makes a range of reserved memory readable and writable.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer into reserved memory.
+///
+/// - `const NSTDUSize size` - The number of bytes to commit.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_commit(ptr: NSTDAny, size: usize) -> NSTDErrorCode {
+    errc(libc::mprotect(
+        ptr,
+        size,
+        libc::PROT_READ | libc::PROT_WRITE,
+    ))
+}
+
+/// Returns a range of committed memory to the reserved state, discarding it's contents.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer into committed memory.
+///
+/// - `const NSTDUSize size` - The number of bytes to decommit.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_decommit(ptr: NSTDAny, size: usize) -> NSTDErrorCode {
+    match libc::madvise(ptr, size, libc::MADV_DONTNEED) {
+        0 => errc(libc::mprotect(ptr, size, libc::PROT_NONE)),
+        _ => 1,
+    }
+}
+
+/// Changes the protection of a range of memory.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer to the memory.
+///
+/// - `const NSTDUSize size` - The number of bytes to protect.
+///
+/// - `const NSTDOSLinuxMemProtection protection` - The new protection flags.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_protect(
+    ptr: NSTDAny,
+    size: usize,
+    protection: NSTDOSLinuxMemProtection,
+) -> NSTDErrorCode {
+    errc(libc::mprotect(ptr, size, prot_flags(protection)))
+}
+
+/// Tells the kernel how a range of memory is expected to be used.
+///
+/// # Parameters
+///
+/// - `const NSTDAny ptr` - A page aligned pointer to the memory.
+///
+/// - `const NSTDUSize size` - The number of bytes the advice applies to.
+///
+/// - `const NSTDOSLinuxMemAdvice advice` - The expected usage of the memory.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_advise(
+    ptr: NSTDAny,
+    size: usize,
+    advice: NSTDOSLinuxMemAdvice,
+) -> NSTDErrorCode {
+    let advice = match advice {
+        NSTDOSLinuxMemAdvice::NSTD_OS_LINUX_MEM_ADVICE_NORMAL => libc::MADV_NORMAL,
+        NSTDOSLinuxMemAdvice::NSTD_OS_LINUX_MEM_ADVICE_RANDOM => libc::MADV_RANDOM,
+        NSTDOSLinuxMemAdvice::NSTD_OS_LINUX_MEM_ADVICE_SEQUENTIAL => libc::MADV_SEQUENTIAL,
+        NSTDOSLinuxMemAdvice::NSTD_OS_LINUX_MEM_ADVICE_WILL_NEED => libc::MADV_WILLNEED,
+        NSTDOSLinuxMemAdvice::NSTD_OS_LINUX_MEM_ADVICE_DONT_NEED => libc::MADV_DONTNEED,
+        NSTDOSLinuxMemAdvice::NSTD_OS_LINUX_MEM_ADVICE_FREE => libc::MADV_FREE,
+    };
+    errc(libc::madvise(ptr, size, advice))
+}
+
+/// Releases a range of reserved or committed memory back to the operating system.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - A pointer to the memory, set to null on success.
+///
+/// - `const NSTDUSize size` - The number of bytes to release.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_release(
+    ptr: &mut NSTDAny,
+    size: usize,
+) -> NSTDErrorCode {
+    match libc::munmap(*ptr, size) {
+        0 => {
+            *ptr = std::ptr::null_mut();
+            0
+        }
+        _ => 1,
+    }
+}
+
+/// Allocates readable and writable memory surrounded by inaccessible guard pages.
+///
+/// # Note
+///
+/// Accessing a guard page raises `SIGSEGV`. The end of the memory meets the back guard page so
+/// overruns are caught immediately, which means the memory is only aligned to the largest power of
+/// two that divides `size`, up to the page size.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize size` - The number of bytes to allocate.
+///
+/// # Returns
+///
+/// `NSTDAny ptr` - A pointer to the memory, null on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_allocate_guarded(size: usize) -> NSTDAny {
+    let page_size = nstd_os_linux_mem_page_size();
+    let (committed, total) = match guarded_sizes(size) {
+        Some(sizes) => sizes,
+        None => return std::ptr::null_mut(),
+    };
+    let mut base = nstd_os_linux_mem_reserve(total);
+    if base.is_null() {
+        return base;
+    }
+    let pages = base.cast::<u8>().add(page_size);
+    if nstd_os_linux_mem_commit(pages.cast(), committed) != 0 {
+        nstd_os_linux_mem_release(&mut base, total);
+        return std::ptr::null_mut();
+    }
+    pages.add(committed - size).cast()
+}
+
+/// Frees memory allocated with `nstd_os_linux_mem_allocate_guarded`.
+///
+/// # Parameters
+///
+/// - `NSTDAny *const ptr` - A pointer to the memory, set to null on success.
+///
+/// - `const NSTDUSize size` - The number of bytes that were allocated.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_os_linux_mem_free_guarded(
+    ptr: &mut NSTDAny,
+    size: usize,
+) -> NSTDErrorCode {
+    let page_size = nstd_os_linux_mem_page_size();
+    let (committed, total) = match guarded_sizes(size) {
+        Some(sizes) => sizes,
+        None => return 1,
+    };
+    let mut base = (*ptr).cast::<u8>().sub(committed - size + page_size).cast();
+    match nstd_os_linux_mem_release(&mut base, total) {
+        0 => {
+            *ptr = std::ptr::null_mut();
+            0
+        }
+        errc => errc,
+    }
+}
@@ -1,13 +1,15 @@
 //! Dynamically sized UTF-8 encoded string.
 use crate::{
+    alloc::allocator::NSTDAllocator,
     core::{
-        def::{NSTDChar, NSTDErrorCode, NSTDUnichar},
-        slice::NSTDSlice,
-        str::NSTDStr,
+        def::{NSTDBool, NSTDChar, NSTDChar16, NSTDChar32, NSTDErrorCode, NSTDUInt32, NSTDUnichar},
+        platform::NSTDCPUEndian,
+        slice::{nstd_core_slice_new, NSTDSlice},
+        str::{nstd_core_str_split_next, NSTDStr, NSTDStrCaseLocale, NSTDStrSplit},
     },
     vec::NSTDVec,
 };
-use std::{ffi::CStr, ptr::addr_of};
+use std::{borrow::Cow, ffi::CStr, ptr::addr_of};
 
 /// Represents a dynamic-sized array of UTF-8 chars.
 #[repr(C)]
@@ -25,6 +27,50 @@ impl<T: Copy> From<&[T]> for NSTDString {
     }
 }
 
+/// The largest precision that a float can be converted to a string with.
+pub const NSTD_STRING_FLOAT_MAX_PRECISION: usize = 32767;
+
+/// Describes how a float is converted to a string.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDStringFloatFormat {
+    /// Fixed notation with a set number of digits after the decimal point, such as "1234.50".
+    NSTD_STRING_FLOAT_FORMAT_FIXED,
+    /// Scientific notation with a set number of digits after the decimal point, such as "1.23e3".
+    NSTD_STRING_FLOAT_FORMAT_SCIENTIFIC,
+    /// The fewest digits that parse back to the same float, in scientific notation for very large
+    /// or small magnitudes.
+    NSTD_STRING_FLOAT_FORMAT_SHORTEST,
+}
+impl Default for NSTDStringFloatFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_STRING_FLOAT_FORMAT_SHORTEST
+    }
+}
+
+/// A Unicode normalization form.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NSTDStringNormalForm {
+    /// Canonical decomposition followed by canonical composition.
+    NSTD_STRING_NORMAL_FORM_NFC,
+    /// Canonical decomposition.
+    NSTD_STRING_NORMAL_FORM_NFD,
+    /// Compatibility decomposition followed by canonical composition.
+    NSTD_STRING_NORMAL_FORM_NFKC,
+    /// Compatibility decomposition.
+    NSTD_STRING_NORMAL_FORM_NFKD,
+}
+impl Default for NSTDStringNormalForm {
+    #[inline]
+    fn default() -> Self {
+        Self::NSTD_STRING_NORMAL_FORM_NFC
+    }
+}
+
 /// Creates a new `NSTDString` instance.
 ///
 /// # Returns
@@ -38,6 +84,23 @@ pub unsafe extern "C" fn nstd_string_new() -> NSTDString {
     NSTDString { bytes }
 }
 
+/// Creates a new `NSTDString` instance that allocates it's memory with `allocator`.
+///
+/// # Parameters
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_new_in(allocator: *mut NSTDAllocator) -> NSTDString {
+    const BYTE_SIZE: usize = std::mem::size_of::<u8>();
+    let bytes = crate::vec::nstd_vec_new_in(BYTE_SIZE, allocator);
+    NSTDString { bytes }
+}
+
 /// Creates an `NSTDString` from existing data.
 ///
 /// # Parameters
@@ -152,9 +215,15 @@ pub unsafe extern "C" fn nstd_string_push(
     if let Some(chr) = char::from_u32(chr) {
         let mut bytes = [0u8; 4];
         chr.encode_utf8(&mut bytes);
+        let len = string.bytes.size;
         for i in 0..chr.len_utf8() {
             let byteptr = addr_of!(bytes[i]).cast();
-            crate::vec::nstd_vec_push(&mut string.bytes, byteptr);
+            let errc = crate::vec::nstd_vec_push(&mut string.bytes, byteptr);
+            // Don't leave part of a character in the string.
+            if errc != 0 {
+                string.bytes.size = len;
+                return errc;
+            }
         }
         return 0;
     }
@@ -201,6 +270,874 @@ pub unsafe extern "C" fn nstd_string_extend(string: &mut NSTDString, chars: &NST
     }
 }
 
+/// Applies the Turkic lowercase mappings that differ from the default ones.
+fn turkic_lowercase_specials(str: &str) -> String {
+    let mut mapped = String::with_capacity(str.len());
+    let mut chars = str.chars().peekable();
+    while let Some(chr) = chars.next() {
+        mapped.push(match chr {
+            // A combining dot above an `I` makes it a dotted `i`.
+            'I' if chars.next_if_eq(&'\u{0307}').is_some() => 'i',
+            'I' => 'ı',
+            'İ' => 'i',
+            _ => chr,
+        });
+    }
+    mapped
+}
+
+/// Lowercases `str` following the rules of `locale`.
+fn lowercase(str: &str, locale: NSTDStrCaseLocale) -> String {
+    match locale {
+        NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_DEFAULT => str.to_lowercase(),
+        NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_TURKIC => {
+            turkic_lowercase_specials(str).to_lowercase()
+        }
+    }
+}
+
+/// Uppercases `str` following the rules of `locale`.
+fn uppercase(str: &str, locale: NSTDStrCaseLocale) -> String {
+    match locale {
+        NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_DEFAULT => str.to_uppercase(),
+        NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_TURKIC => str.replace('i', "İ").to_uppercase(),
+    }
+}
+
+/// Pushes the titlecase form of `chr` onto `string`.
+fn push_titlecase(string: &mut String, chr: char, locale: NSTDStrCaseLocale) {
+    let special = match chr {
+        'i' if locale == NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_TURKIC => "İ",
+        // Digraphs have a dedicated titlecase form.
+        '\u{01C4}'..='\u{01C6}' => "\u{01C5}",
+        '\u{01C7}'..='\u{01C9}' => "\u{01C8}",
+        '\u{01CA}'..='\u{01CC}' => "\u{01CB}",
+        '\u{01F1}'..='\u{01F3}' => "\u{01F2}",
+        // Greek letters with a ypogegrammeni keep it when titlecased.
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            string.extend(char::from_u32(chr as u32 + 8));
+            return;
+        }
+        '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}' => {
+            string.push(chr);
+            return;
+        }
+        '\u{1FB3}' | '\u{1FBC}' => "\u{1FBC}",
+        '\u{1FC3}' | '\u{1FCC}' => "\u{1FCC}",
+        '\u{1FF3}' | '\u{1FFC}' => "\u{1FFC}",
+        '\u{1FB2}' => "\u{1FBA}\u{0345}",
+        '\u{1FB4}' => "\u{0386}\u{0345}",
+        '\u{1FC2}' => "\u{1FCA}\u{0345}",
+        '\u{1FC4}' => "\u{0389}\u{0345}",
+        '\u{1FF2}' => "\u{1FFA}\u{0345}",
+        '\u{1FF4}' => "\u{038F}\u{0345}",
+        '\u{1FB7}' => "\u{0391}\u{0342}\u{0345}",
+        '\u{1FC7}' => "\u{0397}\u{0342}\u{0345}",
+        '\u{1FF7}' => "\u{03A9}\u{0342}\u{0345}",
+        _ => {
+            // Only the first letter of an expansion such as `ß` => "SS" stays uppercase.
+            let mut upper = chr.to_uppercase();
+            string.extend(upper.next());
+            string.extend(upper.flat_map(char::to_lowercase));
+            return;
+        }
+    };
+    string.push_str(special);
+}
+
+/// Checks if `chr` is part of a word.
+fn is_word_char(chr: char) -> bool {
+    chr.is_alphanumeric()
+        || matches!(chr,
+            '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+        )
+}
+
+/// Titlecases `str` following the rules of `locale`.
+fn titlecase(str: &str, locale: NSTDStrCaseLocale) -> String {
+    let str = match locale {
+        NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_DEFAULT => str.to_string(),
+        NSTDStrCaseLocale::NSTD_STR_CASE_LOCALE_TURKIC => turkic_lowercase_specials(str),
+    };
+    // Find the start of each word, apostrophes between letters don't end a word.
+    let mut starts = Vec::new();
+    let mut in_word = false;
+    let mut chars = str.char_indices().peekable();
+    while let Some((i, chr)) = chars.next() {
+        match is_word_char(chr) {
+            true if !in_word => {
+                starts.push(i);
+                in_word = true;
+            }
+            true => {}
+            false => {
+                let is_apostrophe = matches!(chr, '\'' | '\u{2019}');
+                let next_is_letter = chars.peek().is_some_and(|&(_, next)| next.is_alphabetic());
+                in_word &= is_apostrophe && next_is_letter;
+            }
+        }
+    }
+    starts.push(str.len());
+    let mut titled = String::with_capacity(str.len());
+    titled.push_str(&str[..starts[0]].to_lowercase());
+    for word in starts.windows(2) {
+        let word = &str[word[0]..word[1]];
+        let first = word.chars().next().unwrap_or_default();
+        push_titlecase(&mut titled, first, locale);
+        // Lowercase the whole word so a final sigma is detected, then skip the first letter.
+        let lowered = word.to_lowercase();
+        let first_len = first.to_lowercase().map(char::len_utf8).sum::<usize>();
+        titled.push_str(&lowered[first_len..]);
+    }
+    titled
+}
+
+/// Creates a new string from a string slice with `map` applied to it.
+unsafe fn map_str<F: FnOnce(&str) -> String>(
+    str: &NSTDStr,
+    is_err: &mut NSTDErrorCode,
+    map: F,
+) -> NSTDString {
+    match std::str::from_utf8(str.bytes.as_byte_slice()) {
+        Ok(str) => NSTDString::from(map(str).as_bytes()),
+        _ => {
+            *is_err = 1;
+            nstd_string_new()
+        }
+    }
+}
+
+/// Creates a new string with the Unicode uppercase form of a string slice.
+///
+/// # Note
+///
+/// The new string may be longer than `str`, for example "ß" becomes "SS".
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The uppercase string, empty on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_to_uppercase(
+    str: &NSTDStr,
+    locale: NSTDStrCaseLocale,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    map_str(str, is_err, |str| uppercase(str, locale))
+}
+
+/// Creates a new string with the Unicode lowercase form of a string slice.
+///
+/// # Note
+///
+/// A Greek capital sigma at the end of a word becomes a final sigma.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The lowercase string, empty on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_to_lowercase(
+    str: &NSTDStr,
+    locale: NSTDStrCaseLocale,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    map_str(str, is_err, |str| lowercase(str, locale))
+}
+
+/// Creates a new string with the Unicode titlecase form of a string slice.
+///
+/// # Note
+///
+/// The first letter of each word is titlecased and the rest of the word is lowercased. Words are
+/// runs of letters, digits and combining marks, an apostrophe between two letters doesn't end a
+/// word.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStrCaseLocale locale` - The language specific rules to use.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The titlecase string, empty on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_to_titlecase(
+    str: &NSTDStr,
+    locale: NSTDStrCaseLocale,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    map_str(str, is_err, |str| titlecase(str, locale))
+}
+
+/// Creates a new string with a string slice converted to a Unicode normalization form.
+///
+/// # Note
+///
+/// Strings that are in the same normalization form can be compared bytewise. The compatibility
+/// forms also fold formatting distinctions, so "ﬁ" becomes "fi".
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStringNormalForm form` - The normalization form.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `str` isn't valid UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The normalized string, empty on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_normalize(
+    str: &NSTDStr,
+    form: NSTDStringNormalForm,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    use unicode_normalization::UnicodeNormalization;
+    map_str(str, is_err, |str| match form {
+        NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFC => str.nfc().collect(),
+        NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFD => str.nfd().collect(),
+        NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFKC => str.nfkc().collect(),
+        NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFKD => str.nfkd().collect(),
+    })
+}
+
+/// Checks if a string slice is in a Unicode normalization form.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStringNormalForm form` - The normalization form.
+///
+/// # Returns
+///
+/// `NSTDBool is_normalized` - True if `str` is valid UTF-8 and in the normalization form.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_is_normalized(
+    str: &NSTDStr,
+    form: NSTDStringNormalForm,
+) -> NSTDBool {
+    use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd};
+    match std::str::from_utf8(str.bytes.as_byte_slice()) {
+        Ok(str) => NSTDBool::from(match form {
+            NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFC => is_nfc(str),
+            NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFD => is_nfd(str),
+            NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFKC => is_nfkc(str),
+            NSTDStringNormalForm::NSTD_STRING_NORMAL_FORM_NFKD => is_nfkd(str),
+        }),
+        _ => NSTDBool::NSTD_BOOL_FALSE,
+    }
+}
+
+/// Collects the remaining substrings of a split iterator onto the end of a vector.
+///
+/// # Note
+///
+/// The substrings are views into the string slice being split. `parts` is left unchanged on
+/// error.
+///
+/// # Parameters
+///
+/// - `NSTDStrSplit *const split` - The split iterator.
+///
+/// - `NSTDVec *const parts` - A vector of `NSTDStr` to append the substrings to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_split_collect(
+    split: &mut NSTDStrSplit,
+    parts: &mut NSTDVec,
+) -> NSTDErrorCode {
+    if parts.buffer.ptr.size != std::mem::size_of::<NSTDStr>() {
+        return 1;
+    }
+    let len = parts.size;
+    let mut part = split.str;
+    while nstd_core_str_split_next(split, &mut part) == NSTDBool::NSTD_BOOL_TRUE {
+        let errc = crate::vec::nstd_vec_push(parts, addr_of!(part).cast());
+        if errc != 0 {
+            parts.size = len;
+            return errc;
+        }
+    }
+    0
+}
+
+/// Creates a new string with every occurrence of a pattern in a string slice replaced.
+///
+/// # Note
+///
+/// An empty pattern matches at the start of `str`, between each character and at the end of
+/// `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const from` - The pattern to replace.
+///
+/// - `const NSTDStr *const to` - The replacement.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if any of the string slices aren't valid
+///   UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_replace(
+    str: &NSTDStr,
+    from: &NSTDStr,
+    to: &NSTDStr,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    nstd_string_replacen(str, from, to, usize::MAX, is_err)
+}
+
+/// Creates a new string with the first `count` occurrences of a pattern in a string slice
+/// replaced.
+///
+/// # Note
+///
+/// An empty pattern matches at the start of `str`, between each character and at the end of
+/// `str`.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDStr *const from` - The pattern to replace.
+///
+/// - `const NSTDStr *const to` - The replacement.
+///
+/// - `const NSTDUSize count` - The maximum number of occurrences to replace.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if any of the string slices aren't valid
+///   UTF-8.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_replacen(
+    str: &NSTDStr,
+    from: &NSTDStr,
+    to: &NSTDStr,
+    count: usize,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    let from = std::str::from_utf8(from.bytes.as_byte_slice());
+    let to = std::str::from_utf8(to.bytes.as_byte_slice());
+    match (from, to) {
+        (Ok(from), Ok(to)) => map_str(str, is_err, |str| str.replacen(from, to, count)),
+        _ => {
+            *is_err = 1;
+            nstd_string_new()
+        }
+    }
+}
+
+/// Creates a new string made of a string slice repeated `n` times.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDUSize n` - The number of times to repeat `str`.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if the new string's length would overflow or
+///   allocating it fails.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_repeat(
+    str: &NSTDStr,
+    n: usize,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    let bytes = str.bytes.as_byte_slice();
+    let mut repeated = Vec::new();
+    let reserved = match bytes.len().checked_mul(n) {
+        Some(len) => repeated.try_reserve_exact(len).is_ok(),
+        None => false,
+    };
+    if !reserved {
+        *is_err = 1;
+        return nstd_string_new();
+    }
+    for _ in 0..n {
+        repeated.extend_from_slice(bytes);
+    }
+    let string = NSTDString::from(repeated.as_slice());
+    if string.bytes.size != repeated.len() {
+        *is_err = 1;
+    }
+    string
+}
+
+/// Creates a new string by joining a slice of string slices with a separator.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const strs` - A slice of `NSTDStr`.
+///
+/// - `const NSTDStr *const separator` - The string slice to put between each element of `strs`.
+///
+/// - `NSTDErrorCode *const is_err` - Set to nonzero if `strs` doesn't have an element size of
+///   `sizeof(NSTDStr)`.
+///
+/// # Returns
+///
+/// `NSTDString string` - The new string, empty on error.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_join(
+    strs: &NSTDSlice,
+    separator: &NSTDStr,
+    is_err: &mut NSTDErrorCode,
+) -> NSTDString {
+    if strs.ptr.size != std::mem::size_of::<NSTDStr>() {
+        *is_err = 1;
+        return nstd_string_new();
+    }
+    let strs = std::slice::from_raw_parts(strs.ptr.raw as *const NSTDStr, strs.size);
+    let separator = separator.bytes.as_byte_slice();
+    let mut joined = Vec::new();
+    for (i, str) in strs.iter().enumerate() {
+        if i > 0 {
+            joined.extend_from_slice(separator);
+        }
+        joined.extend_from_slice(str.bytes.as_byte_slice());
+    }
+    NSTDString::from(joined.as_slice())
+}
+
+/// A UTF-16 or UTF-32 code unit.
+trait CodeUnit: Copy + Eq + From<NSTDChar16> {
+    /// Converts between native byte order and `endian`, `NSTD_CPU_ENDIAN_UNKNOWN` is native.
+    fn swap_for(self, endian: NSTDCPUEndian) -> Self;
+}
+/// Implements `CodeUnit` for a code unit type.
+macro_rules! impl_code_unit {
+    ($type: ty) => {
+        impl CodeUnit for $type {
+            #[inline]
+            fn swap_for(self, endian: NSTDCPUEndian) -> Self {
+                match endian {
+                    NSTDCPUEndian::NSTD_CPU_ENDIAN_LITTLE => self.to_le(),
+                    NSTDCPUEndian::NSTD_CPU_ENDIAN_BIG => self.to_be(),
+                    NSTDCPUEndian::NSTD_CPU_ENDIAN_UNKNOWN => self,
+                }
+            }
+        }
+    };
+}
+impl_code_unit!(NSTDChar16);
+impl_code_unit!(NSTDChar32);
+
+/// The byte order mark.
+const BOM: NSTDChar16 = 0xFEFF;
+
+/// Encodes a string slice into `vec`, replacing invalid UTF-8 with U+FFFD if `error_offset` is
+/// `None`.
+unsafe fn encode<T: CodeUnit>(
+    str: &NSTDStr,
+    endian: NSTDCPUEndian,
+    bom: NSTDBool,
+    vec: &mut NSTDVec,
+    error_offset: Option<&mut usize>,
+    encode: fn(&str) -> Vec<T>,
+) -> NSTDErrorCode {
+    let bytes = str.bytes.as_byte_slice();
+    let text = match (std::str::from_utf8(bytes), error_offset) {
+        (Ok(text), error_offset) => {
+            if let Some(error_offset) = error_offset {
+                *error_offset = usize::MAX;
+            }
+            Cow::Borrowed(text)
+        }
+        (Err(err), Some(error_offset)) => {
+            *error_offset = err.valid_up_to();
+            return 1;
+        }
+        (Err(_), None) => String::from_utf8_lossy(bytes),
+    };
+    if vec.buffer.ptr.size != std::mem::size_of::<T>() {
+        return 1;
+    }
+    let bom = (bom == NSTDBool::NSTD_BOOL_TRUE).then(|| T::from(BOM));
+    let units = bom.into_iter().chain(encode(&text));
+    let units = units.map(|unit| unit.swap_for(endian)).collect::<Vec<_>>();
+    let units = nstd_core_slice_new(units.len(), std::mem::size_of::<T>(), units.as_ptr() as _);
+    let len = vec.size;
+    let errc = crate::vec::nstd_vec_extend(vec, &units);
+    if errc != 0 {
+        crate::vec::nstd_vec_truncate(vec, len);
+    }
+    errc
+}
+
+/// Decoded characters, `None` if invalid, with the number of code units each one used.
+type DecodedChars = Vec<(Option<char>, usize)>;
+
+/// Decodes code units onto the end of `string`, replacing invalid code units with U+FFFD if
+/// `error_offset` is `None`.
+unsafe fn decode<T: CodeUnit>(
+    units: &NSTDSlice,
+    mut endian: NSTDCPUEndian,
+    string: &mut NSTDString,
+    mut error_offset: Option<&mut usize>,
+    decode: fn(&[T]) -> DecodedChars,
+) -> NSTDErrorCode {
+    if let Some(error_offset) = &mut error_offset {
+        **error_offset = usize::MAX;
+    }
+    if units.ptr.size != std::mem::size_of::<T>() {
+        return 1;
+    }
+    let units = std::slice::from_raw_parts(units.ptr.raw as *const T, units.size);
+    // A byte order mark overrides `endian`.
+    let mut bom_len = 0;
+    for bom_endian in [
+        NSTDCPUEndian::NSTD_CPU_ENDIAN_LITTLE,
+        NSTDCPUEndian::NSTD_CPU_ENDIAN_BIG,
+    ] {
+        if units.first().map(|unit| unit.swap_for(bom_endian)) == Some(T::from(BOM)) {
+            endian = bom_endian;
+            bom_len = 1;
+        }
+    }
+    let units = units[bom_len..].iter().map(|unit| unit.swap_for(endian));
+    let mut text = String::with_capacity(units.len());
+    let mut offset = bom_len;
+    for (chr, len) in decode(&units.collect::<Vec<_>>()) {
+        match (chr, &mut error_offset) {
+            (Some(chr), _) => text.push(chr),
+            (None, Some(error_offset)) => {
+                **error_offset = offset;
+                return 1;
+            }
+            (None, None) => text.push(char::REPLACEMENT_CHARACTER),
+        }
+        offset += len;
+    }
+    let bytes = nstd_core_slice_new(text.len(), 1, text.as_ptr() as _);
+    let len = string.bytes.size;
+    let errc = crate::vec::nstd_vec_extend(&mut string.bytes, &bytes);
+    if errc != 0 {
+        crate::vec::nstd_vec_truncate(&mut string.bytes, len);
+    }
+    errc
+}
+
+/// Encodes a string slice as UTF-16.
+#[inline]
+fn encode_utf16(str: &str) -> Vec<NSTDChar16> {
+    str.encode_utf16().collect()
+}
+
+/// Encodes a string slice as UTF-32.
+#[inline]
+fn encode_utf32(str: &str) -> Vec<NSTDChar32> {
+    str.chars().map(NSTDChar32::from).collect()
+}
+
+/// Decodes UTF-16 code units.
+fn decode_utf16(units: &[NSTDChar16]) -> DecodedChars {
+    char::decode_utf16(units.iter().copied())
+        .map(|chr| match chr {
+            Ok(chr) => (Some(chr), chr.len_utf16()),
+            _ => (None, 1),
+        })
+        .collect()
+}
+
+/// Decodes UTF-32 code units.
+fn decode_utf32(units: &[NSTDChar32]) -> DecodedChars {
+    units
+        .iter()
+        .map(|&unit| (char::from_u32(unit), 1))
+        .collect()
+}
+
+/// Encodes a string slice as UTF-16 onto the end of a vector.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf16` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf16` - A vector of `NSTDChar16` to append the code units to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the first invalid UTF-8
+///   sequence in `str`, -1/usize::MAX if `str` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_encode_utf16(
+    str: &NSTDStr,
+    endian: NSTDCPUEndian,
+    bom: NSTDBool,
+    utf16: &mut NSTDVec,
+    error_offset: &mut usize,
+) -> NSTDErrorCode {
+    encode(str, endian, bom, utf16, Some(error_offset), encode_utf16)
+}
+
+/// Encodes a string slice as UTF-16 onto the end of a vector, replacing invalid UTF-8 with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf16` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf16` - A vector of `NSTDChar16` to append the code units to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_encode_utf16_lossy(
+    str: &NSTDStr,
+    endian: NSTDCPUEndian,
+    bom: NSTDBool,
+    utf16: &mut NSTDVec,
+) -> NSTDErrorCode {
+    encode(str, endian, bom, utf16, None, encode_utf16)
+}
+
+/// Encodes a string slice as UTF-32 onto the end of a vector.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf32` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf32` - A vector of `NSTDChar32` to append the code units to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the byte offset of the first invalid UTF-8
+///   sequence in `str`, -1/usize::MAX if `str` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_encode_utf32(
+    str: &NSTDStr,
+    endian: NSTDCPUEndian,
+    bom: NSTDBool,
+    utf32: &mut NSTDVec,
+    error_offset: &mut usize,
+) -> NSTDErrorCode {
+    encode(str, endian, bom, utf32, Some(error_offset), encode_utf32)
+}
+
+/// Encodes a string slice as UTF-32 onto the end of a vector, replacing invalid UTF-8 with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. `utf32` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDStr *const str` - The string slice.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `const NSTDBool bom` - Whether to write a byte order mark before the code units.
+///
+/// - `NSTDVec *const utf32` - A vector of `NSTDChar32` to append the code units to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_encode_utf32_lossy(
+    str: &NSTDStr,
+    endian: NSTDCPUEndian,
+    bom: NSTDBool,
+    utf32: &mut NSTDVec,
+) -> NSTDErrorCode {
+    encode(str, endian, bom, utf32, None, encode_utf32)
+}
+
+/// Decodes UTF-16 code units onto the end of a string.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf16` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf16` - A slice of `NSTDChar16` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the index of the first invalid code unit in
+///   `utf16`, -1/usize::MAX if `utf16` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_decode_utf16(
+    utf16: &NSTDSlice,
+    endian: NSTDCPUEndian,
+    string: &mut NSTDString,
+    error_offset: &mut usize,
+) -> NSTDErrorCode {
+    decode(utf16, endian, string, Some(error_offset), decode_utf16)
+}
+
+/// Decodes UTF-16 code units onto the end of a string, replacing unpaired surrogates with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf16` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf16` - A slice of `NSTDChar16` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_decode_utf16_lossy(
+    utf16: &NSTDSlice,
+    endian: NSTDCPUEndian,
+    string: &mut NSTDString,
+) -> NSTDErrorCode {
+    decode(utf16, endian, string, None, decode_utf16)
+}
+
+/// Decodes UTF-32 code units onto the end of a string.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf32` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf32` - A slice of `NSTDChar32` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// - `NSTDUSize *const error_offset` - Returns as the index of the first invalid code unit in
+///   `utf32`, -1/usize::MAX if `utf32` is valid.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_decode_utf32(
+    utf32: &NSTDSlice,
+    endian: NSTDCPUEndian,
+    string: &mut NSTDString,
+    error_offset: &mut usize,
+) -> NSTDErrorCode {
+    decode(utf32, endian, string, Some(error_offset), decode_utf32)
+}
+
+/// Decodes UTF-32 code units onto the end of a string, replacing invalid code units with U+FFFD.
+///
+/// # Note
+///
+/// `NSTD_CPU_ENDIAN_UNKNOWN` selects the native byte order. A byte order mark at the start of
+/// `utf32` overrides `endian` and is not decoded. `string` is left unchanged on error.
+///
+/// # Parameters
+///
+/// - `const NSTDSlice *const utf32` - A slice of `NSTDChar32` code units.
+///
+/// - `const NSTDCPUEndian endian` - The byte order of the code units.
+///
+/// - `NSTDString *const string` - The string to append the decoded characters to.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero on error.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_string_decode_utf32_lossy(
+    utf32: &NSTDSlice,
+    endian: NSTDCPUEndian,
+    string: &mut NSTDString,
+) -> NSTDErrorCode {
+    decode(utf32, endian, string, None, decode_utf32)
+}
+
 /// Generates string to ctype conversions.
 macro_rules! nstd_from_ctype {
     ($name: ident, $type: ty) => {
@@ -225,6 +1162,81 @@ nstd_from_ctype!(nstd_string_from_u64, u64);
 nstd_from_ctype!(nstd_string_from_isize, isize);
 nstd_from_ctype!(nstd_string_from_usize, usize);
 
+/// Generates float to string conversions with a format and precision.
+macro_rules! nstd_from_float_formatted {
+    ($name: ident, $type: ty) => {
+        ///
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(
+            num: $type,
+            format: NSTDStringFloatFormat,
+            precision: usize,
+            is_err: &mut NSTDErrorCode,
+        ) -> NSTDString {
+            let shortest = format == NSTDStringFloatFormat::NSTD_STRING_FLOAT_FORMAT_SHORTEST;
+            if !shortest && precision > NSTD_STRING_FLOAT_MAX_PRECISION {
+                *is_err = 1;
+                return nstd_string_new();
+            }
+            let formatted = match format {
+                NSTDStringFloatFormat::NSTD_STRING_FLOAT_FORMAT_FIXED => {
+                    format!("{:.*}", precision, num)
+                }
+                NSTDStringFloatFormat::NSTD_STRING_FLOAT_FORMAT_SCIENTIFIC => {
+                    format!("{:.*e}", precision, num)
+                }
+                NSTDStringFloatFormat::NSTD_STRING_FLOAT_FORMAT_SHORTEST => format!("{:?}", num),
+            };
+            NSTDString::from(formatted.as_bytes())
+        }
+    };
+}
+nstd_from_float_formatted!(nstd_string_from_f32_formatted, f32);
+nstd_from_float_formatted!(nstd_string_from_f64_formatted, f64);
+
+/// Generates integer to string conversions in a radix.
+macro_rules! nstd_from_int_radix {
+    ($name: ident, $type: ty) => {
+        ///
+        #[cfg_attr(feature = "clib", no_mangle)]
+        pub unsafe extern "C" fn $name(
+            num: $type,
+            radix: NSTDUInt32,
+            is_err: &mut NSTDErrorCode,
+        ) -> NSTDString {
+            if !(2..=36).contains(&radix) {
+                *is_err = 1;
+                return nstd_string_new();
+            }
+            let mut magnitude = (num as i128).unsigned_abs();
+            let mut digits = Vec::new();
+            loop {
+                let digit = (magnitude % u128::from(radix)) as u32;
+                digits.push(char::from_digit(digit, radix).unwrap_or_default() as u8);
+                magnitude /= u128::from(radix);
+                if magnitude == 0 {
+                    break;
+                }
+            }
+            if num < <$type>::default() {
+                digits.push(b'-');
+            }
+            digits.reverse();
+            NSTDString::from(digits.as_slice())
+        }
+    };
+}
+nstd_from_int_radix!(nstd_string_from_i8_radix, i8);
+nstd_from_int_radix!(nstd_string_from_u8_radix, u8);
+nstd_from_int_radix!(nstd_string_from_i16_radix, i16);
+nstd_from_int_radix!(nstd_string_from_u16_radix, u16);
+nstd_from_int_radix!(nstd_string_from_i32_radix, i32);
+nstd_from_int_radix!(nstd_string_from_u32_radix, u32);
+nstd_from_int_radix!(nstd_string_from_i64_radix, i64);
+nstd_from_int_radix!(nstd_string_from_u64_radix, u64);
+nstd_from_int_radix!(nstd_string_from_isize_radix, isize);
+nstd_from_int_radix!(nstd_string_from_usize_radix, usize);
+
 /// Frees an `NSTDString` instance.
 ///
 /// # Parameters
@@ -1,20 +1,33 @@
 //! A dynamically sized array.
-use crate::core::{
-    def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
-    slice::NSTDSlice,
-    NSTD_CORE_NULL,
+use crate::{
+    alloc::allocator::{with_errc, NSTDAllocator},
+    core::{
+        def::{NSTDAny, NSTDAnyConst, NSTDErrorCode},
+        slice::NSTDSlice,
+        NSTD_CORE_NULL,
+    },
+};
+use std::{
+    hash::{Hash, Hasher},
+    ptr::addr_of,
 };
-use std::ptr::addr_of;
 
 /// Represents an array of dynamic length.
 #[repr(C)]
-#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug)]
 pub struct NSTDVec {
     /// The number of active elements in this vector.
     pub size: usize,
     /// Buffer of allocated memory where `buffer.size` is the capacity, `buffer.ptr.size` is the
     /// size of each element, and `buffer.ptr.raw` is a raw pointer to the buffer.
     pub buffer: NSTDSlice,
+    /// An optional function that is called with a pointer to each element that is dropped by the
+    /// vector.
+    pub drop: Option<unsafe extern "C" fn(NSTDAny)>,
+    /// The allocator used for the vector's buffer, null to use the default heap allocator.
+    ///
+    /// The allocator is passed to it's own functions as `this`.
+    pub allocator: *mut NSTDAllocator,
 }
 impl NSTDVec {
     /// Gets the total number of bytes allocated for this vec.
@@ -34,6 +47,94 @@ impl NSTDVec {
     pub unsafe fn end_unchecked(&self) -> *mut u8 {
         self.buffer.ptr.raw.add(self.byte_count()).cast()
     }
+
+    /// Returns the fields of this vector in a comparable form.
+    #[inline]
+    fn key(&self) -> (usize, &NSTDSlice, usize, usize) {
+        (
+            self.size,
+            &self.buffer,
+            self.drop.map_or(0, |drop| drop as usize),
+            self.allocator as usize,
+        )
+    }
+
+    /// Reallocates the vector's buffer to `new_byte_count` bytes with the vector's allocator.
+    ///
+    /// A vector without a buffer is given a new one, so allocators never reallocate null.
+    unsafe fn reallocate(&mut self, new_byte_count: usize) -> NSTDErrorCode {
+        if self.buffer.ptr.raw.is_null() {
+            let data = allocate(self.allocator, new_byte_count);
+            if data.is_null() {
+                return 1;
+            }
+            self.buffer.ptr.raw = data;
+            return 0;
+        }
+        let old_byte_count = self.total_byte_count();
+        let (allocator, ptr) = (self.allocator, &mut self.buffer.ptr.raw);
+        if allocator.is_null() {
+            return crate::alloc::nstd_alloc_reallocate(ptr, old_byte_count, new_byte_count);
+        }
+        match (*allocator).reallocate {
+            Some(reallocate) => with_errc(allocator, || {
+                reallocate(allocator.cast(), ptr, old_byte_count, new_byte_count)
+            }),
+            None => 1,
+        }
+    }
+
+    /// Deallocates the vector's buffer with the vector's allocator.
+    unsafe fn deallocate(&mut self) -> NSTDErrorCode {
+        let byte_count = self.total_byte_count();
+        let (allocator, ptr) = (self.allocator, &mut self.buffer.ptr.raw);
+        if allocator.is_null() {
+            return crate::alloc::nstd_alloc_deallocate(ptr, byte_count);
+        }
+        match (*allocator).deallocate {
+            Some(deallocate) => {
+                with_errc(allocator, || deallocate(allocator.cast(), ptr, byte_count))
+            }
+            None => 1,
+        }
+    }
+
+    /// Calls the vector's drop callback on each element in the range [`start`, `self.size`).
+    #[inline]
+    unsafe fn drop_from(&self, start: usize) {
+        if let Some(drop) = self.drop {
+            let mut element = self.buffer.ptr.raw.add(start * self.buffer.ptr.size);
+            for _ in start..self.size {
+                drop(element);
+                element = element.add(self.buffer.ptr.size);
+            }
+        }
+    }
+}
+impl PartialEq for NSTDVec {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+impl Eq for NSTDVec {}
+impl Hash for NSTDVec {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+impl PartialOrd for NSTDVec {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for NSTDVec {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
 }
 impl Default for NSTDVec {
     #[inline]
@@ -42,14 +143,22 @@ impl Default for NSTDVec {
             Self {
                 size: 0,
                 buffer: crate::core::slice::nstd_core_slice_new(0, 0, NSTD_CORE_NULL),
+                drop: None,
+                allocator: std::ptr::null_mut(),
             }
         }
     }
 }
 impl Clone for NSTDVec {
+    /// Creates a bytewise copy of the vector using the same allocator, the new vector does not
+    /// inherit the drop callback.
     fn clone(&self) -> Self {
         unsafe {
-            let mut new_vec = nstd_vec_new_with_capacity(self.buffer.ptr.size, self.buffer.size);
+            let mut new_vec = nstd_vec_new_with_capacity_in(
+                self.buffer.ptr.size,
+                self.buffer.size,
+                self.allocator,
+            );
             if !new_vec.buffer.ptr.raw.is_null() {
                 let byte_count = self.byte_count();
                 let data = std::slice::from_raw_parts(self.buffer.ptr.raw as *const u8, byte_count);
@@ -90,12 +199,7 @@ impl<T: Copy> From<&[T]> for NSTDVec {
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_vec_new(element_size: usize) -> NSTDVec {
-    const INITIAL_CAPACITY: usize = 1;
-    let data = crate::alloc::nstd_alloc_allocate(INITIAL_CAPACITY * element_size);
-    NSTDVec {
-        size: 0,
-        buffer: crate::core::slice::nstd_core_slice_new(INITIAL_CAPACITY, element_size, data),
-    }
+    nstd_vec_new_in(element_size, std::ptr::null_mut())
 }
 
 /// Creates a new vector with the specified capacity.
@@ -115,10 +219,77 @@ pub unsafe extern "C" fn nstd_vec_new_with_capacity(
     element_size: usize,
     capacity: usize,
 ) -> NSTDVec {
-    let data = crate::alloc::nstd_alloc_allocate(capacity * element_size);
+    nstd_vec_new_with_capacity_in(element_size, capacity, std::ptr::null_mut())
+}
+
+/// Creates a new vector that allocates it's buffer with `allocator`.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the vector.
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDVec vec` - The new vector.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_vec_new_in(
+    element_size: usize,
+    allocator: *mut NSTDAllocator,
+) -> NSTDVec {
+    const INITIAL_CAPACITY: usize = 1;
+    nstd_vec_new_with_capacity_in(element_size, INITIAL_CAPACITY, allocator)
+}
+
+/// Allocates `byte_count` bytes with `allocator`, null for the default heap allocator.
+unsafe fn allocate(allocator: *mut NSTDAllocator, byte_count: usize) -> NSTDAny {
+    if allocator.is_null() {
+        return crate::alloc::nstd_alloc_allocate(byte_count);
+    }
+    match (*allocator).allocate {
+        Some(allocate) => allocate(allocator.cast(), byte_count),
+        None => NSTD_CORE_NULL,
+    }
+}
+
+/// Creates a new vector with the specified capacity that allocates it's buffer with `allocator`.
+///
+/// # Note
+///
+/// The allocator must outlive the vector. Custom allocators that store their state alongside the
+/// vtable (such as `NSTDArena` and `NSTDPool`) must be passed as a pointer to the whole allocator
+/// object.
+///
+/// # Parameters
+///
+/// - `const NSTDUSize element_size` - The size of each element in the vector.
+///
+/// - `const NSTDUSize capacity` - The capacity to give the vector, must be greater than 0.
+///
+/// - `NSTDAllocator *const allocator` - The allocator to use, null for the default heap allocator.
+///
+/// # Returns
+///
+/// `NSTDVec vec` - The new vector.
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_vec_new_with_capacity_in(
+    element_size: usize,
+    capacity: usize,
+    allocator: *mut NSTDAllocator,
+) -> NSTDVec {
+    let data = allocate(allocator, capacity * element_size);
+    // A failed allocation leaves the vector with no capacity.
+    let capacity = match data.is_null() {
+        true => 0,
+        false => capacity,
+    };
     NSTDVec {
         size: 0,
         buffer: crate::core::slice::nstd_core_slice_new(capacity, element_size, data),
+        drop: None,
+        allocator,
     }
 }
 
@@ -139,9 +310,33 @@ pub unsafe extern "C" fn nstd_vec_from_existing(size: usize, buffer: &NSTDSlice)
     NSTDVec {
         size,
         buffer: *buffer,
+        drop: None,
+        allocator: std::ptr::null_mut(),
     }
 }
 
+/// Sets the function that is called with a pointer to each element that is dropped by a vector.
+///
+/// # Note
+///
+/// Elements are dropped when the vector is freed or cleared, and by `nstd_vec_pop_discard`,
+/// `nstd_vec_truncate`, `nstd_vec_resize` and `nstd_vec_remove`. Elements returned by
+/// `nstd_vec_pop` are not dropped.
+///
+/// # Parameters
+///
+/// - `NSTDVec *const vec` - The vector.
+///
+/// - `void (*drop)(NSTDAny)` - The drop callback, null to disable dropping elements.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_vec_set_drop(
+    vec: &mut NSTDVec,
+    drop: Option<unsafe extern "C" fn(NSTDAny)>,
+) {
+    vec.drop = drop;
+}
+
 /// Creates an `NSTDSlice` from an `NSTDVec`.
 ///
 /// # Parameters
@@ -242,7 +437,7 @@ pub unsafe extern "C" fn nstd_vec_last(vec: &NSTDVec) -> NSTDAny {
 pub unsafe extern "C" fn nstd_vec_push(vec: &mut NSTDVec, element: NSTDAnyConst) -> NSTDErrorCode {
     // Checking if the vector has reached it's capacity.
     if vec.size == vec.buffer.size {
-        let new_cap = (vec.buffer.size as f32 * 1.5).ceil() as usize;
+        let new_cap = ((vec.buffer.size as f32 * 1.5).ceil() as usize).max(1);
         match nstd_vec_reserve(vec, new_cap) {
             0 => vec.buffer.size = new_cap,
             errc => return errc,
@@ -279,6 +474,24 @@ pub unsafe extern "C" fn nstd_vec_pop(vec: &mut NSTDVec) -> NSTDAny {
     NSTD_CORE_NULL
 }
 
+/// Pops a value off of the back of a vector and drops it.
+///
+/// # Parameters
+///
+/// - `NSTDVec *const vec` - The vector.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if the vector is empty.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_vec_pop_discard(vec: &mut NSTDVec) -> NSTDErrorCode {
+    match vec.size > 0 {
+        true => nstd_vec_truncate(vec, vec.size - 1),
+        false => 1,
+    }
+}
+
 /// Extends a vector from a slice. `vec` and `slice` must have the same element size.
 ///
 /// # Parameters
@@ -297,7 +510,10 @@ pub unsafe extern "C" fn nstd_vec_extend(vec: &mut NSTDVec, slice: &NSTDSlice) -
             nstd_vec_reserve(vec, vec.size + slice.size);
             let mut ptr = slice.ptr.raw;
             for _ in 0..slice.size {
-                nstd_vec_push(vec, ptr);
+                let errc = nstd_vec_push(vec, ptr);
+                if errc != 0 {
+                    return errc;
+                }
                 ptr = ptr.add(slice.ptr.size);
             }
         }
@@ -372,6 +588,9 @@ pub unsafe extern "C" fn nstd_vec_remove(vec: &mut NSTDVec, index: usize) -> NST
     if vec.size > index {
         // Moving data down by one element.
         let index_pointer = nstd_vec_get(vec, index) as *mut u8;
+        if let Some(drop) = vec.drop {
+            drop(index_pointer.cast());
+        }
         let next_index_pointer = index_pointer.add(vec.buffer.ptr.size);
         let copy_size = (vec.size - index - 1) * vec.buffer.ptr.size;
         std::ptr::copy(next_index_pointer, index_pointer, copy_size);
@@ -389,9 +608,32 @@ pub unsafe extern "C" fn nstd_vec_remove(vec: &mut NSTDVec, index: usize) -> NST
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_vec_clear(vec: &mut NSTDVec) {
+    vec.drop_from(0);
     vec.size = 0;
 }
 
+/// Shortens a vector to `len` elements, dropping the rest.
+///
+/// # Parameters
+///
+/// - `NSTDVec *const vec` - The vector.
+///
+/// - `const NSTDUSize len` - The new length of the vector.
+///
+/// # Returns
+///
+/// `NSTDErrorCode errc` - Nonzero if `len` is greater than the vector's length.
+#[inline]
+#[cfg_attr(feature = "clib", no_mangle)]
+pub unsafe extern "C" fn nstd_vec_truncate(vec: &mut NSTDVec, len: usize) -> NSTDErrorCode {
+    if vec.size >= len {
+        vec.drop_from(len);
+        vec.size = len;
+        return 0;
+    }
+    1
+}
+
 /// Resizes a vector.
 ///
 /// # Parameters
@@ -414,6 +656,8 @@ pub unsafe extern "C" fn nstd_vec_resize(vec: &mut NSTDVec, new_size: usize) ->
         }
         let new_bytes = (new_size - vec.size) * vec.buffer.ptr.size;
         std::slice::from_raw_parts_mut(vec.end_unchecked(), new_bytes).fill(0);
+    } else {
+        vec.drop_from(new_size);
     }
     vec.size = new_size;
     0
@@ -433,13 +677,7 @@ pub unsafe extern "C" fn nstd_vec_resize(vec: &mut NSTDVec, new_size: usize) ->
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_vec_reserve(vec: &mut NSTDVec, new_cap: usize) -> NSTDErrorCode {
     if vec.buffer.size < new_cap {
-        let old_byte_count = vec.total_byte_count();
-        let new_byte_count = new_cap * vec.buffer.ptr.size;
-        let errc = crate::alloc::nstd_alloc_reallocate(
-            &mut vec.buffer.ptr.raw,
-            old_byte_count,
-            new_byte_count,
-        );
+        let errc = vec.reallocate(new_cap * vec.buffer.ptr.size);
         if errc != 0 {
             return errc;
         }
@@ -462,13 +700,7 @@ pub unsafe extern "C" fn nstd_vec_reserve(vec: &mut NSTDVec, new_cap: usize) ->
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_vec_shrink(vec: &mut NSTDVec) -> NSTDErrorCode {
     if vec.size > 0 {
-        let old_byte_count = vec.total_byte_count();
-        let new_byte_count = vec.byte_count();
-        let errc = crate::alloc::nstd_alloc_reallocate(
-            &mut vec.buffer.ptr.raw,
-            old_byte_count,
-            new_byte_count,
-        );
+        let errc = vec.reallocate(vec.byte_count());
         if errc != 0 {
             return errc;
         }
@@ -491,6 +723,6 @@ pub unsafe extern "C" fn nstd_vec_shrink(vec: &mut NSTDVec) -> NSTDErrorCode {
 #[inline]
 #[cfg_attr(feature = "clib", no_mangle)]
 pub unsafe extern "C" fn nstd_vec_free(vec: &mut NSTDVec) -> NSTDErrorCode {
-    let bytes = vec.total_byte_count();
-    crate::alloc::nstd_alloc_deallocate(&mut vec.buffer.ptr.raw, bytes)
+    vec.drop_from(0);
+    vec.deallocate()
 }